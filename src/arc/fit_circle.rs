@@ -0,0 +1,56 @@
+use super::circle::*;
+use super::super::geo::*;
+
+///
+/// Finds the circle that best fits a set of points
+///
+/// This uses the algebraic least-squares method described by Kåsa, which minimises the differences between the squared
+/// distances of the points from the center and the squared radius. This is fast and works well for points that cover a
+/// reasonable part of the circle, but tends to underestimate the radius when the points only cover a small arc.
+///
+/// The result is a `Circle` rather than a separate center and radius, which are available as its `center` and `radius` fields.
+/// Returns `None` if there are fewer than 3 points or if the points are all on a straight line.
+///
+pub fn fit_circle<Coord: Coordinate+Coordinate2D>(points: &[Coord]) -> Option<Circle<Coord>> {
+    if points.len() < 3 {
+        return None;
+    }
+
+    // Work relative to the mean of the points to reduce rounding errors
+    let count           = points.len() as f64;
+    let mean_x          = points.iter().map(|point| point.x()).sum::<f64>() / count;
+    let mean_y          = points.iter().map(|point| point.y()).sum::<f64>() / count;
+
+    let (mut suu, mut suv, mut svv)                 = (0.0, 0.0, 0.0);
+    let (mut suuu, mut svvv, mut suvv, mut svuu)    = (0.0, 0.0, 0.0, 0.0);
+
+    for point in points.iter() {
+        let (u, v) = (point.x() - mean_x, point.y() - mean_y);
+
+        suu     += u*u;
+        suv     += u*v;
+        svv     += v*v;
+        suuu    += u*u*u;
+        svvv    += v*v*v;
+        suvv    += u*v*v;
+        svuu    += v*u*u;
+    }
+
+    // Solve the linear equations for the center: the determinant is 0 if the points are collinear
+    let determinant     = suu*svv - suv*suv;
+    let scale           = (suu + svv) * (suu + svv);
+
+    if scale <= 0.0 || determinant.abs() <= scale * 1e-12 {
+        return None;
+    }
+
+    let rhs_u           = 0.5 * (suuu + suvv);
+    let rhs_v           = 0.5 * (svvv + svuu);
+    let center_u        = (rhs_u*svv - rhs_v*suv) / determinant;
+    let center_v        = (rhs_v*suu - rhs_u*suv) / determinant;
+
+    let radius          = (center_u*center_u + center_v*center_v + (suu + svv)/count).sqrt();
+    let center          = Coord::from_components(&[center_u + mean_x, center_v + mean_y]);
+
+    Some(Circle::new(center, radius))
+}
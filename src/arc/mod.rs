@@ -2,11 +2,16 @@
 //! # Describing circular arcs
 //! 
 //! The `arc` module provides routines for describing circular arcs and converting them to bezier
-//! curves.
+//! curves. Elliptical arcs described in the form used by SVG can be converted using `svg_arc_to_beziers()`, and
+//! `fit_circle()` will find the circle that best matches a set of points.
 //!
 
 mod circle;
+mod svg_arc;
+mod fit_circle;
 
 pub use self::circle::*;
+pub use self::svg_arc::*;
+pub use self::fit_circle::*;
 
 // TODO: represent arcs in more than 2 dimensions
@@ -0,0 +1,99 @@
+use super::super::bezier::*;
+use super::super::line::*;
+
+use std::f64;
+
+///
+/// Returns the angle in radians from one vector to another (between -pi and pi)
+///
+#[inline]
+fn angle_between(u: (f64, f64), v: (f64, f64)) -> f64 {
+    let cross   = u.0*v.1 - u.1*v.0;
+    let dot     = u.0*v.0 + u.1*v.1;
+
+    cross.atan2(dot)
+}
+
+///
+/// Converts an elliptical arc in the form used by the SVG `A` command to a set of bezier curves
+///
+/// The arc runs from `start` to `end` on an ellipse with radii `rx` and `ry`, with its x axis rotated by `x_axis_rotation`
+/// degrees. There are usually four arcs that fit these parameters: `large_arc` picks one of the arcs that covers more than
+/// 180 degrees, and `sweep` picks an arc that goes in the direction of increasing angles (anticlockwise if the y axis points
+/// upwards, or clockwise in SVG's coordinate scheme). If the ellipse is too small to reach between the two points, it's scaled
+/// up until it does.
+///
+/// The arc is divided into sections of at most 90 degrees, each of which is approximated by a single curve. An arc where
+/// either of the radii is 0 is a straight line, and an arc where the start and end points are the same is empty.
+///
+pub fn svg_arc_to_beziers<Curve>(start: Curve::Point, rx: f64, ry: f64, x_axis_rotation: f64, large_arc: bool, sweep: bool, end: Curve::Point) -> Vec<Curve>
+where
+    Curve:          BezierCurveFactory,
+    Curve::Point:   Coordinate+Coordinate2D,
+{
+    // See the 'implementation notes' appendix of the SVG specification for the conversion from this form to the center of the ellipse
+    if start == end {
+        return vec![];
+    }
+
+    if rx == 0.0 || ry == 0.0 {
+        return vec![line_to_bezier(&(start, end))];
+    }
+
+    let (mut rx, mut ry)    = (rx.abs(), ry.abs());
+    let phi                 = x_axis_rotation.to_radians();
+    let (sin_phi, cos_phi)  = phi.sin_cos();
+
+    // Transform the start point into a coordinate scheme where the ellipse is not rotated and the midpoint of the line is at the origin
+    let (dx, dy)            = ((start.x()-end.x())/2.0, (start.y()-end.y())/2.0);
+    let (x1, y1)            = (cos_phi*dx + sin_phi*dy, -sin_phi*dx + cos_phi*dy);
+
+    // Scale up the radii if the ellipse is too small to reach between the points
+    let lambda              = (x1*x1)/(rx*rx) + (y1*y1)/(ry*ry);
+    if lambda > 1.0 {
+        rx *= lambda.sqrt();
+        ry *= lambda.sqrt();
+    }
+
+    // Find the center of the ellipse (the sign determines which of the two possible ellipses is used)
+    let sign                = if large_arc == sweep { -1.0 } else { 1.0 };
+    let numerator           = rx*rx*ry*ry - rx*rx*y1*y1 - ry*ry*x1*x1;
+    let denominator         = rx*rx*y1*y1 + ry*ry*x1*x1;
+    let coefficient         = sign * (numerator/denominator).max(0.0).sqrt();
+    let (cx1, cy1)          = (coefficient * rx*y1/ry, -coefficient * ry*x1/rx);
+
+    let cx                  = cos_phi*cx1 - sin_phi*cy1 + (start.x()+end.x())/2.0;
+    let cy                  = sin_phi*cx1 + cos_phi*cy1 + (start.y()+end.y())/2.0;
+
+    // Find the start angle and the angle covered by the arc
+    let start_angle         = angle_between((1.0, 0.0), ((x1-cx1)/rx, (y1-cy1)/ry));
+    let mut arc_angle       = angle_between(((x1-cx1)/rx, (y1-cy1)/ry), ((-x1-cx1)/rx, (-y1-cy1)/ry));
+
+    if !sweep && arc_angle > 0.0 { arc_angle -= 2.0*f64::consts::PI; }
+    if sweep && arc_angle < 0.0 { arc_angle += 2.0*f64::consts::PI; }
+
+    // Divide into sections of at most 90 degrees
+    let num_sections        = (arc_angle.abs() / (f64::consts::PI/2.0)).ceil().max(1.0) as usize;
+    let section_angle       = arc_angle / (num_sections as f64);
+    let kappa               = 4.0/3.0 * (section_angle/4.0).tan();
+
+    // Generates a point on the ellipse from a point on the unit circle
+    let to_ellipse          = |x: f64, y: f64| Curve::Point::from_components(&[cx + rx*x*cos_phi - ry*y*sin_phi, cy + rx*x*sin_phi + ry*y*cos_phi]);
+
+    (0..num_sections)
+        .map(|section_idx| {
+            let angle1          = start_angle + section_angle * (section_idx as f64);
+            let angle2          = angle1 + section_angle;
+            let (sin1, cos1)    = angle1.sin_cos();
+            let (sin2, cos2)    = angle2.sin_cos();
+
+            // Use the exact start and end points for the ends of the arc
+            let p0 = if section_idx == 0 { start } else { to_ellipse(cos1, sin1) };
+            let p3 = if section_idx == num_sections-1 { end } else { to_ellipse(cos2, sin2) };
+            let p1 = to_ellipse(cos1 - kappa*sin1, sin1 + kappa*cos1);
+            let p2 = to_ellipse(cos2 + kappa*sin2, sin2 - kappa*cos2);
+
+            Curve::from_points(p0, (p1, p2), p3)
+        })
+        .collect()
+}
@@ -86,3 +86,76 @@ pub fn de_casteljau3<Point: Coordinate>(t: f64, w1: Point, w2: Point, w3: Point)
 pub fn de_casteljau2<Point: Coordinate>(t: f64, w1: Point, w2: Point) -> Point {
     w1*(1.0-t) + w2*t
 }
+
+///
+/// Elevates the degree of a bezier curve by one, returning a set of control points with one extra point that
+/// describes the same curve
+///
+/// The curve is described by its control points, starting with the start point and ending with the end point (so a cubic
+/// curve has 4 points and the result describes a quartic curve with 5 points). A curve with no points remains empty.
+///
+pub fn elevate_bezier<Point: Coordinate>(points: &[Point]) -> Vec<Point> {
+    if points.is_empty() { return vec![]; }
+
+    let new_degree  = points.len() as f64;
+    let mut result  = Vec::with_capacity(points.len()+1);
+
+    // The end points stay the same, and the other points are interpolated between the original control points
+    result.push(points[0]);
+
+    for idx in 1..points.len() {
+        let ratio = (idx as f64) / new_degree;
+        result.push(points[idx-1]*ratio + points[idx]*(1.0-ratio));
+    }
+
+    result.push(points[points.len()-1]);
+
+    result
+}
+
+///
+/// Converts a quadratic bezier curve to the control points of a cubic bezier curve describing the same curve
+///
+#[inline]
+pub fn elevate_quadratic<Point: Coordinate>(w1: Point, w2: Point, w3: Point) -> (Point, Point, Point, Point) {
+    let cp1 = w1*(1.0/3.0) + w2*(2.0/3.0);
+    let cp2 = w2*(2.0/3.0) + w3*(1.0/3.0);
+
+    (w1, cp1, cp2, w3)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn elevate_cubic_to_quartic() {
+        let cubic   = [Coord2(1.0, 2.0), Coord2(3.0, 7.0), Coord2(6.0, -1.0), Coord2(8.0, 4.0)];
+        let quartic = elevate_bezier(&cubic);
+
+        assert!(quartic.len() == 5);
+        assert!(quartic[0] == cubic[0]);
+        assert!(quartic[4] == cubic[3]);
+
+        for x in 0..100 {
+            let t           = (x as f64)/99.0;
+            let original    = de_casteljau4(t, cubic[0], cubic[1], cubic[2], cubic[3]);
+            let elevated    = de_casteljau_n::<_, 5>(t, quartic.iter().cloned().collect());
+
+            assert!(original.distance_to(&elevated) < 1e-9);
+        }
+    }
+
+    #[test]
+    fn elevate_quadratic_matches_elevate_bezier() {
+        let quadratic           = [Coord2(1.0, 2.0), Coord2(3.0, 7.0), Coord2(6.0, -1.0)];
+        let (w1, w2, w3, w4)    = elevate_quadratic(quadratic[0], quadratic[1], quadratic[2]);
+        let elevated            = elevate_bezier(&quadratic);
+
+        assert!(elevated.len() == 4);
+        assert!(w1.distance_to(&elevated[0]) < 1e-12);
+        assert!(w2.distance_to(&elevated[1]) < 1e-12);
+        assert!(w3.distance_to(&elevated[2]) < 1e-12);
+        assert!(w4.distance_to(&elevated[3]) < 1e-12);
+    }
+}
@@ -0,0 +1,88 @@
+use super::curve::*;
+use crate::geo::*;
+
+///
+/// Describes how smoothly two curves join together
+///
+/// The values are ordered from least to most smooth, so for example `Continuity::G1 > Continuity::C0`.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Continuity {
+    /// The curves do not meet
+    Discontinuous,
+
+    /// The curves meet but there is a corner where they join
+    C0,
+
+    /// The curves meet and have tangents in the same direction but of different magnitudes, so the join is visually smooth
+    G1,
+
+    /// The curves meet and their first derivatives are the same where they join
+    C1
+}
+
+///
+/// Finds the direction that a curve is leaving its end point in
+///
+/// If the second control point is at the end point, the derivative is 0 here, so this falls back to the direction from the other
+/// control points.
+///
+pub (crate) fn end_direction<C: BezierCurve>(curve: &C) -> C::Point {
+    let start_point = curve.start_point();
+    let (cp1, cp2)  = curve.control_points();
+    let end_point   = curve.end_point();
+
+    [cp2, cp1, start_point].iter()
+        .map(|point| end_point - *point)
+        .find(|direction| direction.magnitude() > 0.0)
+        .unwrap_or_else(C::Point::origin)
+}
+
+///
+/// Finds the direction that a curve is leaving its start point in
+///
+pub (crate) fn start_direction<C: BezierCurve>(curve: &C) -> C::Point {
+    let start_point = curve.start_point();
+    let (cp1, cp2)  = curve.control_points();
+    let end_point   = curve.end_point();
+
+    [cp1, cp2, end_point].iter()
+        .map(|point| *point - start_point)
+        .find(|direction| direction.magnitude() > 0.0)
+        .unwrap_or_else(C::Point::origin)
+}
+
+///
+/// Determines the continuity of the join between the end of one curve and the start of another
+///
+/// `epsilon` is the maximum distance between points, or the maximum difference between tangent vectors, that is considered
+/// to be the same. G1 continuity is decided by comparing the unit tangent vectors.
+///
+pub fn join_continuity<C1: BezierCurve, C2: BezierCurve<Point=C1::Point>>(first: &C1, second: &C2, epsilon: f64) -> Continuity {
+    if !first.end_point().is_near_to(&second.start_point(), epsilon) {
+        // The curves do not meet
+        Continuity::Discontinuous
+    } else {
+        // Compare the derivatives where the curves join
+        let (_, cp2)        = first.control_points();
+        let (cp1, _)        = second.control_points();
+        let first_tangent   = (first.end_point() - cp2) * 3.0;
+        let second_tangent  = (cp1 - second.start_point()) * 3.0;
+
+        if first_tangent.is_near_to(&second_tangent, epsilon) {
+            Continuity::C1
+        } else {
+            // Compare the directions of the curves where they join
+            let first_direction     = end_direction(first);
+            let second_direction    = start_direction(second);
+
+            if first_direction.magnitude() > 0.0 
+                && second_direction.magnitude() > 0.0 
+                && first_direction.to_unit_vector().is_near_to(&second_direction.to_unit_vector(), epsilon) {
+                Continuity::G1
+            } else {
+                Continuity::C0
+            }
+        }
+    }
+}
@@ -0,0 +1,69 @@
+use super::curve::*;
+use super::basis::*;
+use super::derivative::*;
+use crate::geo::*;
+
+///
+/// Computes the signed curvature of a 2D curve at a particular t value
+///
+/// The curvature is the reciprocal of the radius of the circle that best fits the curve at this point. It's positive where the 
+/// curve is turning anticlockwise (towards the normal returned by `normal_at_pos()`) and negative where it is turning clockwise.
+/// The curvature is 0 for straight lines, or at points where the curve has no well-defined direction (such as a cusp).
+///
+pub fn curvature_at_pos<C: BezierCurve>(curve: &C, t: f64) -> f64
+where
+    C::Point: Coordinate2D,
+{
+    let w1          = curve.start_point();
+    let (w2, w3)    = curve.control_points();
+    let w4          = curve.end_point();
+
+    // As for the tangent, move away from the end points slightly so that curves with control points at the ends produce a useful value
+    let t           = if t == 0.0 { f64::EPSILON }        else { t };
+    let t           = if t == 1.0 { 1.0-f64::EPSILON }    else { t };
+
+    // Compute the first and second derivatives at this point
+    let (d1, d2, d3)    = derivative4(w1, w2, w3, w4);
+    let (dd1, dd2)      = derivative3(d1, d2, d3);
+    let velocity        = de_casteljau3(t, d1, d2, d3);
+    let acceleration    = de_casteljau2(t, dd1, dd2);
+
+    // k = (x'y'' - y'x'') / |v|^3
+    let speed           = velocity.magnitude();
+    if speed <= 0.0 {
+        return 0.0;
+    }
+
+    (velocity.x()*acceleration.y() - velocity.y()*acceleration.x()) / (speed*speed*speed)
+}
+
+///
+/// Generates the lines that make up a 'curvature comb' for a curve
+///
+/// A curvature comb is a common way of visualising how smooth a curve is: it's made up of 'hairs' at regular intervals along 
+/// the curve, each with a length proportional to the curvature at that point. The result is a list of `(point_on_curve, hair_tip)`
+/// lines. The hairs point towards the concave side of the curve (the side with the center of curvature) and their length is the 
+/// curvature multiplied by `scale`.
+///
+pub fn curvature_comb<C: BezierCurve>(curve: &C, samples: usize, scale: f64) -> Vec<(C::Point, C::Point)>
+where
+    C::Point: Coordinate2D,
+{
+    let w1          = curve.start_point();
+    let (w2, w3)    = curve.control_points();
+    let w4          = curve.end_point();
+    let (d1, d2, d3) = derivative4(w1, w2, w3, w4);
+
+    (0..samples).map(|sample_idx| {
+        let t           = if samples > 1 { (sample_idx as f64) / ((samples-1) as f64) } else { 0.5 };
+        let point       = curve.point_at_pos(t);
+
+        // Positive curvature turns towards the 'left' normal of the tangent, which is where the center of curvature is
+        let curvature   = curvature_at_pos(curve, t);
+        let t           = t.clamp(f64::EPSILON, 1.0-f64::EPSILON);
+        let tangent     = de_casteljau3(t, d1, d2, d3).to_unit_vector();
+        let normal      = C::Point::from_components(&[-tangent.y(), tangent.x()]);
+
+        (point, point + normal * (curvature * scale))
+    }).collect()
+}
@@ -4,12 +4,31 @@ use super::solve::*;
 use super::length::*;
 use super::search::*;
 use super::bounds::*;
+use super::oriented_bounds::*;
 use super::section::*;
 use super::subdivide::*;
+use super::derivative::*;
+use super::curvature::*;
 use super::nearest_point::*;
 use super::characteristics::*;
 
 use crate::geo::*;
+use crate::consts::*;
+
+use smallvec::*;
+
+use std::iter;
+use std::ops::{Range};
+use std::cmp::{Ordering};
+
+/// Number of samples in the arc length tables used by `sample_evenly_by_arc_length()` and `subdivide_at_distance()`
+const ARC_LENGTH_TABLE_SAMPLES: usize = 32;
+
+/// The initial value for the FNV-1a hash used to generate cache keys
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+
+/// The prime used for the FNV-1a hash used to generate cache keys
+const FNV_PRIME: u64 = 0x0100_0000_01b3;
 
 ///
 /// Trait implemented by bezier curves that can create new versions of themselves
@@ -56,6 +75,16 @@ pub trait BezierCurve: Geo+Clone+Sized {
     /// 
     fn control_points(&self) -> (Self::Point, Self::Point);
 
+    ///
+    /// Returns the points that define this curve as an array, in the order `[start_point, control_point1, control_point2, end_point]`
+    ///
+    #[inline]
+    fn control_point_array(&self) -> [Self::Point; 4] {
+        let (cp1, cp2) = self.control_points();
+
+        [self.start_point(), cp1, cp2, self.end_point()]
+    }
+
     ///
     /// Reverses the direction of this curve
     /// 
@@ -73,6 +102,59 @@ pub trait BezierCurve: Geo+Clone+Sized {
         basis(t, self.start_point(), control_points.0, control_points.1, self.end_point())
     }
 
+    ///
+    /// Returns the point at `t` on this curve followed by its first `n` derivatives at the same point
+    ///
+    /// All of the values are found from a single de Casteljau evaluation, which is faster than finding the point and each
+    /// derivative separately when several of them are needed (for example, to find the curvature or the Frenet frame). The
+    /// result has `n+1` entries: a cubic curve has at most 3 non-zero derivatives, so any further derivatives are the origin.
+    ///
+    fn evaluate_with_derivatives(&self, t: f64, n: usize) -> SmallVec<[Self::Point; 4]> {
+        let [w1, w2, w3, w4]    = self.control_point_array();
+
+        // The levels of the de Casteljau triangle
+        let (a1, a2, a3)        = (de_casteljau2(t, w1, w2), de_casteljau2(t, w2, w3), de_casteljau2(t, w3, w4));
+        let (b1, b2)            = (de_casteljau2(t, a1, a2), de_casteljau2(t, a2, a3));
+        let point               = de_casteljau2(t, b1, b2);
+
+        // The derivatives are the differences between the points in each level
+        let mut result          = smallvec![point];
+
+        for derivative in 1..=n {
+            result.push(match derivative {
+                1 => (b2 - b1) * 3.0,
+                2 => (a3 - a2*2.0 + a1) * 6.0,
+                3 => (w4 - w3*3.0 + w2*3.0 - w1) * 6.0,
+                _ => Self::Point::origin()
+            });
+        }
+
+        result
+    }
+
+    ///
+    /// Returns every level of the de Casteljau triangle for this curve at a particular t value
+    ///
+    /// The first level is the 4 points that define the curve, and each following level interpolates between the points of the
+    /// level before it. The final level is a single point, which is the point at `t` on the curve. This is mainly useful for
+    /// visualising how a curve is evaluated or subdivided.
+    ///
+    fn de_casteljau_steps(&self, t: f64) -> Vec<Vec<Self::Point>> {
+        let mut steps   = vec![self.control_point_array().to_vec()];
+
+        while steps[steps.len()-1].len() > 1 {
+            let last_step   = &steps[steps.len()-1];
+            let next_step   = last_step.iter()
+                .zip(last_step.iter().skip(1))
+                .map(|(p1, p2)| de_casteljau2(t, *p1, *p2))
+                .collect();
+
+            steps.push(next_step);
+        }
+
+        steps
+    }
+
     ///
     /// Given a point that is on or very close to the curve, returns the t value where the point can be found
     /// (or None if the point is not very close to the curve)
@@ -98,6 +180,55 @@ pub trait BezierCurve: Geo+Clone+Sized {
             Curve::from_points(second_curve.0, (second_curve.1, second_curve.2), second_curve.3))
     }
 
+    ///
+    /// Splits this curve at a set of t values, returning each of the resulting curves along with the range of t values it
+    /// covers on this curve
+    ///
+    /// The t values can be in any order. Values outside of the range `0.0..1.0` (or at the very start or end of the curve) and
+    /// duplicate values are ignored, so the ranges returned always cover the whole of the original curve.
+    ///
+    fn split_at_with_ranges<Curve: BezierCurveFactory<Point=Self::Point>>(&self, ts: &[f64]) -> Vec<(Curve, Range<f64>)> {
+        // Sort the t values and remove any that would generate an empty curve
+        let mut ts = ts.iter()
+            .cloned()
+            .filter(|t| *t > SMALL_T_DISTANCE && *t < 1.0-SMALL_T_DISTANCE)
+            .collect::<Vec<_>>();
+        ts.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+        ts.dedup_by(|a, b| (*a - *b).abs() < SMALL_T_DISTANCE);
+
+        // Generate a section for each range
+        iter::once(0.0).chain(ts.iter().cloned())
+            .zip(ts.iter().cloned().chain(iter::once(1.0)))
+            .map(|(t_min, t_max)| (Curve::from_curve(&self.section(t_min, t_max)), t_min..t_max))
+            .collect()
+    }
+
+    ///
+    /// Subdivides this curve at a point a certain distance along it, measured by arc length from the start point
+    ///
+    /// Distances less than 0 split the curve at the start point, and distances greater than the length of the curve split it
+    /// at the end point.
+    ///
+    fn subdivide_at_distance<Curve: BezierCurveFactory<Point=Self::Point>>(&self, distance: f64) -> (Curve, Curve) {
+        let t = ArcLengthTable::new(self, ARC_LENGTH_TABLE_SAMPLES).t_for_distance(distance);
+
+        self.subdivide(t)
+    }
+
+    ///
+    /// Subdivides this curve at a set of t values, returning the curves in between each of them
+    ///
+    /// For `n` distinct t values in the range `0.0..1.0`, this returns `n+1` curves that cover the whole of this curve, in order.
+    /// As for `split_at_with_ranges()`, duplicate t values and values outside of the range of the curve are ignored, so an empty
+    /// list of t values will return a single curve that's the same as this one.
+    ///
+    fn subdivide_many<Curve: BezierCurveFactory<Point=Self::Point>>(&self, ts: &[f64]) -> Vec<Curve> {
+        self.split_at_with_ranges(ts)
+            .into_iter()
+            .map(|(curve, _range)| curve)
+            .collect()
+    }
+
     ///
     /// Computes the bounds of this bezier curve
     /// 
@@ -165,6 +296,78 @@ pub trait BezierCurve: Geo+Clone+Sized {
         curve_length(self, 0.01)
     }
 
+    ///
+    /// Returns the parametric speed of this curve at a particular t value (the magnitude of its derivative)
+    ///
+    /// This is the rate at which the arc length changes with t: integrating this over the range `0.0..1.0` produces the 
+    /// length of the curve. The speed is 0 at a cusp, or at the end of a curve where the control point is at the same
+    /// position as the end point.
+    ///
+    fn speed_at_pos(&self, t: f64) -> f64 {
+        let start       = self.start_point();
+        let end         = self.end_point();
+        let (cp1, cp2)  = self.control_points();
+
+        let (d1, d2, d3) = derivative4(start, cp1, cp2, end);
+
+        de_casteljau3(t, d1, d2, d3).magnitude()
+    }
+
+    ///
+    /// Returns `count` points along this curve that are evenly spaced by arc length
+    ///
+    /// The first and last points are the start and end points of the curve. Asking for 0 points returns an empty list, and 
+    /// asking for 1 point returns just the start point. See `walk_curve_evenly()` for a way to walk a curve at a fixed distance
+    /// rather than dividing it into a fixed number of sections.
+    ///
+    fn sample_evenly_by_arc_length(&self, count: usize) -> Vec<Self::Point> {
+        match count {
+            0 => vec![],
+            1 => vec![self.start_point()],
+            _ => {
+                // Build a table to map distances to t values
+                let table       = ArcLengthTable::new(self, ARC_LENGTH_TABLE_SAMPLES);
+                let step        = table.total_length() / ((count-1) as f64);
+
+                // The end points are returned exactly, with the points in between found from the table
+                let mut points  = Vec::with_capacity(count);
+
+                points.push(self.start_point());
+                for idx in 1..(count-1) {
+                    points.push(self.point_at_pos(table.t_for_distance(step * (idx as f64))));
+                }
+                points.push(self.end_point());
+
+                points
+            }
+        }
+    }
+
+    ///
+    /// Returns a hash of the control points of this curve, which can be used as a key for caching things derived from it
+    ///
+    /// The coordinates are rounded to the nearest multiple of `precision` before they are hashed, so curves with very small
+    /// differences in their control points will usually produce the same key (points that round to different values will
+    /// produce different keys even if they're very close, so this is not a replacement for comparing the curves). The hash is
+    /// the 64-bit FNV-1a hash of the rounded values, so it is stable between runs and platforms.
+    ///
+    fn curve_cache_key(&self, precision: f64) -> u64 {
+        let mut hash = FNV_OFFSET_BASIS;
+
+        for point in self.control_point_array().iter() {
+            for component in 0..Self::Point::len() {
+                let quantized = (point.get(component) / precision).round() as i64;
+
+                for byte in quantized.to_le_bytes().iter() {
+                    hash ^= u64::from(*byte);
+                    hash = hash.wrapping_mul(FNV_PRIME);
+                }
+            }
+        }
+
+        hash
+    }
+
     ///
     /// Create a section from this curve. Consider calling `subsection` for curves
     /// that are already `CurveSections`.
@@ -178,6 +381,7 @@ pub trait BezierCurve: Geo+Clone+Sized {
 /// Represents a Bezier curve
 /// 
 #[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Curve<Coord: Coordinate> {
     pub start_point:    Coord,
     pub end_point:      Coord,
@@ -262,6 +466,21 @@ pub trait BezierCurve2D: BezierCurve {
     /// Computes the distance from a point to the closest point on this curve
     ///
     fn distance_to(&self, point: &Self::Point) -> f64;
+
+    ///
+    /// Returns the signed curvature of this curve at a particular t value
+    ///
+    /// See `curvature_at_pos()` for details: the result is positive where the curve turns anticlockwise, and 0 for points
+    /// where the speed of the curve is 0.
+    ///
+    fn curvature_at(&self, t: f64) -> f64;
+
+    ///
+    /// Finds a rotated rectangle around this curve with as small an area as possible
+    ///
+    /// See `curve_oriented_bounds()` for details.
+    ///
+    fn oriented_bounds(&self) -> OrientedBox<Self::Point>;
 }
 
 impl<T: BezierCurve> BezierCurve2D for T
@@ -300,4 +519,14 @@ where
     fn distance_to(&self, point: &Self::Point) -> f64 {
         self.nearest_point(point).distance_to(point)
     }
+
+    #[inline]
+    fn curvature_at(&self, t: f64) -> f64 {
+        curvature_at_pos(self, t)
+    }
+
+    #[inline]
+    fn oriented_bounds(&self) -> OrientedBox<Self::Point> {
+        curve_oriented_bounds(self)
+    }
 }
@@ -0,0 +1,55 @@
+use super::curve::*;
+use super::length::*;
+use super::continuity::*;
+use crate::geo::*;
+use crate::line::*;
+
+/// Error bound passed to `curve_length()` when measuring how far to trim a curve
+const TRIM_MAX_ERROR: f64 = 1e-8;
+
+///
+/// Extends a curve by straight lines along the tangents at its start and end points
+///
+/// `start_len` and `end_len` are the lengths to add before the start and after the end of the curve. Straight extensions can't
+/// be represented by a single cubic curve, so this returns a list of curves: a line leading up to the start of the original curve
+/// (if `start_len` is positive), then the curve itself, then a line continuing on from the end (if `end_len` is positive). The
+/// lines have the same direction as the tangents of the curve, so the joins are smooth.
+///
+/// Negative lengths trim the curve by that arc length instead. The result is empty if the curve is trimmed away entirely.
+///
+pub fn extend_curve<C: BezierCurveFactory>(curve: &C, start_len: f64, end_len: f64) -> Vec<C> {
+    let mut result = vec![];
+
+    // Trim the curve if either length is negative
+    let length      = curve_length(curve, TRIM_MAX_ERROR);
+    let trim_start  = (-start_len).max(0.0);
+    let trim_end    = (-end_len).max(0.0);
+
+    if trim_start + trim_end >= length {
+        return result;
+    }
+
+    let t_min       = if trim_start > 0.0 { t_for_curve_length(curve, trim_start, TRIM_MAX_ERROR) } else { 0.0 };
+    let t_max       = if trim_end > 0.0 { t_for_curve_length(curve, length - trim_end, TRIM_MAX_ERROR) } else { 1.0 };
+    let trimmed     = C::from_curve(&curve.section(t_min, t_max));
+
+    // Add a line leading up to the start of the curve
+    if start_len > 0.0 {
+        let start_point = trimmed.start_point();
+        let direction   = start_direction(curve).to_unit_vector();
+
+        result.push(line_to_bezier(&(start_point - direction*start_len, start_point)));
+    }
+
+    result.push(trimmed.clone());
+
+    // Add a line continuing on from the end of the curve
+    if end_len > 0.0 {
+        let end_point   = trimmed.end_point();
+        let direction   = end_direction(curve).to_unit_vector();
+
+        result.push(line_to_bezier(&(end_point, end_point + direction*end_len)));
+    }
+
+    result
+}
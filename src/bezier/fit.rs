@@ -24,6 +24,136 @@ const MAX_POINTS_TO_FIT: usize = 100;
 ///     in quadratic time otherwise
 /// 
 pub fn fit_curve<Curve>(points: &[Curve::Point], max_error: f64) -> Option<Vec<Curve>>
+where
+    Curve: BezierCurveFactory + BezierCurve
+{
+    fit_curve_indexed(points, max_error, None)
+        .map(|curves| curves.into_iter().map(|(curve, _start_idx, _end_idx)| curve).collect())
+}
+
+///
+/// Fits bezier curves to a set of points that each have an attribute (such as pen pressure) attached to them
+///
+/// The result is the list of fitted curves, along with the values of the attribute at the start and end of each curve. The
+/// fitting process always places the start and end of each curve on one of the input points, so these are the attributes
+/// of the points at the anchors of the fitted curves.
+///
+/// Returns `None` if there are fewer than 2 points, or if `points` and `attributes` are not the same length.
+///
+pub fn fit_curve_with_attributes<Curve>(points: &[Curve::Point], attributes: &[f64], max_error: f64) -> Option<Vec<(Curve, (f64, f64))>>
+where
+    Curve: BezierCurveFactory + BezierCurve
+{
+    if points.len() != attributes.len() {
+        return None;
+    }
+
+    fit_curve_indexed(points, max_error, None)
+        .map(|curves| curves.into_iter()
+            .map(|(curve, start_idx, end_idx)| (curve, (attributes[start_idx], attributes[end_idx])))
+            .collect())
+}
+
+///
+/// A line that a set of points can be mirrored across
+///
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SymmetryAxis {
+    /// A vertical line at the specified x coordinate
+    Vertical(f64),
+
+    /// A horizontal line at the specified y coordinate
+    Horizontal(f64),
+}
+
+impl SymmetryAxis {
+    ///
+    /// Reflects a point across this axis
+    ///
+    pub fn mirror<Point: Coordinate+Coordinate2D>(&self, point: &Point) -> Point {
+        match self {
+            SymmetryAxis::Vertical(x)   => Point::from_components(&[2.0*x - point.x(), point.y()]),
+            SymmetryAxis::Horizontal(y) => Point::from_components(&[point.x(), 2.0*y - point.y()]),
+        }
+    }
+
+    ///
+    /// Moves a point onto this axis
+    ///
+    fn project<Point: Coordinate+Coordinate2D>(&self, point: &Point) -> Point {
+        match self {
+            SymmetryAxis::Vertical(x)   => Point::from_components(&[*x, point.y()]),
+            SymmetryAxis::Horizontal(y) => Point::from_components(&[point.x(), *y]),
+        }
+    }
+
+    ///
+    /// A unit vector perpendicular to this axis
+    ///
+    fn normal<Point: Coordinate+Coordinate2D>(&self) -> Point {
+        match self {
+            SymmetryAxis::Vertical(_)   => Point::from_components(&[1.0, 0.0]),
+            SymmetryAxis::Horizontal(_) => Point::from_components(&[0.0, 1.0]),
+        }
+    }
+}
+
+///
+/// Fits bezier curves to a set of points, optionally forcing the result to be symmetrical
+///
+/// When `symmetry` is `None`, this is the same as `fit_curve()`. Otherwise the points should be approximately symmetrical about
+/// the axis, with the second half of the points mirroring the first half in reverse order (for example, the outline of a 
+/// symmetrical glyph drawn from one side to the other). The first half of the points is averaged with the mirrored second half
+/// and fitted, and the resulting curves are mirrored to produce the second half, so the result is exactly symmetrical and
+/// crosses the axis at a right angle.
+///
+pub fn fit_curve_with_symmetry<Curve>(points: &[Curve::Point], max_error: f64, symmetry: Option<SymmetryAxis>) -> Option<Vec<Curve>>
+where
+    Curve:          BezierCurveFactory + BezierCurve,
+    Curve::Point:   Coordinate + Coordinate2D,
+{
+    let axis = if let Some(axis) = symmetry { axis } else { return fit_curve(points, max_error); };
+
+    if points.len() < 2 {
+        return None;
+    }
+
+    // Average each point in the first half with its mirror image in the second half
+    let num_points      = points.len();
+    let half_len        = num_points / 2;
+    let mut half        = (0..half_len)
+        .map(|idx| (points[idx] + axis.mirror(&points[num_points-1-idx])) * 0.5)
+        .collect::<Vec<_>>();
+
+    // The half ends on the axis: at the middle point if there is one, or between the two middle points otherwise
+    let middle          = if num_points % 2 == 1 { points[half_len] } else { (points[half_len-1] + points[half_len]) * 0.5 };
+    half.push(axis.project(&middle));
+
+    // The curve must cross the axis at right angles for the two halves to join smoothly
+    let normal: Curve::Point    = axis.normal();
+    let end_tangent             = if (half[half.len()-2] - half[half.len()-1]).dot(&normal) < 0.0 { normal * -1.0 } else { normal };
+    let first_half              = fit_curve_indexed::<Curve>(&half, max_error, Some(end_tangent))?
+        .into_iter()
+        .map(|(curve, _start_idx, _end_idx)| curve)
+        .collect::<Vec<_>>();
+
+    // Mirror the first half to generate the second half
+    let second_half             = first_half.iter().rev()
+        .map(|curve| {
+            let (cp1, cp2) = curve.control_points();
+            Curve::from_points(axis.mirror(&curve.end_point()), (axis.mirror(&cp2), axis.mirror(&cp1)), axis.mirror(&curve.start_point()))
+        })
+        .collect::<Vec<_>>();
+
+    Some(first_half.into_iter().chain(second_half).collect())
+}
+
+///
+/// Fits bezier curves to a set of points, returning the curves along with the indexes of the points at their start and end
+///
+/// The tangent at the end of the last curve is estimated from the points, unless one is supplied in `final_end_tangent`.
+///
+fn fit_curve_indexed<Curve>(points: &[Curve::Point], max_error: f64, final_end_tangent: Option<Curve::Point>) -> Option<Vec<(Curve, usize, usize)>>
 where
     Curve: BezierCurveFactory + BezierCurve
 {
@@ -55,11 +185,13 @@ where
             let start_tangent   = start_tangent(block_points);
             let end_tangent     = if start_point+num_points < points.len() {
                 end_tangent(&points[start_point..start_point+num_points+1])
+            } else if let Some(final_end_tangent) = final_end_tangent {
+                final_end_tangent
             } else { 
                 end_tangent(block_points) 
             };
 
-            let fit = fit_curve_cubic(block_points, &start_tangent, &end_tangent, max_error);
+            let fit = fit_curve_cubic_indexed(block_points, &start_tangent, &end_tangent, max_error, start_point);
             for curve in fit {
                 curves.push(curve);
             }
@@ -85,10 +217,32 @@ where
 /// The algorithm here is to attempt to fit a single bezier curve against the points, estimate the point which has the highest error, and if too high
 /// subdivide at that point and try again.
 ///
+/// The result is empty if there are fewer than 2 points.
+///
 pub fn fit_curve_cubic<Curve: BezierCurveFactory+BezierCurve>(points: &[Curve::Point], start_tangent: &Curve::Point, end_tangent: &Curve::Point, max_error: f64) -> Vec<Curve> {
-    if points.len() <= 2 {
-        // 2 points is a line (less than 2 points is an error here)
-        fit_line(&points[0], &points[1])
+    fit_curve_cubic_indexed(points, start_tangent, end_tangent, max_error, 0)
+        .into_iter()
+        .map(|(curve, _start_idx, _end_idx)| curve)
+        .collect()
+}
+
+///
+/// Performs the same fit as `fit_curve_cubic`, but also returns the indexes of the points at the start and end of each curve
+///
+/// `first_idx` is the index of the first point in `points` (used so that the indexes returned refer to the full set of points)
+///
+fn fit_curve_cubic_indexed<Curve: BezierCurveFactory+BezierCurve>(points: &[Curve::Point], start_tangent: &Curve::Point, end_tangent: &Curve::Point, max_error: f64, first_idx: usize) -> Vec<(Curve, usize, usize)> {
+    if points.len() < 2 {
+        // Can't fit a curve to fewer than 2 points
+        vec![]
+    } else if points.len() == 2 {
+        // 2 points is a line
+        fit_line(&points[0], &points[1]).into_iter()
+            .map(|line| (line, first_idx, first_idx+1))
+            .collect()
+    } else if points.iter().all(|point| *point == points[0]) {
+        // All the points are in the same place: the chord lengths and tangents can't be calculated, so the fit is a single point
+        vec![(Curve::from_points(points[0], (points[0], points[0]), points[0]), first_idx, first_idx+points.len()-1)]
     } else {
         // Perform an initial estimate of the 't' values corresponding to the chords of the curve
         let mut chords                  = chords_for_points(points);
@@ -123,14 +277,14 @@ pub fn fit_curve_cubic<Curve: BezierCurveFactory+BezierCurve>(points: &[Curve::P
 
         if error <= max_error {
             // We've generated a curve within the error bounds
-            vec![curve]
+            vec![(curve, first_idx, first_idx+points.len()-1)]
         } else {
             // If error still too large, split the points and create two curves
             let center_tangent = tangent_between(&points[split_pos-1], &points[split_pos], &points[split_pos+1]);
 
             // Fit the two sides
-            let lhs = fit_curve_cubic(&points[0..split_pos+1], start_tangent, &center_tangent, max_error);
-            let rhs = fit_curve_cubic(&points[split_pos..points.len()], &(center_tangent*-1.0), end_tangent, max_error);
+            let lhs = fit_curve_cubic_indexed(&points[0..split_pos+1], start_tangent, &center_tangent, max_error, first_idx);
+            let rhs = fit_curve_cubic_indexed(&points[split_pos..points.len()], &(center_tangent*-1.0), end_tangent, max_error, first_idx+split_pos);
 
             // Collect the result
             lhs.into_iter().chain(rhs.into_iter()).collect()
@@ -0,0 +1,78 @@
+use super::curve::*;
+use super::oriented_bounds::*;
+use crate::geo::*;
+
+///
+/// Clips a convex polygon so that only the part to the left of the line from `edge_start` to `edge_end` is left
+///
+fn clip_polygon_to_edge<Point: Coordinate+Coordinate2D>(polygon: &[Point], edge_start: &Point, edge_end: &Point) -> Vec<Point> {
+    let mut clipped = vec![];
+
+    for idx in 0..polygon.len() {
+        let point       = polygon[idx];
+        let next_point  = polygon[(idx+1)%polygon.len()];
+        let side        = cross(edge_start, edge_end, &point);
+        let next_side   = cross(edge_start, edge_end, &next_point);
+
+        if side >= 0.0 {
+            clipped.push(point);
+        }
+
+        // Add the point where the polygon crosses the edge
+        if (side >= 0.0) != (next_side >= 0.0) {
+            let t = side / (side - next_side);
+            clipped.push(point + (next_point - point) * t);
+        }
+    }
+
+    clipped
+}
+
+///
+/// Finds the area of a polygon
+///
+fn polygon_area<Point: Coordinate+Coordinate2D>(polygon: &[Point]) -> f64 {
+    let twice_area = (0..polygon.len())
+        .map(|idx| {
+            let (p1, p2) = (&polygon[idx], &polygon[(idx+1)%polygon.len()]);
+            p1.x()*p2.y() - p2.x()*p1.y()
+        })
+        .sum::<f64>();
+
+    twice_area.abs() / 2.0
+}
+
+///
+/// Returns the area where the convex hulls of the control points of two curves overlap
+///
+/// A curve is always inside the convex hull of its control points, so curves with hulls that don't overlap can't intersect.
+/// The size of the overlap is a rough measure of how much two curves might overlap, which can be used to decide which pairs
+/// of curves to check for intersections first, or to estimate how serious a collision is. Curves with control points that
+/// are all on a line have a hull with no area, so the result is always 0 for these.
+///
+pub fn hull_overlap_area<C1, C2>(curve1: &C1, curve2: &C2) -> f64
+where
+    C1:         BezierCurve,
+    C2:         BezierCurve<Point=C1::Point>,
+    C1::Point:  Coordinate+Coordinate2D,
+{
+    let hull1       = convex_hull(&curve1.control_point_array());
+    let hull2       = convex_hull(&curve2.control_point_array());
+
+    if hull1.len() < 3 || hull2.len() < 3 {
+        return 0.0;
+    }
+
+    // The hulls are anticlockwise, so clip the first hull against the inside of every edge of the second
+    let mut overlap = hull1;
+
+    for idx in 0..hull2.len() {
+        overlap = clip_polygon_to_edge(&overlap, &hull2[idx], &hull2[(idx+1)%hull2.len()]);
+
+        if overlap.len() < 3 {
+            return 0.0;
+        }
+    }
+
+    polygon_area(&overlap)
+}
@@ -0,0 +1,68 @@
+use super::super::curve::*;
+use super::super::basis::*;
+use super::super::roots::*;
+use crate::geo::*;
+
+use smallvec::*;
+
+///
+/// Multiplies two polynomials (with coefficients in ascending order of power)
+///
+#[inline]
+fn multiply_cubics(p1: &[f64; 4], p2: &[f64; 4]) -> [f64; 7] {
+    let mut result = [0.0; 7];
+
+    for (i1, c1) in p1.iter().enumerate() {
+        for (i2, c2) in p2.iter().enumerate() {
+            result[i1+i2] += c1*c2;
+        }
+    }
+
+    result
+}
+
+///
+/// Finds the t values where a curve crosses a circle
+///
+/// This substitutes the curve into the equation for the circle, `(x-cx)^2 + (y-cy)^2 = r^2`, and solves the resulting
+/// degree 6 polynomial using the bezier root finder. The t values are returned in ascending order. Points where the curve
+/// just touches the circle without crossing it may not be reported.
+///
+pub fn curve_intersects_circle<C: BezierCurve>(curve: &C, center: &C::Point, radius: f64) -> SmallVec<[f64; 6]>
+where
+    C::Point: Coordinate2D,
+{
+    // Compute the polynomial forms of x(t)-cx and y(t)-cy
+    let w1          = curve.start_point();
+    let (w2, w3)    = curve.control_points();
+    let w4          = curve.end_point();
+
+    let (ax, bx, cx, dx) = bezier_coefficients(0, &w1, &w2, &w3, &w4);
+    let (ay, by, cy, dy) = bezier_coefficients(1, &w1, &w2, &w3, &w4);
+
+    let x_poly      = [dx - center.x(), cx, bx, ax];
+    let y_poly      = [dy - center.y(), cy, by, ay];
+
+    // (x(t)-cx)^2 + (y(t)-cy)^2 - r^2 = 0 is the polynomial we need to solve
+    let x_squared   = multiply_cubics(&x_poly, &x_poly);
+    let y_squared   = multiply_cubics(&y_poly, &y_poly);
+
+    let mut circle_poly = [0.0; 7];
+    for idx in 0..7 {
+        circle_poly[idx] = x_squared[idx] + y_squared[idx];
+    }
+    circle_poly[0] -= radius*radius;
+
+    // Normalise the polynomial so that the flatness test for the root finder is independent of the size of the circle
+    let max_coefficient = circle_poly.iter().fold(0.0f64, |max, c| max.max(c.abs()));
+    if max_coefficient == 0.0 {
+        return smallvec![];
+    }
+
+    for coefficient in circle_poly.iter_mut() {
+        *coefficient /= max_coefficient;
+    }
+
+    // Find the roots of the polynomial in the range 0..1
+    find_roots_poly(&circle_poly).into_iter().collect()
+}
@@ -6,6 +6,8 @@ use crate::bezier::solve::*;
 
 use smallvec::*;
 
+use std::cmp::{Ordering};
+
 ///
 /// Determines the length of a curve's hull as a sum of squares
 /// 
@@ -300,6 +302,8 @@ where
 /// Determines the points at which two curves intersect using the Bezier clipping
 /// algorihtm
 /// 
+/// The intersections are returned as `(curve1_t, curve2_t)` pairs, sorted in ascending order of `curve1_t`.
+///
 pub fn curve_intersects_curve_clip<'a, C: BezierCurve>(curve1: &'a C, curve2: &'a C, accuracy: f64) -> SmallVec<[(f64, f64); 8]>
 where 
     C::Point: 'a+Coordinate2D,
@@ -309,5 +313,60 @@ where
     let curve2 = curve2.section(0.0, 1.0);
 
     // Perform the clipping algorithm on these curves
-    curve_intersects_curve_clip_inner(curve1, curve2, accuracy, accuracy*accuracy)
+    let mut intersections = curve_intersects_curve_clip_inner(curve1, curve2, accuracy, accuracy*accuracy);
+
+    // Return the intersections in order along the first curve
+    intersections.sort_by(|(t1a, _), (t1b, _)| t1a.partial_cmp(t1b).unwrap_or(Ordering::Equal));
+
+    intersections
+}
+
+///
+/// Returns true if two curves cannot possibly intersect
+///
+/// A curve is always inside the convex hull of its control points, so the curves can't intersect if there's a line that
+/// separates their control polygons. This tries projecting both sets of control points onto the normal of every line
+/// between two of the control points of either curve (which includes the edges of both convex hulls), and returns true if 
+/// any of these projections do not overlap.
+///
+/// This is a cheap test that can be used to reject pairs of curves before calling `curve_intersects_curve_clip()`: it
+/// can find more separated curves than comparing bounding boxes when the curves are diagonal. When this returns false,
+/// the curves may or may not intersect.
+///
+pub fn curves_definitely_disjoint<C1, C2>(curve1: &C1, curve2: &C2) -> bool
+where
+    C1:         BezierCurve,
+    C2:         BezierCurve<Point=C1::Point>,
+    C1::Point:  Coordinate2D,
+{
+    let points1 = curve1.control_point_array();
+    let points2 = curve2.control_point_array();
+
+    // Finds the range covered by a set of points when projected onto an axis
+    let project = |points: &[C1::Point; 4], axis: (f64, f64)| {
+        points.iter()
+            .map(|point| point.x()*axis.0 + point.y()*axis.1)
+            .fold((f64::MAX, f64::MIN), |(min, max), pos| (min.min(pos), max.max(pos)))
+    };
+
+    // Try each of the axes made by joining two control points from the same curve
+    for points in [&points1, &points2].iter() {
+        for first_idx in 0..4 {
+            for second_idx in (first_idx+1)..4 {
+                let direction   = points[second_idx] - points[first_idx];
+                let axis        = (-direction.y(), direction.x());
+
+                if axis.0 == 0.0 && axis.1 == 0.0 { continue; }
+
+                let (min1, max1) = project(&points1, axis);
+                let (min2, max2) = project(&points2, axis);
+
+                if max1 < min2 || max2 < min1 {
+                    return true;
+                }
+            }
+        }
+    }
+
+    false
 }
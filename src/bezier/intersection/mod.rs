@@ -1,8 +1,10 @@
 mod curve_line;
+mod curve_circle;
 mod curve_curve_clip;
 mod fat_line;
 mod self_intersection;
 
 pub use self::curve_line::*;
+pub use self::curve_circle::*;
 pub use self::curve_curve_clip::*;
 pub use self::self_intersection::*;
@@ -2,6 +2,11 @@ use super::curve::*;
 use super::section::*;
 use crate::geo::*;
 
+use std::cmp::{Ordering};
+
+/// Number of bisection steps used when searching for the t value at a particular length along a curve
+const LENGTH_SEARCH_ITERATIONS: usize = 48;
+
 ///
 /// Returns the length of the control polygon for a bezier curve
 ///
@@ -32,6 +37,68 @@ pub fn curve_length<Curve: BezierCurve>(curve: &Curve, max_error: f64) -> f64 {
     section_length(curve.section(0.0, 1.0), max_error)
 }
 
+///
+/// Measures the length of a bezier curve, guaranteeing that the result is within `max_error` of the true length
+///
+/// The length of a curve is always between the length of its chord and the length of its control polygon. This subdivides
+/// the curve until the sum of the differences between these two lengths for every section is less than `2*max_error`, which
+/// means that the average of the two is within `max_error` of the true length. The error in `curve_length()` is instead 
+/// measured per-section, which is faster but does not place a limit on the error for the whole curve.
+///
+/// To stop rounding errors from causing endless subdivision, sections are not divided any further once their share of the
+/// error budget is below `1e-12`. The bound therefore isn't guaranteed for extremely small values of `max_error`, or for
+/// curves that need to be divided so many times that their sections reach this limit.
+///
+pub fn curve_length_with_error_bound<Curve: BezierCurve>(curve: &Curve, max_error: f64) -> f64 {
+    // Sections with an error budget less than this are not subdivided any further
+    const MIN_ERROR: f64 = 1e-12;
+
+    let mut waiting         = vec![(curve.section(0.0, 1.0), max_error)];
+    let mut total_length    = 0.0;
+
+    while let Some((section, max_error)) = waiting.pop() {
+        let polygon_length  = control_polygon_length(&section);
+        let chord_length    = chord_length(&section);
+
+        // The average of the two lengths is at most half of the difference between them away from the true length
+        if (polygon_length - chord_length) / 2.0 <= max_error || max_error <= MIN_ERROR {
+            total_length += (polygon_length + chord_length) / 2.0;
+        } else {
+            // Each half of the section gets half of the error budget, so the total error stays within the original budget
+            waiting.push((section.subsection(0.0, 0.5), max_error / 2.0));
+            waiting.push((section.subsection(0.5, 1.0), max_error / 2.0));
+        }
+    }
+
+    total_length
+}
+
+///
+/// Finds the t value at a particular distance along a curve
+///
+pub (crate) fn t_for_curve_length<C: BezierCurve>(curve: &C, length: f64, tolerance: f64) -> f64 {
+    if length <= 0.0 {
+        return 0.0;
+    }
+
+    // The length of a section increases with t, so we can bisect to find the position
+    let mut min_t = 0.0;
+    let mut max_t = 1.0;
+
+    for _ in 0..LENGTH_SEARCH_ITERATIONS {
+        let mid_t           = (min_t + max_t) / 2.0;
+        let section_length  = curve_length(&curve.section(0.0, mid_t), tolerance);
+
+        if section_length < length {
+            min_t = mid_t;
+        } else {
+            max_t = mid_t;
+        }
+    }
+
+    (min_t + max_t) / 2.0
+}
+
 ///
 /// Computes the length of a section of a bezier curve
 ///
@@ -71,3 +138,98 @@ where
 
     total_length
 }
+
+/// Maximum error when measuring the length of each section of curve in an arc length table
+const ARC_LENGTH_TABLE_MAX_ERROR: f64 = 1e-7;
+
+/// Number of Newton-Raphson steps used to refine the t values found from an arc length table
+const ARC_LENGTH_TABLE_REFINE_STEPS: usize = 4;
+
+///
+/// A lookup table that maps distances along a curve to t values
+///
+/// Building the table measures the length of the curve between a set of evenly spaced t values, after which finding the t
+/// value for any distance is fast. This is useful for moving things along a curve at a constant speed, where
+/// `t_for_distance()` will be called many times for the same curve.
+///
+#[derive(Clone, Debug)]
+pub struct ArcLengthTable<Curve: BezierCurve> {
+    /// The curve that this table is for
+    curve: Curve,
+
+    /// The length of the curve at each sample point (these are at evenly-spaced t values)
+    lengths: Vec<f64>
+}
+
+impl<Curve: BezierCurve> ArcLengthTable<Curve> {
+    ///
+    /// Creates an arc length table for a curve, measuring the length at `samples` evenly-spaced intervals
+    ///
+    /// More samples produce a better first estimate of the t value for any given distance, but take longer to build.
+    ///
+    pub fn new(curve: &Curve, samples: usize) -> ArcLengthTable<Curve> {
+        let samples     = samples.max(1);
+        let mut lengths = Vec::with_capacity(samples+1);
+        let mut length  = 0.0;
+
+        lengths.push(0.0);
+        for sample_idx in 0..samples {
+            let t_min   = (sample_idx as f64) / (samples as f64);
+            let t_max   = ((sample_idx+1) as f64) / (samples as f64);
+
+            length      += curve_length_with_error_bound(&curve.section(t_min, t_max), ARC_LENGTH_TABLE_MAX_ERROR);
+            lengths.push(length);
+        }
+
+        ArcLengthTable {
+            curve:      curve.clone(),
+            lengths:    lengths
+        }
+    }
+
+    ///
+    /// The total length of the curve
+    ///
+    pub fn total_length(&self) -> f64 {
+        self.lengths[self.lengths.len()-1]
+    }
+
+    ///
+    /// Finds the t value at a particular distance along the curve
+    ///
+    /// Distances less than 0 or greater than the length of the curve return the t value at the start or end of the curve.
+    ///
+    pub fn t_for_distance(&self, distance: f64) -> f64 {
+        let num_samples = self.lengths.len()-1;
+
+        if distance <= 0.0 { return 0.0; }
+        if distance >= self.total_length() { return 1.0; }
+
+        // Find the sample that contains this distance (the first sample is always at length 0)
+        let sample_idx  = match self.lengths.binary_search_by(|length| length.partial_cmp(&distance).unwrap_or(Ordering::Less)) {
+            Ok(idx)     => return (idx as f64) / (num_samples as f64),
+            Err(idx)    => idx-1
+        };
+
+        let t_min       = (sample_idx as f64) / (num_samples as f64);
+        let t_max       = ((sample_idx+1) as f64) / (num_samples as f64);
+        let min_length  = self.lengths[sample_idx];
+        let max_length  = self.lengths[sample_idx+1];
+
+        // Interpolate between the samples for the initial estimate
+        let ratio       = (distance - min_length) / (max_length - min_length);
+        let mut t       = t_min + (t_max - t_min) * ratio;
+
+        // Refine with Newton-Raphson: the derivative of the length is the speed of the curve
+        for _ in 0..ARC_LENGTH_TABLE_REFINE_STEPS {
+            let length  = min_length + curve_length_with_error_bound(&self.curve.section(t_min, t), ARC_LENGTH_TABLE_MAX_ERROR);
+            let speed   = self.curve.speed_at_pos(t);
+
+            if speed <= 0.0 { break; }
+
+            t = (t - (length - distance) / speed).clamp(t_min, t_max);
+        }
+
+        t
+    }
+}
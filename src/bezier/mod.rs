@@ -24,6 +24,7 @@
 //!
 
 mod curve;
+mod quadratic;
 mod section;
 mod basis;
 mod subdivide;
@@ -31,6 +32,8 @@ mod derivative;
 mod tangent;
 mod normal;
 mod bounds;
+mod oriented_bounds;
+mod hull_overlap;
 mod deform;
 mod fit;
 mod offset;
@@ -44,12 +47,18 @@ mod characteristics;
 mod length;
 mod walk;
 mod distort;
+mod reflect;
+mod transform;
 mod nearest_point;
+mod continuity;
+mod extend;
+mod curvature;
 pub (crate) mod roots;
 
 pub mod path;
 
 pub use self::curve::*;
+pub use self::quadratic::*;
 pub use self::section::*;
 pub use self::basis::*;
 pub use self::subdivide::*;
@@ -57,6 +66,8 @@ pub use self::derivative::*;
 pub use self::tangent::*;
 pub use self::normal::*;
 pub use self::bounds::*;
+pub use self::oriented_bounds::*;
+pub use self::hull_overlap::*;
 pub use self::deform::*;
 pub use self::fit::*;
 pub use self::offset::*;
@@ -70,6 +81,11 @@ pub use self::characteristics::*;
 pub use self::length::*;
 pub use self::walk::*;
 pub use self::distort::*;
+pub use self::reflect::*;
 pub use self::nearest_point::*;
+pub use self::continuity::*;
+pub use self::extend::*;
+pub use self::curvature::*;
+pub use self::roots::{find_bezier_roots, find_bezier_roots_with_tolerance, find_bezier_roots_with_sign, find_roots_poly, CrossingDirection};
 
 pub use super::geo::*;
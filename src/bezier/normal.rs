@@ -75,6 +75,43 @@ pub trait NormalCurve : BezierCurve {
     /// In the event that the curve represents a point, this will return the vector (0,0)
     ///
     fn normal_at_pos(&self, t: f64) -> Self::Point;
+
+    ///
+    /// Returns the unit tangent and unit normal at a point on the curve, which form a local frame for the curve at that point
+    ///
+    /// In two dimensions the normal is always the tangent rotated by 90 degrees, so unlike the classical Frenet frame (where
+    /// the normal points towards the center of curvature) it doesn't flip over at inflection points. If the curve has no
+    /// tangent at `t`, (for example because the curve is a single point) both vectors are the origin.
+    ///
+    fn frenet_frame_at(&self, t: f64) -> (Self::Point, Self::Point) {
+        (self.tangent_at_pos(t).to_unit_vector(), self.normal_at_pos(t).to_unit_vector())
+    }
+
+    ///
+    /// Returns the position, unit tangent and unit normal at `count` evenly spaced t values along the curve
+    ///
+    /// This is useful for extruding a shape along a curve. The frames change smoothly along the curve: where the tangent
+    /// can't be found (for example, at a cusp), the frame from the previous sample is used again. Asking for 0 samples
+    /// returns an empty list, and asking for 1 sample returns the frame at the start of the curve.
+    ///
+    fn frenet_frames(&self, count: usize) -> Vec<(Self::Point, Self::Point, Self::Point)> {
+        let mut frames: Vec<(Self::Point, Self::Point, Self::Point)> = Vec::with_capacity(count);
+
+        for idx in 0..count {
+            let t                   = if count > 1 { (idx as f64) / ((count-1) as f64) } else { 0.0 };
+            let (tangent, normal)   = self.frenet_frame_at(t);
+
+            // Reuse the last frame if there's no tangent at this point
+            let (tangent, normal)   = match frames.last() {
+                Some((_, last_tangent, last_normal)) if tangent.magnitude() == 0.0  => (*last_tangent, *last_normal),
+                _                                                                   => (tangent, normal)
+            };
+
+            frames.push((self.point_at_pos(t), tangent, normal));
+        }
+
+        frames
+    }
 }
 
 impl<Curve: BezierCurve> NormalCurve for Curve
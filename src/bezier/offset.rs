@@ -1,8 +1,15 @@
 use super::curve::*;
+use super::basis::*;
 use super::normal::*;
 use super::offset_lms::*;
 use super::super::geo::*;
 
+/// The maximum number of times `offset_with_accuracy()` will subdivide a curve
+const OFFSET_ACCURACY_MAX_DEPTH: usize = 8;
+
+/// The number of samples used to measure the error of each section generated by `offset_with_accuracy()`
+const OFFSET_ACCURACY_SAMPLES: usize = 16;
+
 ///
 /// Computes a series of curves that approximate an offset curve from the specified origin curve.
 ///
@@ -14,3 +21,96 @@ where
     offset_lms_sampling(curve, move |t| (final_offset - initial_offset) * t + initial_offset, |_| 0.0, 32, 0.1)
         .unwrap_or_else(|| vec![])
 }
+
+///
+/// Computes a series of curves that approximate an offset curve where the offset distance follows a cubic profile
+///
+/// The offset distance at `t` is the cubic bezier with the values `start_offset`, `control1`, `control2` and `end_offset`,
+/// which makes it possible to produce calligraphic strokes that swell and taper along the curve (the two sides of a stroke
+/// are generated by offsetting with the profile and with its negation). The profile can cross zero, in which case the offset
+/// curve crosses the original curve at that point.
+///
+pub fn offset_variable<Curve>(curve: &Curve, start_offset: f64, end_offset: f64, control1: f64, control2: f64) -> Vec<Curve>
+where
+    Curve:          BezierCurveFactory+NormalCurve,
+    Curve::Point:   Normalize+Coordinate2D,
+{
+    offset_lms_sampling(curve, move |t| basis(t, start_offset, control1, control2, end_offset), |_| 0.0, 32, 0.1)
+        .unwrap_or_else(Vec::new)
+}
+
+///
+/// Measures how far a set of curves generated by offsetting a curve deviates from the true offset curve
+///
+/// The source curve is sampled at `samples` evenly spaced t values (at least 2 samples are always taken). Each sample is moved 
+/// `distance` units along the normal to find where the true offset curve is, and the result is the largest distance between 
+/// one of these points and the nearest point on the offset curves. This can be used to decide if an offset curve needs to be 
+/// subdivided further.
+///
+pub fn offset_error<Curve>(curve: &Curve, offset_curves: &[Curve], distance: f64, samples: usize) -> f64
+where
+    Curve:          BezierCurve+NormalCurve,
+    Curve::Point:   Normalize+Coordinate2D,
+{
+    // An empty offset can't be near to the true offset
+    if offset_curves.is_empty() { return f64::MAX; }
+
+    let samples = samples.max(2);
+
+    (0..samples)
+        .map(|sample_idx| {
+            // Find the point on the true offset curve
+            let t               = (sample_idx as f64) / ((samples-1) as f64);
+            let original_point  = curve.point_at_pos(t);
+            let unit_tangent    = curve.tangent_at_pos(t).to_unit_vector();
+            let unit_normal     = Curve::Point::to_normal(&original_point, &unit_tangent);
+            let unit_normal     = Curve::Point::from_components(&unit_normal);
+            let expected_point  = original_point + (unit_normal * distance);
+
+            // Measure how far away the nearest point on the offset curve is
+            offset_curves.iter()
+                .map(|offset_curve| offset_curve.distance_to(&expected_point))
+                .fold(f64::MAX, f64::min)
+        })
+        .fold(0.0, f64::max)
+}
+
+///
+/// Offsets a section of a curve, subdividing it until the error is below `max_error` or the maximum depth is reached
+///
+fn offset_section_with_accuracy<Curve>(curve: &Curve, distance: f64, max_error: f64, depth: usize, result: &mut Vec<Curve>)
+where
+    Curve:          BezierCurveFactory+NormalCurve,
+    Curve::Point:   Normalize+Coordinate2D,
+{
+    let offset_curves = offset(curve, distance, distance);
+
+    if depth >= OFFSET_ACCURACY_MAX_DEPTH || offset_error(curve, &offset_curves, distance, OFFSET_ACCURACY_SAMPLES) <= max_error {
+        result.extend(offset_curves);
+    } else {
+        let (first_half, second_half) = curve.subdivide::<Curve>(0.5);
+
+        offset_section_with_accuracy(&first_half, distance, max_error, depth+1, result);
+        offset_section_with_accuracy(&second_half, distance, max_error, depth+1, result);
+    }
+}
+
+///
+/// Computes a series of curves that approximate an offset curve from the specified origin curve, to within a maximum error
+///
+/// The offset from `offset()` can wobble away from the true offset curve where the original curve bends sharply. This 
+/// measures the error of the offset (using `offset_error()`) and subdivides the original curve where it's more than 
+/// `max_error`, offsetting each section separately. Sections are subdivided at most 8 times, so very small values of 
+/// `max_error`, or places where the offset curve has a cusp (where the offset distance is larger than the radius of 
+/// curvature), may still have a larger error.
+///
+pub fn offset_with_accuracy<Curve>(curve: &Curve, distance: f64, max_error: f64) -> Vec<Curve>
+where
+    Curve:          BezierCurveFactory+NormalCurve,
+    Curve::Point:   Normalize+Coordinate2D,
+{
+    let mut result = vec![];
+    offset_section_with_accuracy(curve, distance, max_error, 0, &mut result);
+
+    result
+}
@@ -0,0 +1,156 @@
+use super::curve::*;
+use super::bounds::*;
+use crate::geo::*;
+
+use std::cmp::{Ordering};
+
+/// Number of points sampled from a curve to find the orientation of its bounding box
+const ORIENTED_BOUNDS_SAMPLES: usize = 64;
+
+///
+/// A rectangle that can be rotated to any angle
+///
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct OrientedBox<Point> {
+    /// The center of the box
+    pub center: Point,
+
+    /// Unit vectors along the two sides of the box (these are perpendicular to each other)
+    pub axes: (Point, Point),
+
+    /// Half the length of the box along each of its axes
+    pub half_extents: (f64, f64),
+}
+
+impl<Point: Coordinate+Coordinate2D> OrientedBox<Point> {
+    ///
+    /// The area of this box
+    ///
+    pub fn area(&self) -> f64 {
+        4.0 * self.half_extents.0 * self.half_extents.1
+    }
+
+    ///
+    /// The corners of this box, in order around its edge
+    ///
+    pub fn corners(&self) -> [Point; 4] {
+        let side1 = self.axes.0 * self.half_extents.0;
+        let side2 = self.axes.1 * self.half_extents.1;
+
+        [
+            self.center - side1 - side2,
+            self.center + side1 - side2,
+            self.center + side1 + side2,
+            self.center - side1 + side2,
+        ]
+    }
+
+    ///
+    /// Returns true if a point is inside this box (or on its edge)
+    ///
+    pub fn contains_point(&self, point: &Point) -> bool {
+        let offset = *point - self.center;
+
+        offset.dot(&self.axes.0).abs() <= self.half_extents.0 && offset.dot(&self.axes.1).abs() <= self.half_extents.1
+    }
+}
+
+///
+/// The z component of the cross product of `p1-origin` and `p2-origin`
+///
+#[inline]
+pub (crate) fn cross<Point: Coordinate2D>(origin: &Point, p1: &Point, p2: &Point) -> f64 {
+    (p1.x()-origin.x())*(p2.y()-origin.y()) - (p1.y()-origin.y())*(p2.x()-origin.x())
+}
+
+///
+/// Finds the convex hull of a set of points using the monotone chain algorithm
+///
+/// The hull is returned in anticlockwise order, with collinear points removed
+///
+pub (crate) fn convex_hull<Point: Coordinate+Coordinate2D>(points: &[Point]) -> Vec<Point> {
+    let mut points = points.to_vec();
+    points.sort_by(|a, b| a.x().partial_cmp(&b.x()).unwrap_or(Ordering::Equal).then(a.y().partial_cmp(&b.y()).unwrap_or(Ordering::Equal)));
+    points.dedup();
+
+    if points.len() < 3 {
+        return points;
+    }
+
+    // Build the lower hull followed by the upper hull
+    let mut hull: Vec<Point> = vec![];
+
+    for pass in 0..2 {
+        let start_len = hull.len();
+
+        for point in points.iter() {
+            while hull.len() >= start_len+2 && cross(&hull[hull.len()-2], &hull[hull.len()-1], point) <= 0.0 {
+                hull.pop();
+            }
+            hull.push(*point);
+        }
+
+        // The last point is the first point of the next pass
+        hull.pop();
+
+        if pass == 0 { points.reverse(); }
+    }
+
+    hull
+}
+
+///
+/// Creates the box aligned with a particular axis that contains a curve
+///
+fn curve_box_along_axis<C: BezierCurve>(curve: &C, axis: C::Point) -> OrientedBox<C::Point>
+where
+    C::Point: Coordinate+Coordinate2D,
+{
+    let perp_axis       = C::Point::from_components(&[-axis.y(), axis.x()]);
+    let start           = curve.start_point();
+    let end             = curve.end_point();
+    let (cp1, cp2)      = curve.control_points();
+
+    // Project the curve onto the axes and find the exact extent of the resulting 1D curves
+    let (min_u, max_u)  = bounding_box4::<f64, (f64, f64)>(start.dot(&axis), cp1.dot(&axis), cp2.dot(&axis), end.dot(&axis));
+    let (min_v, max_v)  = bounding_box4::<f64, (f64, f64)>(start.dot(&perp_axis), cp1.dot(&perp_axis), cp2.dot(&perp_axis), end.dot(&perp_axis));
+
+    OrientedBox {
+        center:         axis * ((min_u+max_u)/2.0) + perp_axis * ((min_v+max_v)/2.0),
+        axes:           (axis, perp_axis),
+        half_extents:   ((max_u-min_u)/2.0, (max_v-min_v)/2.0),
+    }
+}
+
+///
+/// Finds a rotated rectangle with the smallest area containing a curve
+///
+/// The curve is sampled to find the convex hull of the samples: the smallest box around a convex shape always has a side
+/// that's parallel to one of its edges (the observation behind the rotating calipers method), so a box is tried for each edge
+/// of the hull along with an axis-aligned box. The extent of each box is calculated from the curve itself, so the result always
+/// contains the whole curve and is never larger than the axis-aligned bounding box, although for very curved sections the 
+/// orientation may not be at precisely the minimum area.
+///
+pub fn curve_oriented_bounds<C: BezierCurve>(curve: &C) -> OrientedBox<C::Point>
+where
+    C::Point: Coordinate+Coordinate2D,
+{
+    // Sample the curve and find the hull of the samples
+    let samples     = (0..=ORIENTED_BOUNDS_SAMPLES).map(|idx| curve.point_at_pos((idx as f64) / (ORIENTED_BOUNDS_SAMPLES as f64))).collect::<Vec<_>>();
+    let hull        = convex_hull(&samples);
+
+    // Try aligning the box with every edge of the hull and pick the one with the smallest area
+    let mut best    = curve_box_along_axis(curve, C::Point::from_components(&[1.0, 0.0]));
+
+    for idx in 0..hull.len() {
+        let edge = hull[(idx+1)%hull.len()] - hull[idx];
+        if edge.magnitude() <= 0.0 { continue; }
+
+        let edge_box = curve_box_along_axis(curve, edge.to_unit_vector());
+        if edge_box.area() < best.area() {
+            best = edge_box;
+        }
+    }
+
+    best
+}
@@ -141,3 +141,35 @@ where   C1::Point:  Coordinate+Coordinate2D,
         None
     }
 }
+
+/// Number of points sampled along each curve when checking that two curves have the same shape
+const SAME_SHAPE_SAMPLES: usize = 16;
+
+///
+/// Returns true if two curves trace out the same shape, regardless of their direction or how they are parameterized
+///
+/// This compares the curves geometrically, rather than by their control points: for example, a curve and its reverse have
+/// the same shape, as do two straight lines between the same points with different control points. The curves must start
+/// and end at the same points (in either order), and every point sampled from each curve must be within `epsilon` of the
+/// other curve.
+///
+pub fn same_shape<C1, C2>(curve1: &C1, curve2: &C2, epsilon: f64) -> bool
+where
+    C1:         BezierCurve,
+    C2:         BezierCurve<Point=C1::Point>,
+    C1::Point:  Coordinate+Coordinate2D,
+{
+    // The end points must match, in one direction or the other
+    let forwards    = curve1.start_point().is_near_to(&curve2.start_point(), epsilon) && curve1.end_point().is_near_to(&curve2.end_point(), epsilon);
+    let backwards   = curve1.start_point().is_near_to(&curve2.end_point(), epsilon) && curve1.end_point().is_near_to(&curve2.start_point(), epsilon);
+
+    if !forwards && !backwards {
+        return false;
+    }
+
+    // Every point on each curve must be close to the other curve
+    let mut samples = (1..SAME_SHAPE_SAMPLES).map(|idx| (idx as f64) / (SAME_SHAPE_SAMPLES as f64));
+
+    samples.clone().all(|t| curve2.distance_to(&curve1.point_at_pos(t)) <= epsilon)
+        && samples.all(|t| curve1.distance_to(&curve2.point_at_pos(t)) <= epsilon)
+}
@@ -364,7 +364,7 @@ where
             }));
             
             // Remove any interior points that the path might have (this happens when the fill path overlaps itself)
-            Some(path_remove_interior_points(&vec![overlapped_path], 0.01))
+            Some(resolve_fill_regions(&[overlapped_path], FillRule::NonZero, options.interior_point_tolerance))
         } else {
             // No curves in the path
             None
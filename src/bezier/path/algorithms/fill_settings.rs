@@ -13,7 +13,10 @@ pub struct FillSettings {
     pub (crate) fit_error: f64,
 
     /// For concave fills, the minimum gap size that a fill can escape through
-    pub (crate) min_gap: Option<f64>
+    pub (crate) min_gap: Option<f64>,
+
+    /// For concave fills, the accuracy used when removing the interior points from the fill path
+    pub (crate) interior_point_tolerance: f64
 }
 
 impl FillSettings {
@@ -53,6 +56,18 @@ impl FillSettings {
         new_options.min_gap = new_min_gap;
         new_options
     }
+
+    ///
+    /// Sets the accuracy used when removing any overlapping sections from the result of a concave fill
+    ///
+    /// This should be scaled along with the size of the region being filled: a tolerance that's too large can merge
+    /// together small details in the fill, and one that's too small can leave overlapping sections in the result.
+    ///
+    pub fn with_interior_point_tolerance(self, new_interior_point_tolerance: f64) -> FillSettings {
+        let mut new_options = self;
+        new_options.interior_point_tolerance = new_interior_point_tolerance;
+        new_options
+    }
 }
 
 impl Default for FillSettings {
@@ -61,9 +76,10 @@ impl Default for FillSettings {
     ///
     fn default() -> FillSettings {
         FillSettings {
-            step:       2.0,
-            fit_error:  0.5,
-            min_gap:    Some(5.0)
+            step:                       2.0,
+            fit_error:                  0.5,
+            min_gap:                    Some(5.0),
+            interior_point_tolerance:   0.01
         }
     }    
 }
@@ -0,0 +1,87 @@
+use super::path::*;
+use super::to_curves::*;
+use super::super::curve::*;
+use super::super::walk::*;
+use super::super::super::geo::*;
+
+/// Number of sub-sections each curve in a path is divided into when estimating its arc length
+const SUBDIVISIONS_PER_CURVE: usize = 64;
+
+/// Number of points along the path to compare
+const NUM_SAMPLES: usize = 256;
+
+///
+/// Approximates a path as a polyline, returning the points along with the cumulative distance along the line at each point
+///
+fn path_polyline<P: BezierPath>(path: &P) -> Vec<(P::Point, f64)> {
+    let mut polyline    = vec![(path.start_point(), 0.0)];
+    let mut distance    = 0.0;
+    let mut last_point  = path.start_point();
+
+    for curve in path_to_curves::<_, Curve<_>>(path) {
+        for section in walk_curve_unevenly(&curve, SUBDIVISIONS_PER_CURVE) {
+            let next_point  = section.end_point();
+            distance        += last_point.distance_to(&next_point);
+            last_point      = next_point;
+
+            polyline.push((next_point, distance));
+        }
+    }
+
+    polyline
+}
+
+///
+/// Finds the point at a particular distance along a polyline generated by `path_polyline()`
+///
+fn point_at_distance<Point: Coordinate>(polyline: &[(Point, f64)], distance: f64, start_idx: &mut usize) -> Point {
+    // Advance to the section containing the distance (the samples are requested in order so we can carry on from the last position)
+    while *start_idx+2 < polyline.len() && polyline[*start_idx+1].1 < distance {
+        *start_idx += 1;
+    }
+
+    // Interpolate along the section of the line
+    let (p1, d1)    = polyline[*start_idx];
+    let (p2, d2)    = polyline[(*start_idx+1).min(polyline.len()-1)];
+
+    if d2 <= d1 {
+        p1
+    } else {
+        let t = ((distance - d1) / (d2 - d1)).clamp(0.0, 1.0);
+        p1 + (p2-p1)*t
+    }
+}
+
+///
+/// Determines if two paths describe approximately the same shape
+///
+/// This compares the paths geometrically rather than structurally: points are sampled at equal arc-length intervals along
+/// both paths and the paths are considered equal if no pair of corresponding points are more than `epsilon` apart. This means
+/// that paths made up of different numbers of curves can still compare as equal if they trace out the same shape.
+///
+/// The paths must start at the same point and run in the same direction to be considered equal.
+///
+pub fn paths_approx_equal<P: BezierPath>(a: &P, b: &P, epsilon: f64) -> bool {
+    // Approximate both paths as polylines
+    let polyline_a  = path_polyline(a);
+    let polyline_b  = path_polyline(b);
+
+    let length_a    = polyline_a[polyline_a.len()-1].1;
+    let length_b    = polyline_b[polyline_b.len()-1].1;
+
+    // Compare points at equal fractions of the length of each path
+    let mut idx_a   = 0;
+    let mut idx_b   = 0;
+
+    for sample in 0..=NUM_SAMPLES {
+        let fraction    = (sample as f64) / (NUM_SAMPLES as f64);
+        let point_a     = point_at_distance(&polyline_a, fraction * length_a, &mut idx_a);
+        let point_b     = point_at_distance(&polyline_b, fraction * length_b, &mut idx_b);
+
+        if !point_a.is_near_to(&point_b, epsilon) {
+            return false;
+        }
+    }
+
+    true
+}
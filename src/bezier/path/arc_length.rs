@@ -0,0 +1,251 @@
+use super::path::*;
+use super::to_curves::*;
+use super::super::curve::*;
+use super::super::length::*;
+use super::super::super::consts::*;
+
+use std::iter;
+
+/// Error bound passed to `curve_length()` by functions that don't take a tolerance
+const LENGTH_MAX_ERROR: f64 = 1e-8;
+
+///
+/// Returns the distance along a path to each of its anchor points
+///
+/// The first value is always 0 (the start point of the path), and there is one further value for the end point of each curve
+/// in the path, so the last value is the total length of the path. `tolerance` is passed to `curve_length()` when measuring
+/// each curve.
+///
+pub fn anchor_arc_positions<P: BezierPath>(path: &P, tolerance: f64) -> Vec<f64> {
+    let mut positions   = vec![0.0];
+    let mut distance    = 0.0;
+
+    for curve in path_to_curves::<_, Curve<_>>(path) {
+        distance += curve_length(&curve, tolerance);
+        positions.push(distance);
+    }
+
+    positions
+}
+
+///
+/// Returns the total length of the curves making up a path
+///
+/// This is the same as `BezierPath::approx_length()`: `tolerance` is passed to `curve_length()` when measuring each curve. Open
+/// paths are not closed before measuring them.
+///
+pub fn path_perimeter<P: BezierPath>(path: &P, tolerance: f64) -> f64 {
+    path.approx_length(tolerance)
+}
+
+///
+/// Changes the start point of a closed path so that it begins at a particular distance along the original path
+///
+/// The result describes the same shape as the original path, with its curves in a rotated order. The curve containing the new
+/// start point is split in two if the distance doesn't fall on an existing anchor point. Distances outside of the length of
+/// the path wrap around. The final point of the path is assumed to be the same as its start point.
+///
+pub fn reparameterize_start<P: BezierPathFactory>(path: &P, arc_length: f64) -> P {
+    let curves      = path_to_curves::<_, Curve<_>>(path).collect::<Vec<_>>();
+    let positions   = anchor_arc_positions(path, LENGTH_MAX_ERROR);
+    let total       = positions[positions.len()-1];
+
+    if curves.is_empty() || total <= 0.0 {
+        // Nothing to rotate
+        return P::from_path(path);
+    }
+
+    // Find the curve that contains the new start point
+    let arc_length  = arc_length.rem_euclid(total);
+    let curve_idx   = (0..curves.len()).rev().find(|idx| positions[*idx] <= arc_length).unwrap_or(0);
+    let curve_t     = t_for_curve_length(&curves[curve_idx], arc_length - positions[curve_idx], LENGTH_MAX_ERROR);
+
+    // Curves that come before and after the split point in the new path
+    let (first, last): (Vec<Curve<_>>, Vec<Curve<_>>) = if curve_t <= SMALL_T_DISTANCE {
+        // Start at the anchor point at the start of this curve
+        (vec![], vec![])
+    } else if curve_t >= 1.0 - SMALL_T_DISTANCE {
+        // Start at the anchor point at the end of this curve (the curve moves to the end of the new path)
+        (vec![], vec![curves[curve_idx]])
+    } else {
+        let (before, after) = curves[curve_idx].subdivide::<Curve<_>>(curve_t);
+        (vec![after], vec![before])
+    };
+
+    let remaining   = if first.is_empty() && last.is_empty() { curve_idx } else { curve_idx+1 };
+    let new_curves  = first.into_iter()
+        .chain(curves[remaining..].iter().cloned())
+        .chain(curves[..curve_idx].iter().cloned())
+        .chain(last)
+        .collect::<Vec<_>>();
+
+    // Reassemble the path
+    let start_point = new_curves[0].start_point();
+    let points      = new_curves.iter().map(|curve| {
+        let (cp1, cp2) = curve.control_points();
+        (cp1, cp2, curve.end_point())
+    }).collect::<Vec<_>>();
+
+    P::from_points(start_point, points)
+}
+
+///
+/// Finds the curve and t value that are a particular fraction of the way along a path, measured by arc length
+///
+/// The result is the index of the curve within the path along with the t value on that curve. `fraction` is clamped to the
+/// range `0.0..=1.0`, where 0.0 is the start of the path and 1.0 is the end. A path with no curves always returns `(0, 0.0)`.
+///
+/// This is useful for animating something along a path at a constant speed.
+///
+pub fn path_t_at_fraction<P: BezierPath>(path: &P, fraction: f64) -> (usize, f64) {
+    let curves      = path_to_curves::<_, Curve<_>>(path).collect::<Vec<_>>();
+    let positions   = anchor_arc_positions(path, LENGTH_MAX_ERROR);
+    let total       = positions[positions.len()-1];
+
+    if curves.is_empty() {
+        return (0, 0.0);
+    } else if fraction >= 1.0 {
+        return (curves.len()-1, 1.0);
+    }
+
+    // Find the curve that contains the distance (skipping any curves with no length)
+    let distance    = fraction.max(0.0) * total;
+    let curve_idx   = (0..curves.len()).rev().find(|idx| positions[*idx] <= distance && positions[*idx] < positions[*idx+1]).unwrap_or(0);
+
+    (curve_idx, t_for_curve_length(&curves[curve_idx], distance - positions[curve_idx], LENGTH_MAX_ERROR))
+}
+
+///
+/// Splits a path into two paths at a particular distance along it, measured by arc length
+///
+/// The curve containing the split point is divided in two, unless the distance falls on an existing anchor point. The second
+/// path is `None` if the distance is at or beyond the end of the path, in which case the first path is a copy of the original.
+/// Distances less than or equal to 0 produce a first path with no curves, starting at the start point of the original path.
+///
+pub fn split_path_at_distance<P: BezierPathFactory>(path: &P, distance: f64) -> (P, Option<P>) {
+    let curves      = path_to_curves::<_, Curve<_>>(path).collect::<Vec<_>>();
+    let positions   = anchor_arc_positions(path, LENGTH_MAX_ERROR);
+    let total       = positions[positions.len()-1];
+
+    if distance >= total {
+        // The whole path is before the split point
+        return (P::from_path(path), None);
+    } else if distance <= 0.0 {
+        // The whole path is after the split point
+        return (P::from_points(path.start_point(), vec![]), Some(P::from_path(path)));
+    }
+
+    // Find the curve that contains the split point
+    let curve_idx   = (0..curves.len()).rev().find(|idx| positions[*idx] <= distance).unwrap_or(0);
+    let curve_t     = t_for_curve_length(&curves[curve_idx], distance - positions[curve_idx], LENGTH_MAX_ERROR);
+
+    // Divide the curves into the ones before and after the split point
+    let (before, after): (Vec<Curve<_>>, Vec<Curve<_>>) = if curve_t <= SMALL_T_DISTANCE {
+        (curves[..curve_idx].to_vec(), curves[curve_idx..].to_vec())
+    } else if curve_t >= 1.0 - SMALL_T_DISTANCE {
+        (curves[..=curve_idx].to_vec(), curves[(curve_idx+1)..].to_vec())
+    } else {
+        let (first, second) = curves[curve_idx].subdivide::<Curve<_>>(curve_t);
+
+        (curves[..curve_idx].iter().cloned().chain(iter::once(first)).collect(), iter::once(second).chain(curves[(curve_idx+1)..].iter().cloned()).collect())
+    };
+
+    // Reassemble the paths
+    let split_point = after.first().map(|curve| curve.start_point())
+        .or_else(|| before.last().map(|curve| curve.end_point()))
+        .unwrap_or_else(|| path.start_point());
+    let to_points   = |curves: Vec<Curve<_>>| curves.into_iter().map(|curve| {
+        let (cp1, cp2) = curve.control_points();
+        (cp1, cp2, curve.end_point())
+    }).collect::<Vec<_>>();
+
+    (P::from_points(path.start_point(), to_points(before)), Some(P::from_points(split_point, to_points(after))))
+}
+
+/// Number of samples in the arc length tables used to find where dashes start and end
+const DASH_ARC_LENGTH_SAMPLES: usize = 32;
+
+///
+/// Divides a path into dashes following a dash pattern
+///
+/// `pattern` is a list of lengths that alternate between dashes and gaps, starting with a dash, and is repeated along the
+/// whole length of the path. As with SVG, a pattern with an odd number of entries is repeated twice to make it even.
+/// `phase` is the distance into the pattern that the start of the path is at. Each dash is returned as a separate path,
+/// with the curves of the original path split at the points where each dash starts and ends. Dashes continue around
+/// corners in the original path, and dashes with no length are left out. If the pattern is empty, or doesn't have a
+/// positive length, the result is a copy of the original path.
+///
+pub fn dash_path<P, POut>(path: &P, pattern: &[f64], phase: f64) -> Vec<POut>
+where
+    P:      BezierPath,
+    POut:   BezierPathFactory<Point=P::Point>,
+{
+    // Make sure the pattern alternates between dashes and gaps
+    let pattern         = if pattern.len() % 2 == 1 { pattern.iter().chain(pattern.iter()).cloned().collect::<Vec<_>>() } else { pattern.to_vec() };
+    let pattern_length  = pattern.iter().sum::<f64>();
+
+    if pattern.is_empty() || pattern_length <= 0.0 || pattern.iter().any(|length| *length < 0.0) {
+        return vec![POut::from_path(path)];
+    }
+
+    // Move to the start of the pattern
+    let mut pattern_idx = 0;
+    let mut remaining   = pattern[0];
+    let mut phase       = phase.rem_euclid(pattern_length);
+
+    while phase >= remaining {
+        phase       -= remaining;
+        pattern_idx = (pattern_idx + 1) % pattern.len();
+        remaining   = pattern[pattern_idx];
+    }
+    remaining -= phase;
+
+    // Walk along the curves of the path, generating the dashes
+    let mut dashes      = vec![];
+    let mut dash        = vec![];
+
+    for curve in path_to_curves::<_, Curve<_>>(path) {
+        let table       = ArcLengthTable::new(&curve, DASH_ARC_LENGTH_SAMPLES);
+        let length      = table.total_length();
+        let mut pos     = 0.0;
+
+        loop {
+            let step = remaining.min(length - pos);
+
+            // Even entries in the pattern are dashes
+            if pattern_idx % 2 == 0 && step > 0.0 {
+                let (t_min, t_max) = (table.t_for_distance(pos), table.t_for_distance(pos + step));
+                dash.push(Curve::from_curve(&curve.section(t_min, t_max)));
+            }
+
+            pos         += step;
+            remaining   -= step;
+
+            if remaining > SMALL_T_DISTANCE * pattern_length {
+                // Reached the end of this curve before the end of the pattern entry
+                break;
+            }
+
+            // Finish the dash and move to the next entry in the pattern
+            if pattern_idx % 2 == 0 && !dash.is_empty() {
+                dashes.push(dash);
+                dash = vec![];
+            }
+
+            pattern_idx = (pattern_idx + 1) % pattern.len();
+            remaining   = pattern[pattern_idx];
+        }
+    }
+
+    if !dash.is_empty() {
+        dashes.push(dash);
+    }
+
+    // Convert the dashes to paths
+    dashes.into_iter()
+        .map(|curves: Vec<Curve<_>>| POut::from_points(curves[0].start_point(), curves.iter().map(|curve| {
+            let (cp1, cp2) = curve.control_points();
+            (cp1, cp2, curve.end_point())
+        })))
+        .collect()
+}
@@ -0,0 +1,164 @@
+use super::path::*;
+use super::arc_length::*;
+use super::super::basis::*;
+use super::super::super::geo::*;
+
+use std::f64;
+
+/// Error bound used when measuring the perimeter of a path to find its compactness
+const COMPACTNESS_LENGTH_MAX_ERROR: f64 = 1e-8;
+
+///
+/// Integrates `x(t) * y'(t)` over the range `0.0..1.0` for the two polynomials, which have their coefficients in ascending order
+///
+#[inline]
+fn integrate_with_derivative(x: &[f64], y: &[f64]) -> f64 {
+    let mut total = 0.0;
+
+    for (x_power, x_coefficient) in x.iter().enumerate() {
+        for (y_power, y_coefficient) in y.iter().enumerate().skip(1) {
+            // The derivative of `c * t^n` is `n * c * t^(n-1)`, and the integral of `t^n` over `0.0..1.0` is `1/(n+1)`
+            let derivative_coefficient  = (y_power as f64) * y_coefficient;
+            let power                   = x_power + y_power - 1;
+
+            total += x_coefficient * derivative_coefficient / ((power + 1) as f64);
+        }
+    }
+
+    total
+}
+
+///
+/// Multiplies two cubic polynomials, which have their coefficients in ascending order
+///
+#[inline]
+fn multiply_cubics(a: &[f64; 4], b: &[f64; 4]) -> [f64; 7] {
+    let mut result = [0.0; 7];
+
+    for (a_power, a_coefficient) in a.iter().enumerate() {
+        for (b_power, b_coefficient) in b.iter().enumerate() {
+            result[a_power + b_power] += a_coefficient * b_coefficient;
+        }
+    }
+
+    result
+}
+
+///
+/// Computes the signed area enclosed by a path
+///
+/// The area is positive if the path is anticlockwise and negative if it's clockwise (assuming that a positive y value indicates
+/// an upwards direction, as for `is_clockwise()`). This is calculated exactly from the curves using Green's theorem, and paths
+/// that are not closed are treated as if there's a straight line from the end point back to the start point.
+///
+pub fn path_area<P: BezierPath>(path: &P) -> f64
+where
+    P::Point: Coordinate+Coordinate2D,
+{
+    let mut area        = 0.0;
+    let mut last_point  = path.start_point();
+
+    for (cp1, cp2, end_point) in path.points() {
+        // Green's theorem: the area is the integral of (x * dy - y * dx)/2 around the path
+        let (ax, bx, cx, dx)    = bezier_coefficients(0, &last_point, &cp1, &cp2, &end_point);
+        let (ay, by, cy, dy)    = bezier_coefficients(1, &last_point, &cp1, &cp2, &end_point);
+        let x                   = [dx, cx, bx, ax];
+        let y                   = [dy, cy, by, ay];
+
+        area        += integrate_with_derivative(&x, &y) - integrate_with_derivative(&y, &x);
+        last_point  = end_point;
+    }
+
+    // Close the path with a straight line (this adds nothing if the path is already closed)
+    let start_point = path.start_point();
+    area += last_point.x() * start_point.y() - start_point.x() * last_point.y();
+
+    area / 2.0
+}
+
+///
+/// Computes the centroid (center of mass) of the area enclosed by a path
+///
+/// Like `path_area()`, this is calculated exactly from the curves using Green's theorem, and paths that are not closed are 
+/// treated as if there's a straight line from the end point back to the start point. The direction of the path doesn't affect
+/// the result. The centroid of a path that encloses no area is undefined, so the start point is returned in this case.
+///
+pub fn path_centroid<P: BezierPath>(path: &P) -> P::Point
+where
+    P::Point: Coordinate+Coordinate2D,
+{
+    let mut area        = 0.0;
+    let mut moment_x    = 0.0;
+    let mut moment_y    = 0.0;
+
+    let mut add_curve   = |start_point: &P::Point, cp1: &P::Point, cp2: &P::Point, end_point: &P::Point| {
+        // Green's theorem: the moment about the y axis is the integral of x^2/2 * dy, and about the x axis is the integral of -y^2/2 * dx
+        let (ax, bx, cx, dx)    = bezier_coefficients(0, start_point, cp1, cp2, end_point);
+        let (ay, by, cy, dy)    = bezier_coefficients(1, start_point, cp1, cp2, end_point);
+        let x                   = [dx, cx, bx, ax];
+        let y                   = [dy, cy, by, ay];
+
+        area        += (integrate_with_derivative(&x, &y) - integrate_with_derivative(&y, &x)) / 2.0;
+        moment_x    += integrate_with_derivative(&multiply_cubics(&x, &x), &y) / 2.0;
+        moment_y    -= integrate_with_derivative(&multiply_cubics(&y, &y), &x) / 2.0;
+    };
+
+    let start_point     = path.start_point();
+    let mut last_point  = start_point;
+
+    for (cp1, cp2, end_point) in path.points() {
+        add_curve(&last_point, &cp1, &cp2, &end_point);
+        last_point = end_point;
+    }
+
+    // Close the path with a straight line (a curve with its control points at its ends)
+    add_curve(&last_point, &last_point, &start_point, &start_point);
+
+    if area == 0.0 {
+        start_point
+    } else {
+        P::Point::from_components(&[moment_x / area, moment_y / area])
+    }
+}
+
+///
+/// Returns the path that encloses the greatest area from a set of paths
+///
+/// The direction of the paths is ignored. This is useful for picking out the main shape from a set of paths that also contains
+/// small fragments. If several paths have the same area, the first one is returned, and the result is `None` if there are no 
+/// paths.
+///
+pub fn largest_subpath<P: BezierPath>(paths: &[P]) -> Option<&P>
+where
+    P::Point: Coordinate+Coordinate2D,
+{
+    paths.iter()
+        .map(|path| (path, path_area(path).abs()))
+        .fold(None, |largest: Option<(&P, f64)>, (path, area)| {
+            match largest {
+                Some((_, largest_area)) if largest_area >= area => largest,
+                _                                               => Some((path, area))
+            }
+        })
+        .map(|(path, _area)| path)
+}
+
+///
+/// Returns the isoperimetric ratio of a path, `4*pi*area / perimeter^2`
+///
+/// This measures how compact a shape is: a circle has the most area for its perimeter so its compactness is 1.0, and long thin
+/// shapes have a compactness close to 0. The direction of the path does not affect the result. A path with no length has a 
+/// compactness of 0.
+///
+pub fn compactness<P: BezierPath>(path: &P) -> f64
+where
+    P::Point: Coordinate+Coordinate2D,
+{
+    let perimeter = path_perimeter(path, COMPACTNESS_LENGTH_MAX_ERROR);
+
+    if perimeter <= 0.0 {
+        0.0
+    } else {
+        4.0 * f64::consts::PI * path_area(path).abs() / (perimeter * perimeter)
+    }
+}
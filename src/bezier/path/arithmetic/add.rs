@@ -1,5 +1,6 @@
 use super::ray_cast::*;
 use super::super::path::*;
+use super::closed_input_paths;
 use super::super::graph_path::*;
 use super::super::super::super::geo::*;
 
@@ -46,15 +47,14 @@ where
     POut:           BezierPathFactory,
     POut::Point:    Coordinate+Coordinate2D,
 {
+    let path1 = closed_input_paths::<_, POut>(path1);
+    let path2 = closed_input_paths::<_, POut>(path2);
+
     // If either path is empty, short-circuit by returning the other
     if path1.is_empty() {
-        return path2.iter()
-            .map(|path| POut::from_path(path))
-            .collect();
+        return path2;
     } else if path2.is_empty() {
-        return path1.iter()
-            .map(|path| POut::from_path(path))
-            .collect();
+        return path1;
     }
 
     // Create the graph path from the source side
@@ -73,6 +73,19 @@ where
     merged_path.exterior_paths()
 }
 
+///
+/// Generates the path formed by adding two sets of paths
+///
+/// This is an alias for `path_add()`, using the name that other libraries usually give to this operation.
+///
+pub fn path_union<POut>(path1: &Vec<impl BezierPath<Point=POut::Point>>, path2: &Vec<impl BezierPath<Point=POut::Point>>, accuracy: f64) -> Vec<POut>
+where
+    POut:           BezierPathFactory,
+    POut::Point:    Coordinate+Coordinate2D,
+{
+    path_add(path1, path2, accuracy)
+}
+
 ///
 /// Generates the path formed by removing any interior points from an existing path. This considers only the outermost edges of the 
 /// path to be the true edges, so if there are sub-paths inside an outer path, they will be removed.
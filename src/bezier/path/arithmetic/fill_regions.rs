@@ -0,0 +1,53 @@
+use super::add::*;
+use super::super::path::*;
+use super::super::orientation::*;
+use super::super::super::super::geo::*;
+
+///
+/// The winding rules that can be used to decide which regions of a set of paths are filled
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FillRule {
+    /// Points are filled if the path winds around them any number of times (using `path_remove_interior_points()`)
+    NonZero,
+
+    /// Points are filled if they are surrounded by an odd number of edges (using `path_remove_overlapped_points()`)
+    EvenOdd
+}
+
+///
+/// Converts a set of paths that may overlap or intersect themselves into a set of non-overlapping paths that describe the same 
+/// filled area under a winding rule
+///
+/// See `path_remove_interior_points()` and `path_remove_overlapped_points()` for details of how the two rules are applied.
+///
+pub fn resolve_fill_regions<P: BezierPathFactory>(paths: &[P], rule: FillRule, accuracy: f64) -> Vec<P>
+where
+    P::Point: Coordinate+Coordinate2D,
+{
+    let paths = paths.to_vec();
+
+    match rule {
+        FillRule::NonZero   => path_remove_interior_points(&paths, accuracy),
+        FillRule::EvenOdd   => path_remove_overlapped_points(&paths, accuracy)
+    }
+}
+
+///
+/// Counts the number of separate filled regions that a path produces under a winding rule
+///
+/// A simple path that doesn't intersect itself produces a single region, so this can be used to check if a path is simple 
+/// before an operation that assumes it is. A region with holes in it counts as a single region, but any regions inside those
+/// holes are counted separately, as are regions that only touch at a point.
+///
+pub fn count_overlap_regions<P: BezierPathFactory>(path: &P, rule: FillRule, accuracy: f64) -> usize
+where
+    P::Point: Coordinate+Coordinate2D,
+{
+    // Resolve the path into a set of non-overlapping outlines: each outline with an even nesting depth is the outside edge of a region
+    let regions = resolve_fill_regions(std::slice::from_ref(path), rule, accuracy);
+
+    path_nesting_depths(&regions).into_iter()
+        .filter(|depth| (depth % 2) == 0)
+        .count()
+}
@@ -1,5 +1,6 @@
 use super::ray_cast::*;
 use super::super::path::*;
+use super::closed_input_paths;
 use super::super::graph_path::*;
 use super::super::super::super::geo::*;
 
@@ -28,15 +29,14 @@ where
     POut:           BezierPathFactory,
     POut::Point:    Coordinate+Coordinate2D
 {
+    let path1 = closed_input_paths::<_, POut>(path1);
+    let path2 = closed_input_paths::<_, POut>(path2);
+
     // If either path is empty, short-circuit by returning the other
     if path1.is_empty() {
-        return path2.iter()
-            .map(|path| POut::from_path(path))
-            .collect();
+        return path2;
     } else if path2.is_empty() {
-        return path1.iter()
-            .map(|path| POut::from_path(path))
-            .collect();
+        return path1;
     }
 
     // Create the graph path from the source side
@@ -6,6 +6,8 @@ mod sub;
 mod chain;
 mod cut;
 mod full_intersect;
+mod xor;
+mod fill_regions;
 
 pub use self::ray_cast::*;
 pub use self::intersect::*;
@@ -15,3 +17,31 @@ pub use self::chain::*;
 pub use self::chain_add::*;
 pub use self::cut::*;
 pub use self::full_intersect::*;
+pub use self::xor::*;
+pub use self::fill_regions::*;
+
+use super::path::*;
+use super::polygon::*;
+use super::super::super::consts::*;
+
+///
+/// Prepares a set of paths to be used as the input of one of the arithmetic operations by closing any that are open
+///
+/// Open paths are treated as if they're closed with a straight line from their end point back to their start point. Paths
+/// that are already closed are left as they are.
+///
+pub (crate) fn closed_input_paths<P, POut>(paths: &[P]) -> Vec<POut>
+where
+    P:      BezierPath<Point=POut::Point>,
+    POut:   BezierPathFactory,
+{
+    paths.iter()
+        .map(|path| {
+            if path.is_closed(SMALL_DISTANCE) {
+                POut::from_path(path)
+            } else {
+                close_path(&POut::from_path(path))
+            }
+        })
+        .collect()
+}
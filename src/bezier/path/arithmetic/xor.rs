@@ -0,0 +1,75 @@
+use super::ray_cast::*;
+use super::super::path::*;
+use super::closed_input_paths;
+use super::super::graph_path::*;
+use super::super::super::super::geo::*;
+
+impl<Point: Coordinate+Coordinate2D> GraphPath<Point, PathLabel> {
+    ///
+    /// Given a labelled graph path, marks exterior edges by finding the regions that are in exactly one of `PathSource::Path1` and `PathSource::Path2`
+    ///
+    pub fn set_exterior_by_xoring(&mut self) {
+        // Use an even-odd winding rule (all edges are considered 'external')
+        self.set_edge_kinds_by_ray_casting(|path_crossings| ((path_crossings[0]&1) != 0) != ((path_crossings[1]&1) != 0));
+    }
+}
+
+///
+/// Generates the path formed by the regions that are in one of two sets of paths but not both (the exclusive-or of the paths)
+///
+/// Each of the two paths passed into this function is assumed not to overlap themselves. IE, this does not perform self-intersection 
+/// on either `path1` or `path2`. See `path_remove_interior_points()` and `path_remove_overlapped_points()` for a way to eliminate 
+/// overlaps.
+///
+pub fn path_xor<POut>(path1: &Vec<impl BezierPath<Point=POut::Point>>, path2: &Vec<impl BezierPath<Point=POut::Point>>, accuracy: f64) -> Vec<POut>
+where
+    POut:           BezierPathFactory,
+    POut::Point:    Coordinate+Coordinate2D
+{
+    let path1 = closed_input_paths::<_, POut>(path1);
+    let path2 = closed_input_paths::<_, POut>(path2);
+
+    // If either path is empty, short-circuit by returning the other
+    if path1.is_empty() {
+        return path2;
+    } else if path2.is_empty() {
+        return path1;
+    }
+
+    // Create the graph path from the source side
+    let mut merged_path = GraphPath::new();
+    merged_path         = merged_path.merge(GraphPath::from_merged_paths(path1.iter().map(|path| (path, PathLabel(0)))));
+
+    // Collide with the target side to generate a full path
+    merged_path         = merged_path.collide(GraphPath::from_merged_paths(path2.iter().map(|path| (path, PathLabel(1)))), accuracy);
+    merged_path.round(accuracy);
+
+    // Set the exterior edges using the 'xor' algorithm
+    merged_path.set_exterior_by_xoring();
+    merged_path.heal_exterior_gaps();
+
+    // Produce the final result
+    merged_path.exterior_paths()
+}
+
+///
+/// Finds the regions that have changed between two versions of a shape
+///
+/// This is the exclusive-or of the two shapes (see `path_xor()`), except that if the two shapes are identical the result is 
+/// always empty.
+///
+pub fn path_difference_outline<P>(before: &[P], after: &[P], accuracy: f64) -> Vec<P>
+where
+    P:          BezierPathFactory,
+    P::Point:   Coordinate+Coordinate2D
+{
+    // Identical shapes have no differences (avoids generating slivers from edges that overlap exactly)
+    let is_identical = before.len() == after.len() 
+        && before.iter().zip(after.iter()).all(|(before, after)| before.start_point() == after.start_point() && before.points().eq(after.points()));
+
+    if is_identical {
+        vec![]
+    } else {
+        path_xor(&before.to_vec(), &after.to_vec(), accuracy)
+    }
+}
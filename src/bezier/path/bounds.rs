@@ -5,12 +5,14 @@ use super::super::super::geo::*;
 
 ///
 /// Finds the bounds of a path
+///
+/// A path with no curves has a bounding box containing just its start point
 /// 
 pub fn path_bounding_box<P: BezierPath, Bounds: BoundingBox<Point=P::Point>>(path: &P) -> Bounds {
     path_to_curves(path)
         .map(|curve: Curve<P::Point>| curve.bounding_box())
         .reduce(|first: Bounds, second| first.union_bounds(second))
-        .unwrap_or_else(|| Bounds::from_min_max(P::Point::origin(), P::Point::origin()))
+        .unwrap_or_else(|| Bounds::from_min_max(path.start_point(), path.start_point()))
 }
 
 ///
@@ -20,5 +22,34 @@ pub fn path_fast_bounding_box<P: BezierPath, Bounds: BoundingBox<Point=P::Point>
     path_to_curves(path)
         .map(|curve: Curve<P::Point>| curve.fast_bounding_box())
         .reduce(|first: Bounds, second| first.union_bounds(second))
-        .unwrap_or_else(|| Bounds::from_min_max(P::Point::origin(), P::Point::origin()))
+        .unwrap_or_else(|| Bounds::from_min_max(path.start_point(), path.start_point()))
+}
+
+///
+/// Finds the bounds of a path after it has been offset by `distance` units
+///
+/// This pads the bounds of the path by `distance` in every direction without generating the offset curves, so the result is
+/// always large enough to contain the offset path (or a stroke with a width of `2*distance`), but may be larger than it needs
+/// to be. Negative distances pad the bounds in the same way as positive ones.
+///
+pub fn offset_bounds<P: BezierPath, Bounds: BoundingBox<Point=P::Point>>(path: &P, distance: f64) -> Bounds {
+    let bounds: Bounds  = path_bounding_box(path);
+    let padding         = P::Point::from_components(&vec![distance.abs(); P::Point::len()]);
+
+    Bounds::from_min_max(bounds.min() - padding, bounds.max() + padding)
+}
+
+///
+/// Finds the bounds of the region covered while morphing between two paths
+///
+/// A morph between two paths with the same number of curves linearly interpolates their points, so each point on a frame of
+/// the morph is between the points on the two paths at the same position. This means that every frame fits inside the union
+/// of the bounding boxes of the two paths, which is what this returns. This is useful for tracking the region that needs to be
+/// redrawn while a shape is being animated.
+///
+pub fn morph_bounds<P: BezierPath, Bounds: BoundingBox<Point=P::Point>>(from: &P, to: &P) -> Bounds {
+    let from_bounds: Bounds = path_bounding_box(from);
+    let to_bounds: Bounds   = path_bounding_box(to);
+
+    from_bounds.union_bounds(to_bounds)
 }
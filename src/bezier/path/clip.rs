@@ -0,0 +1,60 @@
+use super::path::*;
+use super::point::*;
+use super::to_curves::*;
+use super::arithmetic::*;
+use super::super::curve::*;
+use super::super::intersection::*;
+use super::super::super::geo::*;
+use super::super::super::consts::*;
+
+use std::iter;
+use std::cmp::{Ordering};
+
+/// The accuracy used when finding where a curve crosses the edge of a clipping region
+const CLIP_ACCURACY: f64 = 0.001;
+
+///
+/// Returns the sections of a curve that are inside a region
+///
+/// The region is made up of a set of closed paths, and `rule` determines which parts of it are filled where the paths overlap.
+/// The curve is divided at the points where it crosses any of the paths, and the sections with their midpoints inside the
+/// region are returned in order along the curve. Neighbouring sections that are both inside the region (for example, where
+/// the curve touches the edge of the region without leaving it) are joined back together.
+///
+pub fn curve_clipped_to_path<C, P>(curve: &C, region: &[P], rule: FillRule) -> Vec<C>
+where
+    C:          BezierCurveFactory,
+    P:          BezierPath<Point=C::Point>,
+    C::Point:   Coordinate+Coordinate2D,
+{
+    // Find the t values where the curve crosses the edges of the region
+    let clip_curve  = Curve::from_curve(curve);
+    let mut cuts    = region.iter()
+        .flat_map(|path| path_to_curves::<_, Curve<_>>(path))
+        .flat_map(|edge| curve_intersects_curve_clip(&clip_curve, &edge, CLIP_ACCURACY).into_iter().map(|(t, _)| t))
+        .filter(|t| *t > SMALL_T_DISTANCE && *t < 1.0-SMALL_T_DISTANCE)
+        .collect::<Vec<_>>();
+
+    cuts.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+    cuts.dedup_by(|a, b| (*a - *b).abs() <= SMALL_T_DISTANCE);
+
+    // Find the ranges that are inside the region, merging neighbouring ranges
+    let mut inside_ranges: Vec<(f64, f64)> = vec![];
+    let boundaries  = iter::once(0.0).chain(cuts).chain(iter::once(1.0)).collect::<Vec<_>>();
+
+    for (t_min, t_max) in boundaries.iter().zip(boundaries.iter().skip(1)) {
+        let midpoint = curve.point_at_pos((t_min + t_max) / 2.0);
+
+        if paths_contain_point(region, &midpoint, rule) {
+            match inside_ranges.last_mut() {
+                Some((_, last_max)) if *last_max == *t_min  => { *last_max = *t_max; }
+                _                                           => { inside_ranges.push((*t_min, *t_max)); }
+            }
+        }
+    }
+
+    // Generate the curve sections
+    inside_ranges.into_iter()
+        .map(|(t_min, t_max)| C::from_curve(&curve.section(t_min, t_max)))
+        .collect()
+}
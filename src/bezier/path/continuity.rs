@@ -0,0 +1,52 @@
+use super::path::*;
+use super::to_curves::*;
+use super::super::curve::*;
+use super::super::continuity::*;
+use super::super::super::geo::*;
+
+///
+/// Finds the continuity of each join between the curves in a path
+///
+/// There is one result for every pair of adjacent curves in the path, in order. If the path is closed, the last result is the
+/// continuity where the final curve joins back up with the first curve.
+///
+pub fn continuity_report<P: BezierPath>(path: &P, epsilon: f64) -> Vec<Continuity> {
+    segment_windows::<_, Curve<_>>(path)
+        .map(|(first, second)| join_continuity(&first, &second, epsilon))
+        .collect()
+}
+
+///
+/// Returns the unit vector pointing outwards from the start of a path, along the direction of its first curve
+///
+/// This is the opposite of the direction that the path is travelling in at its start point, so it's the direction that an 
+/// arrowhead at the start of an open path should point in. Curves with no length at the start of the path are skipped, and
+/// the result is the origin if the path has no length at all.
+///
+/// This has a `path_` prefix, like `path_area()` and `path_bounding_box()`, to separate it from the functions that find the
+/// directions at the ends of a single curve.
+///
+pub fn path_start_direction<P: BezierPath>(path: &P) -> P::Point {
+    path_to_curves::<_, Curve<_>>(path)
+        .map(|curve| start_direction(&curve))
+        .find(|direction| direction.magnitude() > 0.0)
+        .map(|direction| (direction * -1.0).to_unit_vector())
+        .unwrap_or_else(P::Point::origin)
+}
+
+///
+/// Returns the unit vector pointing outwards from the end of a path, along the direction of its last curve
+///
+/// This is the direction that the path is travelling in at its end point. Curves with no length at the end of the path are
+/// skipped, and the result is the origin if the path has no length at all. See `path_start_direction()` for the direction at
+/// the other end.
+///
+pub fn path_end_direction<P: BezierPath>(path: &P) -> P::Point {
+    let curves = path_to_curves::<_, Curve<_>>(path).collect::<Vec<_>>();
+
+    curves.iter().rev()
+        .map(end_direction)
+        .find(|direction| direction.magnitude() > 0.0)
+        .map(|direction| direction.to_unit_vector())
+        .unwrap_or_else(P::Point::origin)
+}
@@ -0,0 +1,28 @@
+use super::path::*;
+use super::super::super::geo::*;
+
+///
+/// Removes anchor points from a path that are at the same position as the anchor point before them
+///
+/// Operations like path arithmetic, clipping and splitting can leave curves with no length in a path, where an anchor point is
+/// repeated. These can cause problems for algorithms that need the direction of each curve, such as offsetting. A curve is
+/// removed if its end point and both of its control points are within `epsilon` of the anchor point before it, which leaves
+/// the shape of the path the same. Curves that start and end at the same point but have control points elsewhere (small
+/// loops) are left in the path.
+///
+pub fn dedup_anchors<P: BezierPathFactory>(path: &P, epsilon: f64) -> P {
+    let start_point     = path.start_point();
+    let mut last_point  = start_point;
+    let mut points      = vec![];
+
+    for (cp1, cp2, end_point) in path.points() {
+        let is_duplicate = end_point.is_near_to(&last_point, epsilon) && cp1.is_near_to(&last_point, epsilon) && cp2.is_near_to(&last_point, epsilon);
+
+        if !is_duplicate {
+            points.push((cp1, cp2, end_point));
+            last_point = end_point;
+        }
+    }
+
+    P::from_points(start_point, points)
+}
@@ -0,0 +1,35 @@
+use super::path::*;
+use super::point::*;
+use super::to_curves::*;
+use super::super::curve::*;
+use super::super::super::geo::*;
+
+use std::f64;
+
+///
+/// Computes the signed distance from a point to the nearest edge of a set of paths
+///
+/// The result is negative if the point is inside the paths and positive if it is outside. Points are considered to be inside
+/// if the total winding number of all the paths around the point is non-zero.
+///
+pub fn path_signed_distance<P: BezierPath>(paths: &[P], point: &P::Point) -> f64
+where
+    P::Point: Coordinate2D,
+{
+    // Magnitude is the distance to the nearest point on any of the curves in the paths
+    let distance = paths.iter()
+        .flat_map(|path| path_to_curves::<_, Curve<_>>(path))
+        .map(|curve| curve.distance_to(point))
+        .fold(f64::MAX, f64::min);
+
+    // Sign is determined by the winding number of the paths
+    let winding_number = paths.iter()
+        .map(|path| path_winding_number(path, point))
+        .sum::<i32>();
+
+    if winding_number != 0 {
+        -distance
+    } else {
+        distance
+    }
+}
@@ -0,0 +1,129 @@
+use super::path::*;
+use super::to_curves::*;
+use super::super::curve::*;
+use super::super::walk::*;
+use super::super::super::geo::*;
+
+/// Number of points each curve is divided into when finding the enclosing circle
+const POINTS_PER_CURVE: usize = 32;
+
+/// Allowance for floating point error when checking if a point is within a circle
+const CIRCLE_EPSILON: f64 = 1e-9;
+
+///
+/// Returns the circle with two points at either end of its diameter
+///
+#[inline]
+fn circle_from_two_points<Point: Coordinate+Coordinate2D>(p1: &Point, p2: &Point) -> (Point, f64) {
+    let center = (*p1 + *p2) * 0.5;
+
+    (center, center.distance_to(p1))
+}
+
+///
+/// Returns the circle passing through three points, or the smallest circle containing them if they are collinear
+///
+fn circle_from_three_points<Point: Coordinate+Coordinate2D>(p1: &Point, p2: &Point, p3: &Point) -> (Point, f64) {
+    let (ax, ay) = (p1.x(), p1.y());
+    let (bx, by) = (p2.x()-ax, p2.y()-ay);
+    let (cx, cy) = (p3.x()-ax, p3.y()-ay);
+
+    let d = 2.0 * (bx*cy - by*cx);
+
+    if d.abs() < 1e-12 {
+        // Points are collinear: the circle is defined by the two points furthest apart
+        let candidates = [circle_from_two_points(p1, p2), circle_from_two_points(p1, p3), circle_from_two_points(p2, p3)];
+
+        candidates.iter()
+            .fold(candidates[0], |largest, circle| if circle.1 > largest.1 { *circle } else { largest })
+    } else {
+        // Circumcircle of the three points
+        let b_squared   = bx*bx + by*by;
+        let c_squared   = cx*cx + cy*cy;
+        let ux          = (cy*b_squared - by*c_squared) / d;
+        let uy          = (bx*c_squared - cx*b_squared) / d;
+
+        let center      = Point::from_components(&[ux + ax, uy + ay]);
+
+        (center, center.distance_to(p1))
+    }
+}
+
+///
+/// True if a point is inside (or on the edge of) a circle
+///
+#[inline]
+fn circle_contains<Point: Coordinate+Coordinate2D>(circle: &(Point, f64), point: &Point) -> bool {
+    circle.0.distance_to(point) <= circle.1 + CIRCLE_EPSILON
+}
+
+///
+/// Finds the smallest circle that encloses a set of points, returning the center and radius
+///
+/// This uses Welzl's algorithm. The points are shuffled into a fixed pseudo-random order first, which keeps the expected
+/// running time linear even when the points are supplied in order along a curve.
+///
+pub fn minimum_enclosing_circle_for_points<Point: Coordinate+Coordinate2D>(points: &[Point]) -> (Point, f64) {
+    if points.is_empty() {
+        return (Point::origin(), 0.0);
+    }
+
+    // Shuffle the points using a simple xorshift generator (so the results are deterministic)
+    let mut points  = points.to_vec();
+    let mut seed    = 0x2545f4914f6cdd1du64;
+
+    for idx in (1..points.len()).rev() {
+        seed ^= seed << 13;
+        seed ^= seed >> 7;
+        seed ^= seed << 17;
+
+        points.swap(idx, (seed % (idx as u64 + 1)) as usize);
+    }
+
+    // Welzl's algorithm, in its iterative form
+    let mut circle = (points[0], 0.0);
+
+    for i in 1..points.len() {
+        if circle_contains(&circle, &points[i]) { continue; }
+
+        // points[i] must be on the boundary of the circle
+        circle = (points[i], 0.0);
+
+        for j in 0..i {
+            if circle_contains(&circle, &points[j]) { continue; }
+
+            // points[i] and points[j] must both be on the boundary
+            circle = circle_from_two_points(&points[i], &points[j]);
+
+            for k in 0..j {
+                if circle_contains(&circle, &points[k]) { continue; }
+
+                // The circle is defined by all three points
+                circle = circle_from_three_points(&points[i], &points[j], &points[k]);
+            }
+        }
+    }
+
+    circle
+}
+
+///
+/// Finds the smallest circle that encloses a path, returning the center and radius
+///
+/// The path is flattened into a set of points before the circle is computed. As the flattened points are all on the path,
+/// the circle may be very slightly smaller than the true enclosing circle where the path bulges between two of those
+/// points. Using the control points instead would cover the whole path but produce a circle that is generally larger than
+/// necessary.
+///
+pub fn minimum_enclosing_circle<P: BezierPath>(path: &P) -> (P::Point, f64)
+where
+    P::Point: Coordinate2D,
+{
+    let mut points = vec![path.start_point()];
+
+    for curve in path_to_curves::<_, Curve<_>>(path) {
+        points.extend(walk_curve_unevenly(&curve, POINTS_PER_CURVE).map(|section| section.end_point()));
+    }
+
+    minimum_enclosing_circle_for_points(&points)
+}
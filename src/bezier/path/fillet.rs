@@ -0,0 +1,116 @@
+use super::path::*;
+use super::to_curves::*;
+use super::super::curve::*;
+use super::super::length::*;
+use super::super::normal::*;
+use super::super::continuity::*;
+use super::super::super::geo::*;
+use super::super::super::consts::*;
+
+/// Number of samples in the arc length tables used to find where to trim the curves either side of a corner
+const FILLET_ARC_LENGTH_SAMPLES: usize = 32;
+
+/// The maximum difference between the unit tangents either side of a join for it to be left unchanged
+const FILLET_SMOOTH_EPSILON: f64 = 0.01;
+
+///
+/// Returns the angle (in radians) between two unit vectors
+///
+#[inline]
+fn angle_between<Point: Coordinate+Coordinate2D>(a: &Point, b: &Point) -> f64 {
+    let cross   = a.x()*b.y() - a.y()*b.x();
+    let dot     = a.dot(b);
+
+    cross.atan2(dot).abs()
+}
+
+///
+/// Rounds the corners of a path by replacing them with circular arcs of a particular radius
+///
+/// At each anchor point where the tangents of the curves on either side differ, the curves are trimmed back from the corner
+/// and a curve approximating a circular arc of `radius` is inserted between the trimmed ends, tangent to both. A closed
+/// path also has its closing corner rounded. The distance trimmed from each curve grows with the angle of the corner, and
+/// is limited to half the length of the curve, so neighbouring corners don't overlap: where this happens, the arc at that
+/// corner uses a smaller radius. Smooth joins, and corners where one of the curves has no length, are left unchanged.
+///
+pub fn fillet_corners<P: BezierPathFactory>(path: &P, radius: f64) -> P
+where
+    P::Point: Coordinate+Coordinate2D+Normalize,
+{
+    let curves      = path_to_curves::<_, Curve<_>>(path).collect::<Vec<_>>();
+    if curves.is_empty() || radius <= 0.0 {
+        return P::from_path(path);
+    }
+
+    let tables      = curves.iter().map(|curve| ArcLengthTable::new(curve, FILLET_ARC_LENGTH_SAMPLES)).collect::<Vec<_>>();
+    let is_closed   = path.is_closed(SMALL_DISTANCE);
+    let num_joins   = if is_closed { curves.len() } else { curves.len()-1 };
+
+    // Work out how far to trim the curves at each corner, and the radius of the arc that will fit there
+    let mut trim_start  = vec![0.0; curves.len()];
+    let mut trim_end    = vec![0.0; curves.len()];
+    let mut fillets     = vec![None; curves.len()];
+
+    for join_idx in 0..num_joins {
+        let next_idx    = (join_idx+1) % curves.len();
+        let incoming    = end_direction(&curves[join_idx]).to_unit_vector();
+        let outgoing    = start_direction(&curves[next_idx]).to_unit_vector();
+
+        if incoming.magnitude() == 0.0 || outgoing.magnitude() == 0.0 || incoming.is_near_to(&outgoing, FILLET_SMOOTH_EPSILON) {
+            continue;
+        }
+
+        // The arc touches the two curves at a distance of r*tan(angle/2) from the corner, limited to half of each curve
+        let angle       = angle_between(&incoming, &outgoing);
+        let half_tan    = (angle / 2.0).tan();
+        let max_trim    = (tables[join_idx].total_length() / 2.0).min(tables[next_idx].total_length() / 2.0);
+        let trim        = (radius * half_tan).min(max_trim);
+
+        if trim <= 0.0 || !trim.is_finite() {
+            continue;
+        }
+
+        trim_end[join_idx]      = trim;
+        trim_start[next_idx]    = trim;
+        fillets[join_idx]       = Some(trim / half_tan);
+    }
+
+    // Trim the curves
+    let t_ranges    = (0..curves.len())
+        .map(|idx| {
+            let t_min = if trim_start[idx] > 0.0 { tables[idx].t_for_distance(trim_start[idx]) } else { 0.0 };
+            let t_max = if trim_end[idx] > 0.0 { tables[idx].t_for_distance(tables[idx].total_length() - trim_end[idx]) } else { 1.0 };
+
+            (t_min, t_max.max(t_min))
+        })
+        .collect::<Vec<_>>();
+    let trimmed     = curves.iter().zip(t_ranges.iter())
+        .map(|(curve, (t_min, t_max))| Curve::from_curve(&curve.section(*t_min, *t_max)))
+        .collect::<Vec<_>>();
+
+    // Generate the new path, adding an arc after each trimmed corner
+    let mut points  = vec![];
+
+    for (idx, curve) in trimmed.iter().enumerate() {
+        let (cp1, cp2) = curve.control_points();
+        points.push((cp1, cp2, curve.end_point()));
+
+        if let Some(fillet_radius) = fillets[idx] {
+            // A cubic approximates a circular arc of angle a when its control points are 4/3*tan(a/4)*r along the tangents
+            // (the tangents are taken from the original curves, as the trimmed curves can have no length)
+            let next_idx        = (idx+1) % trimmed.len();
+            let next            = &trimmed[next_idx];
+            let incoming        = curves[idx].tangent_at_pos(t_ranges[idx].1).to_unit_vector();
+            let outgoing        = curves[next_idx].tangent_at_pos(t_ranges[next_idx].0).to_unit_vector();
+            let arc_angle       = angle_between(&incoming, &outgoing);
+            let handle_length   = 4.0/3.0 * (arc_angle / 4.0).tan() * fillet_radius;
+
+            let arc_start       = curve.end_point();
+            let arc_end         = next.start_point();
+
+            points.push((arc_start + incoming * handle_length, arc_end - outgoing * handle_length, arc_end));
+        }
+    }
+
+    P::from_points(trimmed[0].start_point(), points)
+}
@@ -1,9 +1,13 @@
 use super::path::*;
 use super::to_curves::*;
 use super::super::curve::*;
+use super::super::solve::*;
 use super::super::intersection::*;
 use super::super::super::geo::*;
 use super::super::super::line::*;
+use super::super::super::consts::*;
+
+use std::cmp::{Ordering};
 
 ///
 /// Determines the intersections of a path and a line
@@ -42,7 +46,8 @@ where
 /// Finds the points where a path intersects another path
 /// 
 /// Intersections are returned as (segment index, t-value), in pairs indicating the position on the first path
-/// and the position on the second path. Intersections are unordered by default.
+/// and the position on the second path. The results are sorted in order along the first path: by the section index 
+/// in `path1`, then by the t value along that section.
 /// 
 /// The accuracy value indicates the maximum errors that's permitted for an intersection: the bezier curve
 /// intersection algorithm is approximate.
@@ -80,5 +85,56 @@ where
         }
     }
 
+    // Sort the results along path1 (the sections are already in order, but the intersections from different sections of path2 will be interleaved)
+    result.sort_by(|((section_a, t_a), _), ((section_b, t_b), _)| section_a.cmp(section_b).then(t_a.partial_cmp(t_b).unwrap_or(Ordering::Equal)));
+
     result
 }
+
+///
+/// Finds the points where a path crosses a line where one of the coordinates has a fixed value
+///
+/// The points are sorted by the `sort_dimension` coordinate
+///
+fn path_intersections_with_axis<P: BezierPath>(path: &P, dimension: usize, value: f64, sort_dimension: usize) -> Vec<P::Point> {
+    let mut points = path_to_curves::<_, Curve<_>>(path)
+        .flat_map(|curve| {
+            let (w1, w4)    = (curve.start_point(), curve.end_point());
+            let (w2, w3)    = curve.control_points();
+
+            solve_basis_for_t(w1.get(dimension), w2.get(dimension), w3.get(dimension), w4.get(dimension), value)
+                .into_iter()
+                .map(move |t| curve.point_at_pos(t))
+        })
+        .collect::<Vec<_>>();
+
+    // Sort along the line, and remove any duplicate points (which occur where the line passes through the point where two curves join)
+    points.sort_by(|a, b| a.get(sort_dimension).partial_cmp(&b.get(sort_dimension)).unwrap_or(Ordering::Equal));
+    points.dedup_by(|a, b| a.is_near_to(b, SMALL_DISTANCE));
+
+    points
+}
+
+///
+/// Finds the points where a path crosses a horizontal line at a particular y position
+///
+/// The points are returned sorted by their x coordinate.
+///
+pub fn path_intersections_with_horizontal<P: BezierPath>(path: &P, y: f64) -> Vec<P::Point>
+where
+    P::Point: Coordinate2D,
+{
+    path_intersections_with_axis(path, 1, y, 0)
+}
+
+///
+/// Finds the points where a path crosses a vertical line at a particular x position
+///
+/// The points are returned sorted by their y coordinate.
+///
+pub fn path_intersections_with_vertical<P: BezierPath>(path: &P, x: f64) -> Vec<P::Point>
+where
+    P::Point: Coordinate2D,
+{
+    path_intersections_with_axis(path, 0, x, 1)
+}
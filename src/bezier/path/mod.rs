@@ -22,7 +22,8 @@
 //! as a convenient default implementation of this trait. These paths represent a single perimeter of a region.
 //!
 //! The arithmetic operations such as `path_sub()`, `path_add()`, `path_intersect()` all work with collections of these
-//! perimeters, stored in a `Vec`. A path with a hole in the middle will have two perimeters, for example. 
+//! perimeters, stored in a `Vec`. A path with a hole in the middle will have two perimeters, for example. Any open
+//! perimeters are closed with a straight line from their end point back to their start point.
 //!
 //! These perimeters must not be self-intersecting: `flo_curves` doesn't use a winding rule as such but instead considers
 //! all edges to be exterior edges (which is very similar to an even-odd winding rule). A couple of methods are provided
@@ -39,20 +40,56 @@ mod path;
 mod to_curves;
 mod ray;
 mod point;
+mod distance;
 mod bounds;
 mod intersection;
+mod tangency;
 mod path_builder;
+mod polygon;
+mod rasterize;
+mod svg;
+mod clip;
+mod offset;
+mod stroke;
+mod sweep;
 mod graph_path;
 mod is_clockwise;
+mod dedup_anchors;
+mod fillet;
+mod simplify;
+mod orientation;
+mod approx_equal;
+mod enclosing_circle;
+mod arc_length;
+mod area;
+mod continuity;
 mod arithmetic;
 pub mod algorithms;
 
 pub use self::path::*;
 pub use self::to_curves::*;
 pub use self::point::*;
+pub use self::distance::*;
 pub use self::bounds::*;
 pub use self::intersection::*;
+pub use self::tangency::*;
 pub use self::path_builder::*;
+pub use self::polygon::*;
+pub use self::rasterize::*;
+pub use self::svg::*;
+pub use self::clip::*;
+pub use self::offset::*;
+pub use self::stroke::*;
+pub use self::sweep::*;
 pub use self::graph_path::*;
 pub use self::is_clockwise::*;
+pub use self::dedup_anchors::*;
+pub use self::fillet::*;
+pub use self::simplify::*;
+pub use self::orientation::*;
+pub use self::approx_equal::*;
+pub use self::enclosing_circle::*;
+pub use self::arc_length::*;
+pub use self::area::*;
+pub use self::continuity::*;
 pub use self::arithmetic::*;
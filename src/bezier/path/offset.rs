@@ -0,0 +1,123 @@
+use super::path::*;
+use super::to_curves::*;
+use super::super::curve::*;
+use super::super::offset::*;
+use super::super::normal::*;
+use super::super::continuity::*;
+use super::super::super::geo::*;
+use super::super::super::consts::*;
+
+/// The maximum difference between the unit tangents either side of a join for it to be considered smooth
+const SMOOTH_JOIN_EPSILON: f64 = 0.01;
+
+///
+/// Moves the ends of two curves so that they meet, and lines up their control points so the join is smooth
+///
+/// The curves meet at the average of their end points, and the control points on either side of the join keep their distance
+/// from it but are moved onto a shared tangent line.
+///
+fn stitch_join<Point: Coordinate+Coordinate2D>(first: &Curve<Point>, second: &Curve<Point>) -> (Curve<Point>, Curve<Point>) {
+    let (first_cp1, first_cp2)      = first.control_points();
+    let (second_cp1, second_cp2)    = second.control_points();
+    let join_point                  = (first.end_point() + second.start_point()) * 0.5;
+
+    // The shared tangent is the average of the directions either side of the join
+    let tangent                     = (end_direction(first).to_unit_vector() + start_direction(second).to_unit_vector()).to_unit_vector();
+
+    let (first_cp2, second_cp1)     = if tangent.magnitude() > 0.0 {
+        (join_point - tangent * first.end_point().distance_to(&first_cp2), join_point + tangent * second.start_point().distance_to(&second_cp1))
+    } else {
+        (first_cp2, second_cp1)
+    };
+
+    (Curve::from_points(first.start_point(), (first_cp1, first_cp2), join_point), Curve::from_points(join_point, (second_cp1, second_cp2), second.end_point()))
+}
+
+///
+/// Lines up every join within a run of curves
+///
+fn stitch_run<Point: Coordinate+Coordinate2D>(run: &mut [Curve<Point>]) {
+    for idx in 1..run.len() {
+        let (first, second) = stitch_join(&run[idx-1], &run[idx]);
+        run[idx-1]          = first;
+        run[idx]            = second;
+    }
+}
+
+///
+/// Returns true if the join between two curves in the source path is smooth
+///
+fn is_smooth_join<Point: Coordinate+Coordinate2D>(first: &Curve<Point>, second: &Curve<Point>) -> bool {
+    let first_direction     = end_direction(first).to_unit_vector();
+    let second_direction    = start_direction(second).to_unit_vector();
+
+    first_direction.magnitude() > 0.0 && first_direction.is_near_to(&second_direction, SMOOTH_JOIN_EPSILON)
+}
+
+///
+/// Offsets a path by a fixed distance, producing continuous offset curves wherever the original path is smooth
+///
+/// Each curve in the path is offset by `distance` along its normal (using the same method as `offset()`), and the offset
+/// sections are then joined up: where two curves meet smoothly in the original path (G1 continuity or better), the ends
+/// of the offset sections are moved to meet each other and their control points are lined up, so the offset is also smooth
+/// there. Corners in the original path can't be offset smoothly, so the offset is split into a new path at each corner. A
+/// smooth path therefore produces a single offset path, and a closed path is joined back up where it closes if its start
+/// is smooth.
+///
+pub fn offset_path_continuous<P>(path: &P, distance: f64) -> Vec<P>
+where
+    P:          BezierPathFactory,
+    P::Point:   Coordinate+Coordinate2D+Normalize,
+{
+    let curves                  = path_to_curves::<_, Curve<_>>(path).collect::<Vec<_>>();
+    if curves.is_empty() {
+        return vec![];
+    }
+
+    // Offset each curve, starting a new run after each corner
+    let mut runs                = vec![vec![]];
+    for (idx, curve) in curves.iter().enumerate() {
+        if idx > 0 && !is_smooth_join(&curves[idx-1], curve) {
+            runs.push(vec![]);
+        }
+
+        let run = runs.last_mut().unwrap();
+        run.extend(offset::<Curve<_>>(curve, distance, distance));
+    }
+
+    // Closed paths with a smooth start continue the last run into the first one
+    let smooth_close            = path.is_closed(SMALL_DISTANCE) && is_smooth_join(&curves[curves.len()-1], &curves[0]);
+    let is_smooth_loop          = smooth_close && runs.len() == 1;
+
+    if smooth_close && !is_smooth_loop {
+        let first_run = runs.remove(0);
+        runs.last_mut().unwrap().extend(first_run);
+    }
+
+    let mut runs                = runs.into_iter().filter(|run| !run.is_empty()).collect::<Vec<_>>();
+    for run in runs.iter_mut() {
+        stitch_run(run);
+    }
+
+    // A path that is smooth all the way round also needs to be stitched where it closes
+    if is_smooth_loop && runs.len() == 1 {
+        let run             = &mut runs[0];
+        let last_idx        = run.len()-1;
+        let (last, first)   = stitch_join(&run[last_idx], &run[0]);
+
+        if last_idx == 0 {
+            run[0]          = Curve::from_points(first.start_point(), (first.control_points().0, last.control_points().1), last.end_point());
+        } else {
+            run[last_idx]   = last;
+            run[0]          = first;
+        }
+    }
+
+    // Generate the paths from the runs
+    runs.into_iter()
+        .map(|run| P::from_points(run[0].start_point(), run.iter().map(|curve| {
+            let (cp1, cp2) = curve.control_points();
+            (cp1, cp2, curve.end_point())
+        })))
+        .collect()
+}
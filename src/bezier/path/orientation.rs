@@ -0,0 +1,65 @@
+use super::path::*;
+use super::point::*;
+use super::is_clockwise::*;
+use super::to_curves::*;
+use super::super::curve::*;
+use super::super::super::geo::*;
+
+///
+/// The conventions used by font formats for the direction of the outlines of filled regions
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OutlineConvention {
+    /// Outer outlines are clockwise and holes are anticlockwise (as used by TrueType fonts)
+    TrueType,
+
+    /// Outer outlines are anticlockwise and holes are clockwise (as used by PostScript and CFF fonts)
+    PostScript
+}
+
+///
+/// Changes the direction of a set of non-intersecting paths so they follow a winding convention
+///
+/// The nesting depth of each path is found by counting how many of the other paths contain it: paths with an even depth are
+/// the outer edges of filled regions and paths with an odd depth are holes. Paths that already have the correct direction are
+/// returned unchanged, and the others are reversed. The paths are returned in the same order as they were passed in.
+///
+pub fn normalize_orientation<P: BezierPathFactory>(paths: &[P], convention: OutlineConvention) -> Vec<P>
+where
+    P::Point: Coordinate+Coordinate2D,
+{
+    paths.iter().zip(path_nesting_depths(paths)).map(|(path, depth)| {
+        // Outer edges have an even depth
+        let is_outer    = (depth % 2) == 0;
+        let clockwise   = match convention {
+            OutlineConvention::TrueType     => is_outer,
+            OutlineConvention::PostScript   => !is_outer
+        };
+
+        if path.is_clockwise() == clockwise {
+            path.clone()
+        } else {
+            path.reversed()
+        }
+    }).collect()
+}
+
+///
+/// For a set of non-intersecting paths, returns how many of the other paths surround each path
+///
+pub (crate) fn path_nesting_depths<P: BezierPath>(paths: &[P]) -> Vec<usize>
+where
+    P::Point: Coordinate+Coordinate2D,
+{
+    paths.iter().enumerate().map(|(path_idx, path)| {
+        // The paths don't intersect, so any point on the path will do, but the start point is avoided as it's often lined up 
+        // with the start points of the other paths
+        let point       = path_to_curves::<_, Curve<_>>(path).next()
+            .map(|curve| curve.point_at_pos(0.5))
+            .unwrap_or_else(|| path.start_point());
+
+        paths.iter().enumerate()
+            .filter(|(other_idx, other)| *other_idx != path_idx && path_contains_point(*other, &point))
+            .count()
+    }).collect()
+}
@@ -3,11 +3,14 @@
 use super::bounds::*;
 use super::to_curves::*;
 use super::super::curve::*;
+use super::super::length::*;
 use super::super::super::geo::*;
 
 use itertools::*;
 use std::vec;
 use std::iter;
+use std::fmt;
+use std::error::Error;
 
 ///
 /// Trait representing a path made out of bezier sections
@@ -51,6 +54,47 @@ pub trait BezierPath : Geo+Clone+Sized {
         path_to_curves(self).collect()
     }
 
+    ///
+    /// Returns the points for each curve in this path, in the format `[start_point, control_point1, control_point2, end_point]`
+    ///
+    fn to_control_point_arrays(&self) -> Vec<[Self::Point; 4]> {
+        path_to_curves::<_, Curve<_>>(self)
+            .map(|curve| curve.control_point_array())
+            .collect()
+    }
+
+    ///
+    /// Returns the number of curve segments in this path
+    ///
+    #[inline]
+    fn num_segments(&self) -> usize {
+        self.points().count()
+    }
+
+    ///
+    /// Returns true if the end point of this path is within `epsilon` units of its start point
+    ///
+    /// Filling operations treat open paths as if there's a straight line between their end point and their start point.
+    /// A path with no curves is considered closed.
+    ///
+    fn is_closed(&self, epsilon: f64) -> bool {
+        let start_point = self.start_point();
+        let end_point   = self.points().last().map(|(_, _, end_point)| end_point).unwrap_or(start_point);
+
+        end_point.is_near_to(&start_point, epsilon)
+    }
+
+    ///
+    /// Estimates the length of this path by summing the arc lengths of each of its segments
+    ///
+    /// The tolerance is passed through to `curve_length()` for each segment
+    ///
+    fn approx_length(&self, tolerance: f64) -> f64 {
+        path_to_curves::<_, Curve<_>>(self)
+            .map(|curve| curve_length(&curve, tolerance))
+            .sum()
+    }
+
     ///
     /// Creates a reversed version of this path
     ///
@@ -77,6 +121,25 @@ pub trait BezierPath : Geo+Clone+Sized {
     }
 }
 
+///
+/// Errors that can occur when constructing a bezier path
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PathError {
+    /// One of the coordinates in the path was infinite or NaN (the value is the index of the point, 0 for the start point)
+    NonFiniteCoordinate(usize),
+}
+
+impl fmt::Display for PathError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PathError::NonFiniteCoordinate(idx) => write!(f, "Point {} in the path has a non-finite coordinate", idx)
+        }
+    }
+}
+
+impl Error for PathError { }
+
 ///
 /// Trait implemented by types that can construct new bezier paths
 ///
@@ -87,6 +150,29 @@ pub trait BezierPathFactory : BezierPath {
     /// 
     fn from_points<FromIter: IntoIterator<Item=(Self::Point, Self::Point, Self::Point)>>(start_point: Self::Point, points: FromIter) -> Self;
 
+    ///
+    /// Creates a new instance of this path from a set of points, checking that they are valid
+    ///
+    /// This is the same as `from_points()`, except an error is returned if any of the coordinates are infinite or NaN. Points are
+    /// numbered from the start point, so the first set of control points and end point is point 1.
+    ///
+    fn try_from_points<FromIter: IntoIterator<Item=(Self::Point, Self::Point, Self::Point)>>(start_point: Self::Point, points: FromIter) -> Result<Self, PathError> {
+        let is_finite   = |point: &Self::Point| (0..Self::Point::len()).all(|component| point.get(component).is_finite());
+        let points      = points.into_iter().collect::<Vec<_>>();
+
+        if !is_finite(&start_point) {
+            return Err(PathError::NonFiniteCoordinate(0));
+        }
+
+        for (idx, (cp1, cp2, end_point)) in points.iter().enumerate() {
+            if !is_finite(cp1) || !is_finite(cp2) || !is_finite(end_point) {
+                return Err(PathError::NonFiniteCoordinate(idx+1));
+            }
+        }
+
+        Ok(Self::from_points(start_point, points))
+    }
+
     ///
     /// Creates a new instance of this path from the points in another path
     ///
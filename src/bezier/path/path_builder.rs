@@ -1,4 +1,9 @@
 use super::path::*;
+use super::super::curve::*;
+use super::super::super::geo::*;
+use super::super::super::arc::*;
+
+use std::f64;
 
 ///
 /// Used to build a bezier path
@@ -29,16 +34,23 @@ impl<P: BezierPathFactory> BezierPathBuilder<P> {
         P::from_points(self.start_point, self.points)
     }
 
+    ///
+    /// The point where the next section of the path will start
+    ///
+    fn current_point(&self) -> P::Point {
+        if self.points.is_empty() {
+            self.start_point
+        } else {
+            self.points[self.points.len()-1].2
+        }
+    }
+
     ///
     /// Adds a line to the specified point
     /// 
     pub fn line_to(mut self, point: P::Point) -> Self {
         // Get the vector from the last point to the new point
-        let distance = if self.points.is_empty() {
-            point - self.start_point
-        } else {
-            point - self.points[self.points.len()-1].2
-        };
+        let distance = point - self.current_point();
 
         // A line puts control points at 33% and 66% of the distance
         let cp1 = point - (distance*0.6666);
@@ -49,6 +61,15 @@ impl<P: BezierPathFactory> BezierPathBuilder<P> {
         self
     }
 
+    ///
+    /// Adds a line to a point specified relative to the end of the path so far
+    ///
+    pub fn rel_line_to(self, offset: P::Point) -> Self {
+        let point = self.current_point() + offset;
+
+        self.line_to(point)
+    }
+
     ///
     /// Adds a curve to a particular point
     /// 
@@ -57,4 +78,83 @@ impl<P: BezierPathFactory> BezierPathBuilder<P> {
 
         self
     }
-}
\ No newline at end of file
+
+    ///
+    /// Adds a curve where the control points and end point are specified relative to the end of the path so far
+    ///
+    pub fn rel_curve_to(self, (cp1, cp2): (P::Point, P::Point), end_point: P::Point) -> Self {
+        let origin = self.current_point();
+
+        self.curve_to((origin + cp1, origin + cp2), origin + end_point)
+    }
+}
+
+impl<P: BezierPathFactory> BezierPathBuilder<P>
+where
+    P::Point: Coordinate2D,
+{
+    ///
+    /// Adds the curves approximating an arc of a unit circle, after mapping their points through a transformation function
+    ///
+    /// A line is added to the start of the arc if the path does not already end there
+    ///
+    fn unit_arc_to<TransformFn: Fn(f64, f64) -> P::Point>(mut self, start_angle: f64, end_angle: f64, transform: TransformFn) -> Self {
+        // Split the arc into sections of at most 90 degrees (the approximation gets very inaccurate for larger angles)
+        let sweep           = end_angle - start_angle;
+        let num_sections    = (sweep.abs() / (f64::consts::PI/2.0)).ceil().max(1.0) as usize;
+        let section_sweep   = sweep / (num_sections as f64);
+
+        // Move to the start of the arc
+        let start_point     = transform(start_angle.cos(), start_angle.sin());
+        if !self.current_point().is_near_to(&start_point, 1e-9) {
+            self = self.line_to(start_point);
+        }
+
+        // Arcs are measured clockwise from the y axis, while our angles are measured anticlockwise from the x axis
+        let unit_circle     = Circle::new(Coord2(0.0, 0.0), 1.0);
+
+        for section in 0..num_sections {
+            let section_start   = start_angle + section_sweep * (section as f64);
+            let section_end     = section_start + section_sweep;
+            let arc             = unit_circle.arc(f64::consts::PI/2.0 - section_start, f64::consts::PI/2.0 - section_end);
+            let curve           = arc.to_bezier_curve::<Curve<Coord2>>();
+
+            let (cp1, cp2)      = curve.control_points();
+            let end_point       = curve.end_point();
+
+            self.points.push((transform(cp1.x(), cp1.y()), transform(cp2.x(), cp2.y()), transform(end_point.x(), end_point.y())));
+        }
+
+        self
+    }
+
+    ///
+    /// Adds a circular arc to the path
+    ///
+    /// Angles are in radians, measured anticlockwise from the x axis (assuming positive y is upwards). The arc will be drawn clockwise
+    /// if `end_angle` is less than `start_angle`. If the path does not already end at the start of the arc, a line is added to join
+    /// them.
+    ///
+    pub fn arc_to(self, center: P::Point, radius: f64, start_angle: f64, end_angle: f64) -> Self {
+        self.unit_arc_to(start_angle, end_angle, move |x, y| P::Point::from_components(&[center.x() + x*radius, center.y() + y*radius]))
+    }
+
+    ///
+    /// Adds an elliptical arc to the path
+    ///
+    /// The ellipse has radii `rx` and `ry` and is rotated by `x_axis_rotation` radians around its center. The start and end angles 
+    /// are the parametric angles on the ellipse before it is rotated, in radians measured anticlockwise from the x axis. As for 
+    /// `arc_to()`, a line is added to join the path to the start of the arc if necessary.
+    ///
+    pub fn elliptical_arc_to(self, center: P::Point, (rx, ry): (f64, f64), x_axis_rotation: f64, start_angle: f64, end_angle: f64) -> Self {
+        // Bezier curves are affine-invariant, so we can transform the unit circle into the ellipse by transforming its control points
+        let (sin_rotation, cos_rotation) = x_axis_rotation.sin_cos();
+
+        self.unit_arc_to(start_angle, end_angle, move |x, y| {
+            let (x, y) = (x*rx, y*ry);
+            let (x, y) = (x*cos_rotation - y*sin_rotation, x*sin_rotation + y*cos_rotation);
+
+            P::Point::from_components(&[center.x() + x, center.y() + y])
+        })
+    }
+}
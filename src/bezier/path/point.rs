@@ -2,9 +2,12 @@ use super::ray::*;
 use super::path::*;
 use super::to_curves::*;
 use super::graph_path::*;
+use super::arithmetic::*;
 use super::super::curve::*;
 use super::super::normal::*;
 use super::super::super::geo::*;
+use super::super::super::line::*;
+use super::super::super::consts::*;
 
 use smallvec::*;
 
@@ -120,9 +123,13 @@ where
 }
 
 ///
-/// Returns true if a particular point is within a bezier path
-/// 
-pub fn path_contains_point<P: BezierPath>(path: &P, point: &P::Point) -> bool
+/// Returns the winding number of a bezier path around a point
+///
+/// This is the number of times the path goes around the point: 0 if the point is outside the path. The sign indicates the
+/// direction in which the path winds around the point. Paths that are not closed are treated as if there's a straight line
+/// from their end point back to their start point.
+///
+pub fn path_winding_number<P: BezierPath>(path: &P, point: &P::Point) -> i32
 where 
     P::Point: Coordinate2D,
 {
@@ -131,14 +138,21 @@ where
 
     if min_bounds.x() > point.x() || max_bounds.x() < point.x() || min_bounds.y() > point.y() || max_bounds.y() < point.y() {
         // Point is outside the bounds of the path
-        false
+        0
     } else {
         // Ray is from the top of the bounds to our point
         let ray             = (max_bounds + P::Point::from_components(&[0.01, 0.01]), *point);
         let ray_direction   = ray.1 - ray.0;
 
         // Call through to ray_collisions to get the collisions
-        let curves          = path_to_curves::<_, Curve<_>>(path).collect::<Vec<_>>();
+        let mut curves      = path_to_curves::<_, Curve<_>>(path).collect::<Vec<_>>();
+
+        // Open paths are treated as if they're closed with a straight line
+        if !path.is_closed(SMALL_DISTANCE) {
+            let end_point = curves[curves.len()-1].end_point();
+            curves.push(line_to_bezier(&(end_point, path.start_point())));
+        }
+
         let collisions      = ray_collisions(&curves, &ray);
 
         // The total of all of the ray directions
@@ -159,7 +173,75 @@ where
             total_direction += direction;
         }
 
-        // Point is inside the path if the ray crosses more lines facing in a particular direction
-        total_direction != 0
+        total_direction
     }
 }
+
+///
+/// Returns true if a particular point is within a bezier path
+/// 
+pub fn path_contains_point<P: BezierPath>(path: &P, point: &P::Point) -> bool
+where 
+    P::Point: Coordinate2D,
+{
+    // Point is inside the path if the ray crosses more lines facing in a particular direction
+    path_winding_number(path, point) != 0
+}
+
+///
+/// Returns true if a point is inside the region described by a set of paths, using a fill rule to decide which parts are filled
+///
+/// The winding numbers of each path around the point (see `path_winding_number()`) are added together, so paths that overlap or
+/// are inside each other will produce holes depending on the fill rule and their direction. Places where the ray that finds the
+/// winding number touches an edge without crossing it are not counted, so points near corners and tangents give a consistent
+/// result. Points exactly on an edge are inside or outside depending on where the edge is, but always give the same result.
+///
+pub fn paths_contain_point<P: BezierPath>(paths: &[P], point: &P::Point, rule: FillRule) -> bool
+where
+    P::Point: Coordinate2D,
+{
+    let winding_number = paths.iter()
+        .map(|path| path_winding_number(path, point))
+        .sum::<i32>();
+
+    match rule {
+        FillRule::NonZero   => winding_number != 0,
+        FillRule::EvenOdd   => (winding_number % 2) != 0,
+    }
+}
+
+///
+/// Counts the number of times a ray starting at `origin` and travelling in `direction` crosses the edges of a set of paths
+///
+/// Unlike `path_winding_number()`, the ray can be cast in any direction, and crossings in either direction are counted. Places
+/// where the ray just touches an edge without crossing it are not counted, and paths that are not closed are treated as if
+/// there's a straight line from their end point back to their start point. The point is inside the region described by the
+/// paths using the even-odd rule if the result is odd.
+///
+pub fn ray_crossings<P: BezierPath>(paths: &[P], origin: &P::Point, direction: &P::Point) -> usize
+where
+    P::Point: Coordinate2D,
+{
+    let ray = (*origin, *origin + *direction);
+
+    paths.iter()
+        .map(|path| {
+            let mut curves = path_to_curves::<_, Curve<_>>(path).collect::<Vec<_>>();
+            if curves.is_empty() {
+                return 0;
+            }
+
+            // Open paths are treated as if they're closed with a straight line
+            if !path.is_closed(SMALL_DISTANCE) {
+                let end_point = curves[curves.len()-1].end_point();
+                curves.push(line_to_bezier(&(end_point, path.start_point())));
+            }
+
+            // Only the collisions in front of the origin are on the ray
+            ray_collisions(&curves, &ray)
+                .into_iter()
+                .filter(|(_collision, _curve_t, line_t, _pos)| *line_t >= 0.0)
+                .count()
+        })
+        .sum()
+}
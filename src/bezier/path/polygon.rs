@@ -0,0 +1,183 @@
+use super::path::*;
+use super::to_curves::*;
+use super::super::curve::*;
+use super::super::super::geo::*;
+use super::super::super::line::*;
+use super::super::super::consts::*;
+
+use itertools::*;
+
+///
+/// Creates a path made up of straight lines between a list of points
+///
+/// If `closed` is true, a final line is added from the last point back to the first point (unless the last point is already
+/// at the same position as the first point). A polygon with no points is a path with no curves starting at the origin.
+///
+pub fn polygon_to_path<P: BezierPathFactory>(points: &[P::Point], closed: bool) -> P {
+    if points.is_empty() {
+        return P::from_points(P::Point::origin(), vec![]);
+    }
+
+    let start_point = points[0];
+    let closing     = if closed && points[points.len()-1] != start_point { Some(start_point) } else { None };
+
+    // Generate a line from each point to the next one
+    let lines       = points.iter().cloned()
+        .chain(closing)
+        .tuple_windows()
+        .map(|line: (P::Point, P::Point)| line_to_bezier::<_, Curve<_>>(&line))
+        .map(|curve| {
+            let (cp1, cp2) = curve.control_points();
+            (cp1, cp2, curve.end_point())
+        });
+
+    P::from_points(start_point, lines.collect::<Vec<_>>())
+}
+
+///
+/// Closes a path by adding a straight line from its end point back to its start point
+///
+/// If the end point is already within `SMALL_DISTANCE` of the start point, no line is added: instead the end point is moved so
+/// it's exactly at the start point. Paths with no curves are returned unchanged.
+///
+pub fn close_path<P: BezierPathFactory>(path: &P) -> P {
+    let start_point     = path.start_point();
+    let mut points      = path.points().collect::<Vec<_>>();
+
+    if let Some((_, _, end_point)) = points.last_mut() {
+        if end_point.is_near_to(&start_point, SMALL_DISTANCE) {
+            // Just snap the end point so the path closes exactly
+            *end_point = start_point;
+        } else {
+            // Add a line back to the start
+            let line        = line_to_bezier::<_, Curve<_>>(&(*end_point, start_point));
+            let (cp1, cp2)  = line.control_points();
+
+            points.push((cp1, cp2, start_point));
+        }
+    }
+
+    P::from_points(start_point, points)
+}
+
+///
+/// Creates a smooth closed path that passes through each of a list of points
+///
+/// The curves between the points are generated as a closed Catmull-Rom spline: the tangent at each point is parallel to the line
+/// between the points either side of it. `smoothness` scales the tangents: 0.0 produces straight lines between the points
+/// (a polygon), and 1.0 produces a standard Catmull-Rom spline. If the last point is the same as the first, it's ignored.
+/// Fewer than 2 points produces a path with no curves.
+///
+pub fn smooth_closed_path_through<P: BezierPathFactory>(points: &[P::Point], smoothness: f64) -> P {
+    if points.is_empty() {
+        return P::from_points(P::Point::origin(), vec![]);
+    }
+
+    // The path closes itself, so there's no need for a duplicate start point
+    let points = if points.len() > 1 && points[0] == points[points.len()-1] { &points[0..points.len()-1] } else { points };
+
+    if points.len() < 2 {
+        return P::from_points(points[0], vec![]);
+    }
+
+    // Each point has a tangent parallel to the line between its neighbours
+    let num_points  = points.len();
+    let scale       = smoothness / 6.0;
+    let tangents    = (0..num_points)
+        .map(|idx| (points[(idx+1)%num_points] - points[(idx+num_points-1)%num_points]) * scale)
+        .collect::<Vec<_>>();
+
+    // Generate a curve between each pair of points, finishing with one back to the start
+    let curves      = (0..num_points)
+        .map(|idx| {
+            let next_idx = (idx+1)%num_points;
+            (points[idx] + tangents[idx], points[next_idx] - tangents[next_idx], points[next_idx])
+        });
+
+    P::from_points(points[0], curves.collect::<Vec<_>>())
+}
+
+///
+/// The distance from a point to the closest point on the line segment between `start` and `end`
+///
+#[inline]
+fn distance_to_segment<Point: Coordinate>(point: &Point, start: &Point, end: &Point) -> f64 {
+    let segment     = *end - *start;
+    let length_sq   = segment.dot(&segment);
+
+    if length_sq <= 0.0 {
+        point.distance_to(start)
+    } else {
+        let t = ((*point - *start).dot(&segment) / length_sq).clamp(0.0, 1.0);
+        point.distance_to(&(*start + segment * t))
+    }
+}
+
+///
+/// Adds the end points of the lines approximating a curve to `points`, subdividing it until the chord is within `max_error`
+/// of the curve
+///
+fn flatten_curve<Point: Coordinate>(curve: &Curve<Point>, max_error: f64, max_depth: usize, points: &mut Vec<Point>) {
+    let start_point = curve.start_point();
+    let end_point   = curve.end_point();
+    let (cp1, cp2)  = curve.control_points();
+
+    // The curve is inside the convex hull of its control points, so it can't be further away from the chord than they are
+    let hull_error  = distance_to_segment(&cp1, &start_point, &end_point).max(distance_to_segment(&cp2, &start_point, &end_point));
+
+    if hull_error <= max_error || max_depth == 0 {
+        points.push(end_point);
+    } else {
+        let (first, second) = curve.subdivide::<Curve<_>>(0.5);
+
+        flatten_curve(&first, max_error, max_depth-1, points);
+        flatten_curve(&second, max_error, max_depth-1, points);
+    }
+}
+
+///
+/// Finds how many times a curve needs to be divided in half so its chords are guaranteed to be within `max_error` of it
+///
+/// This uses Wang's formula: dividing a cubic curve into `n` sections with equal t ranges puts each chord within
+/// `3/4 * M / n^2` of the curve, where `M` is the length of the largest second difference of the control points.
+///
+fn flatten_depth<Point: Coordinate>(curve: &Curve<Point>, max_error: f64) -> usize {
+    // Limit the amount of subdivision when there's no error allowed at all (or the error is NaN)
+    const MAX_ZERO_ERROR_DEPTH: usize = 16;
+
+    let start_point         = curve.start_point();
+    let end_point           = curve.end_point();
+    let (cp1, cp2)          = curve.control_points();
+    let second_difference   = (start_point - cp1*2.0 + cp2).magnitude().max((cp1 - cp2*2.0 + end_point).magnitude());
+
+    if second_difference <= 0.0 {
+        // Straight lines with evenly spaced control points are exactly the same as their chord
+        0
+    } else if max_error > 0.0 {
+        let num_sections = (0.75 * second_difference / max_error).sqrt();
+        num_sections.log2().ceil().max(0.0) as usize
+    } else {
+        MAX_ZERO_ERROR_DEPTH
+    }
+}
+
+///
+/// Converts a path to a set of straight lines, where each line is guaranteed to be within `max_chord_error` of the curve it
+/// replaces
+///
+/// The result includes the start point of the path and the end point of every curve. Curves are subdivided until their control
+/// points (and hence the whole curve) are within `max_chord_error` of the line between their start and end points, so this
+/// is a stricter guarantee than sampling the curves at a fixed spacing. The amount of subdivision is limited to the depth
+/// at which Wang's formula guarantees the error bound, so the bound always holds for a positive `max_chord_error`. An error
+/// of 0 can't be met by a finite number of lines, so in that case the subdivision stops after 16 levels.
+///
+pub fn to_polygon_with_chord_error<P: BezierPath>(path: &P, max_chord_error: f64) -> Vec<P::Point> {
+    let mut points = vec![path.start_point()];
+
+    for curve in path_to_curves::<_, Curve<_>>(path) {
+        let max_depth = flatten_depth(&curve, max_chord_error);
+        flatten_curve(&curve, max_chord_error, max_depth, &mut points);
+    }
+
+    points
+}
@@ -0,0 +1,108 @@
+use super::path::*;
+use super::polygon::*;
+use super::arithmetic::*;
+use super::super::super::geo::*;
+
+use std::ops::{Range};
+use std::cmp::{Ordering};
+
+/// The maximum distance between the flattened edges used for rasterizing and the curves of a path
+const FLATTEN_ERROR: f64 = 0.05;
+
+///
+/// A straight edge of a flattened path, which crosses the scanlines between `min_y` and `max_y`
+///
+struct ScanlineEdge {
+    min_y:      f64,
+    max_y:      f64,
+    x_at_min_y: f64,
+    dx_dy:      f64,
+    direction:  i32
+}
+
+///
+/// Calls a function with the ranges that a set of paths cover on each row of a grid
+///
+/// The grid is `size.0` units wide and `size.1` units high, and each row is sampled along the line through the center of its
+/// pixels (so row `y` is sampled at `y + 0.5`). `emit` is called once for each row, in order, with the row index and the
+/// sorted list of ranges along the x axis that are filled on that row (which is empty for rows that aren't covered by the
+/// paths). Ranges are clipped to the width of the grid. The curves are flattened to within `0.05` units before rasterizing,
+/// and open paths are treated as if they're closed with a straight line.
+///
+/// This produces one row at a time so the result can be copied into a framebuffer without needing to allocate a
+/// separate coverage buffer.
+///
+pub fn rasterize_scanlines<P, EmitFn>(paths: &[P], size: (usize, usize), rule: FillRule, mut emit: EmitFn)
+where
+    P:          BezierPath,
+    P::Point:   Coordinate+Coordinate2D,
+    EmitFn:     FnMut(usize, &[Range<f64>]),
+{
+    let (width, height) = size;
+    let width           = width as f64;
+
+    // Flatten the paths into a set of edges
+    let mut edges = vec![];
+
+    for path in paths.iter() {
+        let mut polygon = to_polygon_with_chord_error(path, FLATTEN_ERROR);
+        polygon.push(path.start_point());
+
+        for (start, end) in polygon.iter().zip(polygon.iter().skip(1)) {
+            // Horizontal edges never cross a scanline
+            if start.y() == end.y() { continue; }
+
+            let (low, high, direction) = if start.y() < end.y() { (start, end, 1) } else { (end, start, -1) };
+
+            edges.push(ScanlineEdge {
+                min_y:      low.y(),
+                max_y:      high.y(),
+                x_at_min_y: low.x(),
+                dx_dy:      (high.x() - low.x()) / (high.y() - low.y()),
+                direction:  direction
+            });
+        }
+    }
+
+    // Generate the ranges for each row
+    let mut crossings   = vec![];
+    let mut ranges      = vec![];
+
+    for row in 0..height {
+        let y = (row as f64) + 0.5;
+
+        // Edges include their start point but not their end point, so edges that join on the scanline are only counted once
+        crossings.clear();
+        crossings.extend(edges.iter()
+            .filter(|edge| edge.min_y <= y && y < edge.max_y)
+            .map(|edge| (edge.x_at_min_y + (y - edge.min_y) * edge.dx_dy, edge.direction)));
+        crossings.sort_by(|(x1, _), (x2, _)| x1.partial_cmp(x2).unwrap_or(Ordering::Equal));
+
+        // Track the winding number along the row to find the filled ranges
+        ranges.clear();
+        let mut winding     = 0;
+        let mut fill_start  = None;
+
+        for (x, direction) in crossings.iter() {
+            winding += direction;
+
+            let is_filled = match rule {
+                FillRule::NonZero   => winding != 0,
+                FillRule::EvenOdd   => (winding % 2) != 0
+            };
+
+            match (is_filled, fill_start) {
+                (true, None)            => { fill_start = Some(*x); }
+                (false, Some(start))    => {
+                    let range = start.max(0.0)..x.min(width);
+                    if range.start < range.end { ranges.push(range); }
+
+                    fill_start = None;
+                }
+                _                       => { }
+            }
+        }
+
+        emit(row, &ranges);
+    }
+}
@@ -0,0 +1,87 @@
+use super::path::*;
+use super::to_curves::*;
+use super::super::fit::*;
+use super::super::curve::*;
+use super::super::continuity::*;
+use super::super::super::geo::*;
+
+/// Number of points sampled from each curve in the source path when refitting it
+const SIMPLIFY_SAMPLES_PER_CURVE: usize = 16;
+
+/// The maximum difference between the unit tangents either side of a join for it to be merged with its neighbours
+const SIMPLIFY_SMOOTH_EPSILON: f64 = 0.01;
+
+///
+/// Refits a run of curves that join smoothly, returning the original curves if the fit doesn't need fewer curves
+///
+fn simplify_run<Point: Coordinate+Coordinate2D>(run: &[Curve<Point>], max_error: f64) -> Vec<Curve<Point>> {
+    if run.len() < 2 {
+        return run.to_vec();
+    }
+
+    // Sample points along the run (leaving out any repeated points, which have no direction)
+    let mut points = vec![run[0].start_point()];
+    for curve in run.iter() {
+        for sample_idx in 1..=SIMPLIFY_SAMPLES_PER_CURVE {
+            let point = curve.point_at_pos((sample_idx as f64) / (SIMPLIFY_SAMPLES_PER_CURVE as f64));
+
+            if Some(&point) != points.last() {
+                points.push(point);
+            }
+        }
+    }
+
+    // Fit using the tangents at the ends of the run, so the joins to the neighbouring runs are unchanged
+    let start_tangent   = start_direction(&run[0]).to_unit_vector();
+    let end_tangent     = (end_direction(&run[run.len()-1]) * -1.0).to_unit_vector();
+    let fitted          = fit_curve_cubic::<Curve<_>>(&points, &start_tangent, &end_tangent, max_error);
+
+    if fitted.is_empty() || fitted.len() >= run.len() {
+        run.to_vec()
+    } else {
+        fitted
+    }
+}
+
+///
+/// Simplifies a path by replacing it with as few curves as possible while keeping within `max_error` of its original shape
+///
+/// This is useful for paths that have been divided into many more curves than are needed to represent their shape, such as
+/// those imported from other programs or produced by flattening. The path is divided into runs of curves that join smoothly,
+/// and each run is sampled and refitted using `fit_curve_cubic()`, which merges neighbouring curves that can be represented
+/// by a single curve (such as nearly-collinear lines or arcs of the same circle). Corners in the path are kept where they
+/// are, and runs that can't be represented using fewer curves are left unchanged.
+///
+pub fn simplify_path<P: BezierPathFactory>(path: &P, max_error: f64) -> P
+where
+    P::Point: Coordinate+Coordinate2D,
+{
+    let curves  = path_to_curves::<_, Curve<_>>(path).collect::<Vec<_>>();
+
+    // Split the path into runs at its corners
+    let mut runs: Vec<Vec<Curve<_>>> = vec![];
+    for (idx, curve) in curves.iter().enumerate() {
+        let is_smooth = idx > 0 && {
+            let incoming = end_direction(&curves[idx-1]).to_unit_vector();
+            let outgoing = start_direction(curve).to_unit_vector();
+
+            incoming.magnitude() > 0.0 && incoming.is_near_to(&outgoing, SIMPLIFY_SMOOTH_EPSILON)
+        };
+
+        match runs.last_mut() {
+            Some(run) if is_smooth  => run.push(*curve),
+            _                       => runs.push(vec![*curve]),
+        }
+    }
+
+    // Refit each run
+    let points = runs.iter()
+        .flat_map(|run| simplify_run(run, max_error))
+        .map(|curve| {
+            let (cp1, cp2) = curve.control_points();
+            (cp1, cp2, curve.end_point())
+        })
+        .collect::<Vec<_>>();
+
+    P::from_points(path.start_point(), points)
+}
@@ -0,0 +1,296 @@
+use super::path::*;
+use super::to_curves::*;
+use super::super::curve::*;
+use super::super::offset::*;
+use super::super::normal::*;
+use super::super::continuity::*;
+use super::super::super::geo::*;
+use super::super::super::arc::*;
+use super::super::super::consts::*;
+
+///
+/// How the outline of a stroke is joined where the path has a corner
+///
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum LineJoin {
+    /// The outer edges of the stroke are extended until they meet at a point (falling back to a bevel join if the point is
+    /// further away than the miter limit)
+    Miter,
+
+    /// The outer edges of the stroke are joined by a circular arc around the corner
+    Round,
+
+    /// The outer edges of the stroke are joined by a straight line
+    Bevel
+}
+
+///
+/// How the ends of the stroke of an open path are drawn
+///
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum LineCap {
+    /// The stroke ends exactly at the end of the path
+    Butt,
+
+    /// The stroke ends with a semicircle around the end of the path
+    Round,
+
+    /// The stroke is extended past the end of the path by half its width
+    Square
+}
+
+///
+/// Options that affect how a path is converted into a stroke
+///
+/// The default options are created using `StrokeOptions::default()`, which uses the same defaults as SVG: miter joins
+/// with a miter limit of 4, and butt caps.
+///
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct StrokeOptions {
+    /// How the stroke is joined at corners
+    pub (crate) join: LineJoin,
+
+    /// How the ends of the stroke are drawn
+    pub (crate) cap: LineCap,
+
+    /// The longest miter join to allow, as a ratio of the distance from the corner to the tip of the join and half the stroke width
+    pub (crate) miter_limit: f64
+}
+
+impl StrokeOptions {
+    ///
+    /// Creates a new set of stroke options from this one by setting the join style
+    ///
+    pub fn with_join(self, new_join: LineJoin) -> StrokeOptions {
+        let mut new_options = self;
+        new_options.join = new_join;
+        new_options
+    }
+
+    ///
+    /// Creates a new set of stroke options from this one by setting the cap style
+    ///
+    pub fn with_cap(self, new_cap: LineCap) -> StrokeOptions {
+        let mut new_options = self;
+        new_options.cap = new_cap;
+        new_options
+    }
+
+    ///
+    /// Creates a new set of stroke options from this one by setting the miter limit
+    ///
+    /// This is the ratio of the length of a miter join (measured from the corner to its tip) to half the width of the
+    /// stroke. Corners that need longer miters than this use a bevel join instead, which stops very sharp corners from
+    /// producing long spikes.
+    ///
+    pub fn with_miter_limit(self, new_miter_limit: f64) -> StrokeOptions {
+        let mut new_options = self;
+        new_options.miter_limit = new_miter_limit;
+        new_options
+    }
+}
+
+impl Default for StrokeOptions {
+    ///
+    /// Creates the default set of stroke options
+    ///
+    fn default() -> StrokeOptions {
+        StrokeOptions {
+            join:           LineJoin::Miter,
+            cap:            LineCap::Butt,
+            miter_limit:    4.0
+        }
+    }
+}
+
+///
+/// Adds a straight line to a set of path points
+///
+#[inline]
+fn line_to<Point: Coordinate>(points: &mut Vec<(Point, Point, Point)>, start: Point, end: Point) {
+    points.push((start + (end-start) * (1.0/3.0), start + (end-start) * (2.0/3.0), end));
+}
+
+///
+/// Adds a circular arc around `center` to a set of path points: the arc always goes the short way around the circle
+///
+fn arc_to<Point: Coordinate+Coordinate2D>(points: &mut Vec<(Point, Point, Point)>, center: Point, start: Point, end: Point) {
+    let radius  = center.distance_to(&start);
+    let sweep   = (start.x()-center.x())*(end.y()-center.y()) - (start.y()-center.y())*(end.x()-center.x()) > 0.0;
+
+    for curve in svg_arc_to_beziers::<Curve<_>>(start, radius, radius, 0.0, false, sweep, end) {
+        let (cp1, cp2) = curve.control_points();
+        points.push((cp1, cp2, curve.end_point()));
+    }
+}
+
+///
+/// Adds the join between two offset curves to one side of a stroke outline
+///
+/// `corner` is the point on the original path, `start` and `end` are where the offset curves before and after the corner
+/// end and start, and `outgoing` is the direction of the original path after the corner.
+///
+fn add_join<Point: Coordinate+Coordinate2D>(points: &mut Vec<(Point, Point, Point)>, corner: Point, start: Point, end: Point, outgoing: Point, options: &StrokeOptions) {
+    if start.is_near_to(&end, SMALL_DISTANCE) {
+        // The offset curves already meet
+        return;
+    }
+
+    let normal1 = (start - corner).to_unit_vector();
+    let normal2 = (end - corner).to_unit_vector();
+
+    if normal1.dot(&outgoing) > 0.0 {
+        // This is the inside of the corner: the offset curves overlap, so join them through the corner (the overlap is filled by the non-zero rule)
+        line_to(points, start, corner);
+        line_to(points, corner, end);
+    } else {
+        match options.join {
+            LineJoin::Bevel => line_to(points, start, end),
+            LineJoin::Round => arc_to(points, corner, start, end),
+            LineJoin::Miter => {
+                // The tip of the miter is on the line bisecting the two normals
+                let bisector    = normal1 + normal2;
+                let half_width  = corner.distance_to(&start);
+                let ratio       = if bisector.magnitude() > 0.0 { 2.0 / bisector.magnitude() } else { f64::MAX };
+
+                if ratio <= options.miter_limit {
+                    let tip = corner + bisector * (2.0 * half_width / bisector.dot(&bisector));
+
+                    line_to(points, start, tip);
+                    line_to(points, tip, end);
+                } else {
+                    line_to(points, start, end);
+                }
+            }
+        }
+    }
+}
+
+///
+/// Adds the cap at the end of an open stroke, going from `start` to `end` around the end point `center` of the original path
+///
+/// `direction` is the unit vector pointing out of the end of the path.
+///
+fn add_cap<Point: Coordinate+Coordinate2D>(points: &mut Vec<(Point, Point, Point)>, center: Point, start: Point, end: Point, direction: Point, options: &StrokeOptions) {
+    let half_width  = center.distance_to(&start);
+    let extension   = direction * half_width;
+
+    match options.cap {
+        LineCap::Butt   => line_to(points, start, end),
+
+        LineCap::Square => {
+            line_to(points, start, start + extension);
+            line_to(points, start + extension, end + extension);
+            line_to(points, end + extension, end);
+        }
+
+        LineCap::Round  => {
+            // Split the semicircle at its tip so each half is unambiguously the short way around
+            arc_to(points, center, start, center + extension);
+            arc_to(points, center, center + extension, end);
+        }
+    }
+}
+
+///
+/// Generates one side of a stroke outline by offsetting each curve and joining them together
+///
+fn stroke_side<Point>(curves: &[Curve<Point>], distance: f64, is_closed: bool, options: &StrokeOptions) -> (Point, Vec<(Point, Point, Point)>)
+where
+    Point: Coordinate+Coordinate2D+Normalize,
+{
+    // Offset each of the curves, falling back to a straight line if the offset can't be fitted
+    let offsets = curves.iter()
+        .map(|curve| {
+            let offset_curves = offset::<Curve<_>>(curve, distance, distance);
+
+            if offset_curves.is_empty() {
+                let start   = curve.start_point() + curve.normal_at_pos(0.0).to_unit_vector() * distance;
+                let end     = curve.end_point() + curve.normal_at_pos(1.0).to_unit_vector() * distance;
+                vec![Curve::from_points(start, (start + (end-start) * (1.0/3.0), start + (end-start) * (2.0/3.0)), end)]
+            } else {
+                offset_curves
+            }
+        })
+        .collect::<Vec<_>>();
+
+    // Join the offset curves together
+    let start_point = offsets[0][0].start_point();
+    let mut points  = vec![];
+
+    for (idx, offset_curves) in offsets.iter().enumerate() {
+        for curve in offset_curves.iter() {
+            let (cp1, cp2) = curve.control_points();
+            points.push((cp1, cp2, curve.end_point()));
+        }
+
+        // Join to the next curve, which wraps around to the start of the path if it's closed
+        let next_idx = if idx+1 < curves.len() { idx+1 } else if is_closed { 0 } else { continue };
+
+        let corner      = curves[idx].end_point();
+        let end         = offset_curves[offset_curves.len()-1].end_point();
+        let next_start  = offsets[next_idx][0].start_point();
+        let outgoing    = start_direction(&curves[next_idx]).to_unit_vector();
+
+        add_join(&mut points, corner, end, next_start, outgoing, options);
+    }
+
+    (start_point, points)
+}
+
+///
+/// Converts a path into the outline of a stroke of a particular width along it
+///
+/// Each curve in the path is offset by half of `width` on either side (using the same method as `offset()`), and the offset
+/// curves are joined at corners using the join style from `options`. An open path produces a single closed outline, with caps
+/// at either end. A closed path produces two outlines, one for each side of the stroke, going in opposite directions so that
+/// the inside of the path is a hole. Where the offset curves overlap (such as on the inside of corners), the outline
+/// overlaps itself, so the result should be filled using the non-zero winding rule. Curves with no length are ignored, and
+/// the result is empty if the path has no length at all.
+///
+pub fn stroke_path<P, POut>(path: &P, width: f64, options: &StrokeOptions) -> Vec<POut>
+where
+    P:          BezierPath,
+    P::Point:   Coordinate+Coordinate2D+Normalize,
+    POut:       BezierPathFactory<Point=P::Point>,
+{
+    // Ignore any curves with no length
+    let curves = path_to_curves::<_, Curve<_>>(path)
+        .filter(|curve| {
+            let start_point = curve.start_point();
+            let (cp1, cp2)  = curve.control_points();
+            let end_point   = curve.end_point();
+
+            !(start_point.is_near_to(&cp1, SMALL_DISTANCE) && start_point.is_near_to(&cp2, SMALL_DISTANCE) && start_point.is_near_to(&end_point, SMALL_DISTANCE))
+        })
+        .collect::<Vec<_>>();
+
+    if curves.is_empty() {
+        return vec![];
+    }
+
+    let half_width              = width / 2.0;
+    let is_closed               = path.is_closed(SMALL_DISTANCE);
+    let (left_start, left)      = stroke_side(&curves, half_width, is_closed, options);
+
+    // The other side is generated by offsetting the reversed path, so it goes in the opposite direction
+    let reversed                = curves.iter().rev().map(|curve| curve.reverse::<Curve<_>>()).collect::<Vec<_>>();
+    let (right_start, right)    = stroke_side(&reversed, half_width, is_closed, options);
+
+    if is_closed {
+        vec![POut::from_points(left_start, left), POut::from_points(right_start, right)]
+    } else {
+        // Join the two sides with caps at either end
+        let mut outline     = left;
+        let left_end        = outline.last().map(|(_, _, end_point)| *end_point).unwrap_or(left_start);
+        let right_end       = right.last().map(|(_, _, end_point)| *end_point).unwrap_or(right_start);
+        let path_start      = curves[0].start_point();
+        let path_end        = curves[curves.len()-1].end_point();
+
+        add_cap(&mut outline, path_end, left_end, right_start, end_direction(&curves[curves.len()-1]).to_unit_vector(), options);
+        outline.extend(right);
+        add_cap(&mut outline, path_start, right_end, left_start, (start_direction(&curves[0]) * -1.0).to_unit_vector(), options);
+
+        vec![POut::from_points(left_start, outline)]
+    }
+}
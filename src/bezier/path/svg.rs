@@ -0,0 +1,330 @@
+use super::path::*;
+use super::super::curve::*;
+use super::super::basis::*;
+use super::super::super::geo::*;
+use super::super::super::line::*;
+use super::super::super::arc::*;
+use super::super::super::consts::*;
+
+use std::fmt;
+use std::fmt::Write;
+use std::error::Error;
+
+///
+/// Generates an SVG path data string (the `d` attribute of a `<path>` element) for a bezier path
+///
+/// The path is written as an `M` command for the start point followed by a `C` command for each curve in the path, with a `Z`
+/// command at the end if the path is closed. Coordinates are written with `precision` decimal places.
+///
+pub fn to_svg_path_string<P: BezierPath>(path: &P, precision: usize) -> String
+where
+    P::Point: Coordinate2D,
+{
+    let mut svg = String::new();
+    let start   = path.start_point();
+
+    write!(&mut svg, "M {:.*} {:.*}", precision, start.x(), precision, start.y()).unwrap();
+
+    for (cp1, cp2, end_point) in path.points() {
+        write!(&mut svg, " C {:.*} {:.*} {:.*} {:.*} {:.*} {:.*}", 
+            precision, cp1.x(), precision, cp1.y(), 
+            precision, cp2.x(), precision, cp2.y(), 
+            precision, end_point.x(), precision, end_point.y()).unwrap();
+    }
+
+    if path.num_segments() > 0 && path.is_closed(SMALL_DISTANCE) {
+        write!(&mut svg, " Z").unwrap();
+    }
+
+    svg
+}
+
+///
+/// Errors that can occur when parsing SVG path data
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SvgParseError {
+    /// A character that isn't part of a number or a supported command was found (the value is the byte offset of the character)
+    UnexpectedCharacter(usize),
+
+    /// Numbers were found before the first command (the value is the byte offset of the number)
+    MissingCommand(usize),
+
+    /// The path data has a drawing command before its first move command (the value is the byte offset of the command)
+    MissingMoveTo(usize),
+
+    /// A command did not have the number of coordinates it needs (the value is the byte offset of the command)
+    MissingCoordinates(usize),
+}
+
+impl fmt::Display for SvgParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SvgParseError::UnexpectedCharacter(pos) => write!(f, "Unexpected character at position {} in the SVG path", pos),
+            SvgParseError::MissingCommand(pos)      => write!(f, "Coordinates without a command at position {} in the SVG path", pos),
+            SvgParseError::MissingMoveTo(pos)       => write!(f, "The SVG path does not start with a move command (at position {})", pos),
+            SvgParseError::MissingCoordinates(pos)  => write!(f, "Not enough coordinates for the command at position {} in the SVG path", pos),
+        }
+    }
+}
+
+impl Error for SvgParseError { }
+
+///
+/// A token in some SVG path data
+///
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum SvgToken {
+    Command(char),
+    Number(f64),
+}
+
+///
+/// Splits SVG path data into commands and numbers, along with the byte offset of each token
+///
+fn tokenize_svg_path(d: &str) -> Result<Vec<(usize, SvgToken)>, SvgParseError> {
+    let bytes       = d.as_bytes();
+    let mut tokens  = vec![];
+    let mut pos     = 0;
+
+    // The number of numbers read since the last arc command (None if the last command wasn't an arc)
+    let mut arc_number: Option<usize> = None;
+
+    while pos < bytes.len() {
+        let chr = bytes[pos];
+
+        match chr {
+            b' ' | b'\t' | b'\r' | b'\n' | b',' => { pos += 1; }
+
+            b'M' | b'm' | b'L' | b'l' | b'H' | b'h' | b'V' | b'v' | b'C' | b'c' | b'S' | b's' | b'Q' | b'q' | b'T' | b't' | b'A' | b'a' | b'Z' | b'z' => {
+                tokens.push((pos, SvgToken::Command(chr as char)));
+                arc_number  = if chr == b'A' || chr == b'a' { Some(0) } else { None };
+                pos         += 1;
+            }
+
+            b'0' | b'1' if matches!(arc_number, Some(num) if num%7 == 3 || num%7 == 4) => {
+                // The flags of an arc are always a single character, so they don't need to be separated from the next number ('0110' is two flags and a number)
+                tokens.push((pos, SvgToken::Number((chr - b'0') as f64)));
+                arc_number  = arc_number.map(|num| num+1);
+                pos         += 1;
+            }
+
+            b'+' | b'-' | b'.' | b'0'..=b'9' if !matches!(arc_number, Some(num) if num%7 == 3 || num%7 == 4) => {
+                // Numbers are a sign, some digits with at most one decimal point, and an optional exponent (so '1.5.5' is two numbers)
+                let start           = pos;
+                let mut seen_point  = false;
+
+                if chr == b'+' || chr == b'-' { pos += 1; }
+                while pos < bytes.len() && (bytes[pos].is_ascii_digit() || (bytes[pos] == b'.' && !seen_point)) {
+                    seen_point = seen_point || bytes[pos] == b'.';
+                    pos += 1;
+                }
+
+                if pos < bytes.len() && (bytes[pos] == b'e' || bytes[pos] == b'E') {
+                    pos += 1;
+                    if pos < bytes.len() && (bytes[pos] == b'+' || bytes[pos] == b'-') { pos += 1; }
+                    while pos < bytes.len() && bytes[pos].is_ascii_digit() { pos += 1; }
+                }
+
+                let number = d[start..pos].parse::<f64>().map_err(|_| SvgParseError::UnexpectedCharacter(start))?;
+                tokens.push((start, SvgToken::Number(number)));
+                arc_number = arc_number.map(|num| num+1);
+            }
+
+            _ => { return Err(SvgParseError::UnexpectedCharacter(pos)); }
+        }
+    }
+
+    Ok(tokens)
+}
+
+///
+/// Parses the path data from an SVG `<path>` element (the `d` attribute) into a set of bezier paths
+///
+/// Every move command starts a new path. The `M`, `L`, `H`, `V`, `C`, `S`, `Q`, `T`, `A` and `Z` commands are supported, in both 
+/// their absolute (upper case) and relative (lower case) forms. Lines are converted to straight bezier curves, quadratic curves are 
+/// converted to cubic curves, and the control points for the smooth curve commands are generated by reflecting the previous 
+/// control point. Arcs are converted using `svg_arc_to_beziers()`: their flags are read as single `0` or `1` characters, so
+/// they don't need to be separated from the numbers that follow them. Paths that are closed with `Z` have a line added back to their start point if they don't end there already. Paths
+/// with no drawing commands are not returned.
+///
+pub fn parse_svg_path<P: BezierPathFactory>(d: &str) -> Result<Vec<P>, SvgParseError>
+where
+    P::Point: Coordinate2D,
+{
+    let tokens          = tokenize_svg_path(d)?;
+    let mut paths       = vec![];
+
+    // The path that's currently being built
+    let mut start_point = None;
+    let mut points      = vec![];
+    let mut current     = P::Point::origin();
+
+    // The control point to reflect for the S and T commands (these are reset by any other command)
+    let mut last_cubic_cp: Option<P::Point>     = None;
+    let mut last_quad_cp: Option<P::Point>      = None;
+
+    let mut token_idx   = 0;
+    while token_idx < tokens.len() {
+        // Read the next command
+        let (command_pos, command) = match tokens[token_idx] {
+            (pos, SvgToken::Command(command))   => (pos, command),
+            (pos, SvgToken::Number(_))          => { return Err(SvgParseError::MissingCommand(pos)); }
+        };
+        token_idx += 1;
+
+        // Each command is followed by a number of coordinates (which may be repeated)
+        let num_coordinates = match command.to_ascii_uppercase() {
+            'Z'             => 0,
+            'H' | 'V'       => 1,
+            'M' | 'L' | 'T' => 2,
+            'S' | 'Q'       => 4,
+            'A'             => 7,
+            _               => 6,
+        };
+
+        let mut command     = command;
+        let mut first_group = true;
+
+        loop {
+            // Read the coordinates for the next repetition of this command
+            let coordinates = tokens[token_idx..].iter()
+                .take(num_coordinates)
+                .map_while(|(_, token)| if let SvgToken::Number(number) = token { Some(*number) } else { None })
+                .collect::<Vec<_>>();
+
+            if num_coordinates > 0 && coordinates.is_empty() && !first_group {
+                // No more repetitions of this command
+                break;
+            }
+
+            if coordinates.len() < num_coordinates {
+                return Err(SvgParseError::MissingCoordinates(command_pos));
+            }
+            token_idx += num_coordinates;
+
+            // Relative coordinates are relative to the current point
+            let command_type    = command.to_ascii_uppercase();
+            let relative        = command.is_ascii_lowercase();
+            let offset          = if relative { current } else { P::Point::origin() };
+            let point_at        = |idx: usize| P::Point::from_components(&[coordinates[idx], coordinates[idx+1]]) + offset;
+
+            // Any command other than M or Z needs a path to add to
+            if start_point.is_none() && command_type != 'M' {
+                return Err(SvgParseError::MissingMoveTo(command_pos));
+            }
+
+            match command_type {
+                'M' => {
+                    // Finish the previous path and start a new one
+                    if let Some(start_point) = start_point {
+                        if !points.is_empty() { paths.push(P::from_points(start_point, points.drain(..))); }
+                    }
+
+                    current         = point_at(0);
+                    start_point     = Some(current);
+                    last_cubic_cp   = None;
+                    last_quad_cp    = None;
+
+                    // Any coordinates after the first pair are treated as line commands
+                    command         = if relative { 'l' } else { 'L' };
+                }
+
+                'Z' => {
+                    // Close the path with a line if it doesn't end at the start point
+                    let start = start_point.unwrap_or(current);
+
+                    if !points.is_empty() {
+                        if !current.is_near_to(&start, SMALL_DISTANCE) {
+                            let line        = line_to_bezier::<_, Curve<_>>(&(current, start));
+                            let (cp1, cp2)  = line.control_points();
+                            points.push((cp1, cp2, start));
+                        }
+
+                        paths.push(P::from_points(start, points.drain(..)));
+                    }
+
+                    // Any commands after this start a new path from the same point 
+                    current         = start;
+                    start_point     = Some(start);
+                    last_cubic_cp   = None;
+                    last_quad_cp    = None;
+                    break;
+                }
+
+                'L' | 'H' | 'V' => {
+                    let end_point = match command_type {
+                        'H' => P::Point::from_components(&[coordinates[0] + if relative { current.x() } else { 0.0 }, current.y()]),
+                        'V' => P::Point::from_components(&[current.x(), coordinates[0] + if relative { current.y() } else { 0.0 }]),
+                        _   => point_at(0),
+                    };
+
+                    let line        = line_to_bezier::<_, Curve<_>>(&(current, end_point));
+                    let (cp1, cp2)  = line.control_points();
+                    points.push((cp1, cp2, end_point));
+
+                    current         = end_point;
+                    last_cubic_cp   = None;
+                    last_quad_cp    = None;
+                }
+
+                'C' | 'S' => {
+                    // The first control point of the 'S' command is the reflection of the previous second control point
+                    let (cp1, cp2, end_point) = if command_type == 'C' {
+                        (point_at(0), point_at(2), point_at(4))
+                    } else {
+                        let cp1 = last_cubic_cp.map(|cp| current*2.0 - cp).unwrap_or(current);
+                        (cp1, point_at(0), point_at(2))
+                    };
+
+                    points.push((cp1, cp2, end_point));
+
+                    current         = end_point;
+                    last_cubic_cp   = Some(cp2);
+                    last_quad_cp    = None;
+                }
+
+                'A' => {
+                    // Elliptical arcs are converted to a series of curves
+                    let end_point   = point_at(5);
+                    let arc         = svg_arc_to_beziers::<Curve<_>>(current, coordinates[0], coordinates[1], coordinates[2], coordinates[3] != 0.0, coordinates[4] != 0.0, end_point);
+
+                    points.extend(arc.into_iter().map(|curve| {
+                        let (cp1, cp2) = curve.control_points();
+                        (cp1, cp2, curve.end_point())
+                    }));
+
+                    current         = end_point;
+                    last_cubic_cp   = None;
+                    last_quad_cp    = None;
+                }
+
+                _ => {
+                    // 'Q' and 'T' generate quadratic curves (the 'T' command reflects the previous control point in the same way as 'S')
+                    let (cp, end_point) = if command_type == 'Q' {
+                        (point_at(0), point_at(2))
+                    } else {
+                        let cp = last_quad_cp.map(|cp| current*2.0 - cp).unwrap_or(current);
+                        (cp, point_at(0))
+                    };
+
+                    let (_, cp1, cp2, _) = elevate_quadratic(current, cp, end_point);
+                    points.push((cp1, cp2, end_point));
+
+                    current         = end_point;
+                    last_cubic_cp   = None;
+                    last_quad_cp    = Some(cp);
+                }
+            }
+
+            first_group = false;
+        }
+    }
+
+    // Finish the last path
+    if let Some(start_point) = start_point {
+        if !points.is_empty() { paths.push(P::from_points(start_point, points)); }
+    }
+
+    Ok(paths)
+}
@@ -0,0 +1,90 @@
+use super::path::*;
+use super::to_curves::*;
+use super::arc_length::*;
+use super::super::curve::*;
+use super::super::length::*;
+use super::super::normal::*;
+use super::super::super::geo::*;
+
+/// Number of samples in the arc length tables used to space the cross-sections of a swept mesh evenly along each curve
+const SWEEP_ARC_LENGTH_SAMPLES: usize = 32;
+
+/// Error bound used when measuring the length of the curves in a path to sweep along
+const SWEEP_LENGTH_MAX_ERROR: f64 = 1e-6;
+
+///
+/// Finds the width at a particular fraction of the way along a sweep by interpolating between the entries in a profile
+///
+fn width_at_fraction(width_profile: &[f64], fraction: f64) -> f64 {
+    match width_profile.len() {
+        0 => 0.0,
+        1 => width_profile[0],
+        _ => {
+            let pos     = fraction.clamp(0.0, 1.0) * ((width_profile.len()-1) as f64);
+            let idx     = (pos.floor() as usize).min(width_profile.len()-2);
+            let ratio   = pos - (idx as f64);
+
+            width_profile[idx] * (1.0-ratio) + width_profile[idx+1] * ratio
+        }
+    }
+}
+
+///
+/// Generates a triangle mesh by sweeping a line across a path, with a width that can vary along its length
+///
+/// The path is sampled at `samples` points spaced evenly by arc length (at least 2 samples are always taken), and a
+/// cross-section is placed at each sample along the normal of the path (see `NormalCurve::frenet_frames()`). `width_profile`
+/// is a list of widths that are evenly spaced along the path from its start to its end, and the width of each cross-section is
+/// interpolated from these (a single entry produces a constant width). This produces a ribbon that can be rendered directly
+/// on a GPU, for things like roads or variable-width strokes.
+///
+/// The result is a list of vertices and a list of triangles that index into the vertex list. Each cross-section adds two
+/// vertices, on the left and right of the path, and each pair of neighbouring cross-sections is joined by two triangles.
+///
+pub fn sweep_profile_along_path<P: BezierPath>(path: &P, width_profile: &[f64], samples: usize) -> (Vec<P::Point>, Vec<[u32; 3]>)
+where
+    P::Point: Coordinate+Coordinate2D+Normalize,
+{
+    let curves      = path_to_curves::<_, Curve<_>>(path).collect::<Vec<_>>();
+    if curves.is_empty() {
+        return (vec![], vec![]);
+    }
+
+    let tables      = curves.iter().map(|curve| ArcLengthTable::new(curve, SWEEP_ARC_LENGTH_SAMPLES)).collect::<Vec<_>>();
+    let positions   = anchor_arc_positions(path, SWEEP_LENGTH_MAX_ERROR);
+    let total       = positions[positions.len()-1];
+    let samples     = samples.max(2);
+
+    // Place a cross-section at each sample point
+    let mut vertices: Vec<P::Point> = Vec::with_capacity(samples*2);
+    let mut last_normal             = P::Point::origin();
+
+    for sample_idx in 0..samples {
+        let fraction    = (sample_idx as f64) / ((samples-1) as f64);
+        let distance    = fraction * total;
+
+        // Find the curve containing this sample (skipping any curves with no length)
+        let curve_idx   = (0..curves.len()).rev().find(|idx| positions[*idx] <= distance && positions[*idx] < positions[*idx+1]).unwrap_or(0);
+        let t           = tables[curve_idx].t_for_distance(distance - positions[curve_idx]);
+
+        // Reuse the last normal if the curve has no tangent here
+        let point       = curves[curve_idx].point_at_pos(t);
+        let (_, normal) = curves[curve_idx].frenet_frame_at(t);
+        let normal      = if normal.magnitude() > 0.0 { normal } else { last_normal };
+        let half_width  = width_at_fraction(width_profile, fraction) / 2.0;
+
+        vertices.push(point + normal * half_width);
+        vertices.push(point - normal * half_width);
+        last_normal = normal;
+    }
+
+    // Join each pair of cross-sections with two triangles
+    let triangles   = (0..(samples-1) as u32)
+        .flat_map(|idx| {
+            let (left, right, next_left, next_right) = (idx*2, idx*2+1, idx*2+2, idx*2+3);
+            vec![[left, right, next_left], [right, next_right, next_left]]
+        })
+        .collect();
+
+    (vertices, triangles)
+}
@@ -0,0 +1,122 @@
+use super::path::*;
+use super::to_curves::*;
+use super::super::curve::*;
+use super::super::normal::*;
+use super::super::super::geo::*;
+
+/// Number of points on each curve of the first path that are checked against the second path when searching for tangent points
+const TANGENCY_SAMPLES: usize = 64;
+
+/// Number of iterations used to refine the position of the closest point
+const REFINE_ITERATIONS: usize = 40;
+
+/// Maximum value of the cross product of the unit tangents for two curves to be considered to be heading in the same direction
+const TANGENT_TOLERANCE: f64 = 0.01;
+
+///
+/// Finds the minimum of a function between two t values using a golden section search
+///
+fn golden_section_minimum<DistanceFn: Fn(f64) -> f64>(min_t: f64, max_t: f64, distance: DistanceFn) -> f64 {
+    let inv_phi         = (5.0f64.sqrt() - 1.0) / 2.0;
+    let (mut a, mut b)  = (min_t, max_t);
+
+    for _ in 0..REFINE_ITERATIONS {
+        let c = b - (b-a)*inv_phi;
+        let d = a + (b-a)*inv_phi;
+
+        if distance(c) < distance(d) {
+            b = d;
+        } else {
+            a = c;
+        }
+    }
+
+    (a+b) / 2.0
+}
+
+///
+/// Finds the points where two paths touch each other without crossing
+///
+/// These are the points where the paths are within `epsilon` of each other and are heading in the same (or opposite) directions,
+/// but where the first path stays on the same side of the second path on either side of the point. Normal intersections, where 
+/// the paths cross at an angle, are not returned (see `path_intersects_path()` for these).
+///
+/// Results are returned in the same format as `path_intersects_path()`: pairs of (segment index, t-value) for the first and
+/// second path.
+///
+pub fn path_tangencies<P: BezierPath>(path1: &P, path2: &P, epsilon: f64) -> Vec<((usize, f64), (usize, f64))>
+where
+    P::Point: Coordinate2D,
+{
+    let epsilon_point   = P::Point::from_components(&[epsilon, epsilon]);
+    let expand_bounds   = |bounds: Bounds<P::Point>| Bounds::from_min_max(bounds.min() - epsilon_point, bounds.max() + epsilon_point);
+
+    let path1_sections  = path_to_curves::<_, Curve<_>>(path1)
+        .map(|curve| (curve, expand_bounds(curve.bounding_box())))
+        .collect::<Vec<_>>();
+    let path2_sections  = path_to_curves::<_, Curve<_>>(path2)
+        .map(|curve| (curve, expand_bounds(curve.bounding_box())))
+        .collect::<Vec<_>>();
+
+    let mut result: Vec<((usize, f64), (usize, f64))>   = vec![];
+    let mut found_points: Vec<P::Point>                 = vec![];
+
+    for (p1_section_id, (p1_curve, p1_bounds)) in path1_sections.iter().enumerate() {
+        for (p2_section_id, (p2_curve, p2_bounds)) in path2_sections.iter().enumerate() {
+            if !p1_bounds.overlaps(p2_bounds) {
+                continue;
+            }
+
+            // Sample the distance from the first curve to the second curve
+            let distance    = |t: f64| p2_curve.distance_to(&p1_curve.point_at_pos(t));
+            let samples     = (0..=TANGENCY_SAMPLES)
+                .map(|idx| (idx as f64) / (TANGENCY_SAMPLES as f64))
+                .map(|t| (t, distance(t)))
+                .collect::<Vec<_>>();
+
+            for idx in 0..samples.len() {
+                // Look for local minima in the distance
+                let is_minimum = (idx == 0 || samples[idx].1 <= samples[idx-1].1) && (idx == samples.len()-1 || samples[idx].1 < samples[idx+1].1);
+                if !is_minimum { continue; }
+
+                // Refine the position of the minimum
+                let min_t       = if idx > 0 { samples[idx-1].0 } else { samples[idx].0 };
+                let max_t       = if idx < samples.len()-1 { samples[idx+1].0 } else { samples[idx].0 };
+                let t1          = golden_section_minimum(min_t, max_t, distance);
+                let pos         = p1_curve.point_at_pos(t1);
+
+                if distance(t1) > epsilon { continue; }
+
+                // Ignore points we've already found (a tangent point at the join between two curves will be found twice)
+                if found_points.iter().any(|point| point.is_near_to(&pos, epsilon)) { continue; }
+
+                // Curves must be heading in the same direction at the point where they touch
+                let t2          = p2_curve.nearest_t(&pos);
+                let tangent1    = p1_curve.tangent_at_pos(t1).to_unit_vector();
+                let tangent2    = p2_curve.tangent_at_pos(t2).to_unit_vector();
+                let cross       = tangent1.x()*tangent2.y() - tangent1.y()*tangent2.x();
+
+                if cross.abs() > TANGENT_TOLERANCE { continue; }
+
+                // The first curve must stay on the same side of the second curve either side of the point
+                let side        = |t: f64| {
+                    let p1_pos  = p1_curve.point_at_pos(t);
+                    let t2      = p2_curve.nearest_t(&p1_pos);
+
+                    (p1_pos - p2_curve.point_at_pos(t2)).dot(&p2_curve.normal_at_pos(t2))
+                };
+                let before      = if idx > 0 { Some(side(samples[idx-1].0)) } else { None };
+                let after       = if idx < samples.len()-1 { Some(side(samples[idx+1].0)) } else { None };
+
+                if let (Some(before), Some(after)) = (before, after) {
+                    if before.signum() != after.signum() { continue; }
+                }
+
+                found_points.push(pos);
+                result.push(((p1_section_id, t1), (p2_section_id, t2)));
+            }
+        }
+    }
+
+    result
+}
@@ -1,7 +1,10 @@
 use super::path::*;
 use super::super::curve::*;
+use super::super::super::geo::*;
+use super::super::super::consts::*;
 
 use itertools::*;
+use std::iter;
 
 ///
 /// Converts a path to a series of bezier curves
@@ -16,3 +19,28 @@ pub fn path_to_curves<Path: BezierPath, Curve: BezierCurveFactory<Point=Path::Po
             Curve::from_points(start_point, (cp1, cp2), end_point)
         })
 }
+
+///
+/// Returns each pair of adjacent curves in a path
+///
+/// Each item is a curve along with the curve that follows it. If the path is closed (its final point is at the same position as
+/// its start point), the last item will be the final curve followed by the first curve, so every join in the path is returned,
+/// including the one where the path wraps around.
+///
+/// This is useful for algorithms that operate on the joins between curves, such as working out the angle of a corner.
+///
+pub fn segment_windows<Path: BezierPath, Curve: BezierCurveFactory<Point=Path::Point>>(path: &Path) -> impl Iterator<Item=(Curve, Curve)> {
+    let curves      = path_to_curves::<_, Curve>(path).collect::<Vec<_>>();
+
+    // The path wraps around if the last point is at the start point
+    let is_closed   = !curves.is_empty() && curves[curves.len()-1].end_point().is_near_to(&path.start_point(), SMALL_DISTANCE);
+    let wrap_around = if is_closed {
+        Some((curves[curves.len()-1].clone(), curves[0].clone()))
+    } else {
+        None
+    };
+
+    curves.into_iter()
+        .tuple_windows()
+        .chain(iter::once(wrap_around).flatten())
+}
@@ -0,0 +1,91 @@
+use super::curve::*;
+use super::basis::*;
+use crate::geo::*;
+
+///
+/// Trait implemented by quadratic bezier curves that can be created from a set of points
+///
+pub trait QuadraticBezierCurveFactory: QuadraticBezierCurve {
+    ///
+    /// Creates a new quadratic bezier curve of the same type from its start, control and end points
+    ///
+    fn from_points(start: Self::Point, control_point: Self::Point, end: Self::Point) -> Self;
+}
+
+///
+/// Trait implemented by quadratic bezier curves (curves with a single control point, as used by TrueType fonts)
+///
+pub trait QuadraticBezierCurve: Geo+Clone+Sized {
+    ///
+    /// The start point of this curve
+    ///
+    fn start_point(&self) -> Self::Point;
+
+    ///
+    /// The end point of this curve
+    ///
+    fn end_point(&self) -> Self::Point;
+
+    ///
+    /// The control point for this curve
+    ///
+    fn control_point(&self) -> Self::Point;
+
+    ///
+    /// Given a value t from 0 to 1, returns a point on this curve
+    ///
+    #[inline]
+    fn point_at_pos(&self, t: f64) -> Self::Point {
+        de_casteljau3(t, self.start_point(), self.control_point(), self.end_point())
+    }
+
+    ///
+    /// Converts this curve to a cubic bezier curve that describes exactly the same curve
+    ///
+    fn to_cubic<Curve: BezierCurveFactory<Point=Self::Point>>(&self) -> Curve {
+        let (start, cp1, cp2, end) = elevate_quadratic(self.start_point(), self.control_point(), self.end_point());
+
+        Curve::from_points(start, (cp1, cp2), end)
+    }
+}
+
+///
+/// Represents a quadratic Bezier curve
+///
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct QuadCurve<Coord: Coordinate> {
+    pub start_point:    Coord,
+    pub end_point:      Coord,
+    pub control_point:  Coord
+}
+
+impl<Coord: Coordinate> Geo for QuadCurve<Coord> {
+    type Point = Coord;
+}
+
+impl<Coord: Coordinate> QuadraticBezierCurveFactory for QuadCurve<Coord> {
+    fn from_points(start: Coord, control_point: Coord, end: Coord) -> Self {
+        QuadCurve {
+            start_point:    start,
+            control_point:  control_point,
+            end_point:      end
+        }
+    }
+}
+
+impl<Coord: Coordinate> QuadraticBezierCurve for QuadCurve<Coord> {
+    #[inline]
+    fn start_point(&self) -> Coord {
+        self.start_point
+    }
+
+    #[inline]
+    fn end_point(&self) -> Coord {
+        self.end_point
+    }
+
+    #[inline]
+    fn control_point(&self) -> Coord {
+        self.control_point
+    }
+}
@@ -0,0 +1,56 @@
+use super::path::*;
+use super::curve::*;
+use crate::geo::*;
+use crate::line::*;
+
+///
+/// Reflects a single point across a line
+///
+#[inline]
+fn reflect_point<Point: Coordinate>(point: Point, origin: Point, unit_direction: Point) -> Point {
+    // Project the point onto the line, then move the same distance again to find the reflection
+    let offset      = point - origin;
+    let projected   = origin + unit_direction * offset.dot(&unit_direction);
+
+    projected * 2.0 - point
+}
+
+///
+/// Mirrors a curve across a line
+///
+/// The line is infinite, passing through both of its points. The line must have two different points, or the result will
+/// be made up of NaN values.
+///
+pub fn reflect_curve<C, L>(curve: &C, line: &L) -> C
+where
+    C: BezierCurveFactory,
+    L: Line<Point=C::Point>,
+{
+    let (origin, direction_point)   = line.points();
+    let unit_direction              = (direction_point - origin).to_unit_vector();
+    let (cp1, cp2)                  = curve.control_points();
+
+    C::from_points(reflect_point(curve.start_point(), origin, unit_direction), 
+        (reflect_point(cp1, origin, unit_direction), reflect_point(cp2, origin, unit_direction)), 
+        reflect_point(curve.end_point(), origin, unit_direction))
+}
+
+///
+/// Mirrors a path across a line
+///
+/// Reflecting a path reverses its direction, so a clockwise path will become an anticlockwise one and vice-versa.
+///
+pub fn reflect_path<P, L>(path: &P, line: &L) -> P
+where
+    P: BezierPathFactory,
+    L: Line<Point=P::Point>,
+{
+    let (origin, direction_point)   = line.points();
+    let unit_direction              = (direction_point - origin).to_unit_vector();
+
+    let start_point                 = reflect_point(path.start_point(), origin, unit_direction);
+    let points                      = path.points()
+        .map(|(cp1, cp2, end_point)| (reflect_point(cp1, origin, unit_direction), reflect_point(cp2, origin, unit_direction), reflect_point(end_point, origin, unit_direction)));
+
+    P::from_points(start_point, points)
+}
@@ -1,8 +1,25 @@
 use crate::geo::*;
 use crate::bezier::*;
 use crate::line::*;
+use super::polynomial_to_bezier::*;
 
 use smallvec::*;
+use std::convert::{TryInto};
+
+/// The default maximum distance from the control points to the baseline for a section of curve to be considered flat by the root finder
+const FLAT_ENOUGH: f64 = 0.1;
+
+///
+/// The direction that a bezier curve crosses the x-axis at a root
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CrossingDirection {
+    /// The y coordinate goes from negative to positive as t increases
+    NegativeToPositive,
+
+    /// The y coordinate goes from positive to negative as t increases
+    PositiveToNegative,
+}
 
 ///
 /// Counts the number of times a bezier curve polygon crosses the x-axis (excluding the closing line of the polygon)
@@ -29,12 +46,10 @@ where
 /// Returns true if the control polygon is flat enough to try to find a root for it
 ///
 #[inline]
-fn flat_enough<TPoint, const N: usize>(points: &[TPoint; N]) -> bool 
+fn flat_enough<TPoint, const N: usize>(points: &[TPoint; N], tolerance: f64) -> bool 
 where
     TPoint: Coordinate + Coordinate2D,
 {
-    const FLAT_ENOUGH: f64 = 0.1;
-
     // x coordinates increase monotonically so we just check that the y-components are all in the same direction
     let y_direction = (points[1].y() - points[0].y()).signum();
     for idx in 1..(N-1) {
@@ -55,7 +70,7 @@ where
     }
 
     // The graphics gems code goes on to compute a bounding box to get a precise estimate of the maximum error, here we just use the furthest away control point as a measure of flatness
-    return max_distance <= FLAT_ENOUGH;
+    max_distance <= tolerance
 }
 
 ///
@@ -124,18 +139,108 @@ pub fn find_x_intercept_newton_raphson<const N: usize>(points: SmallVec<[f64; N]
 /// start to limit the effectiveness of this function.
 ///
 pub fn find_bezier_roots<TPoint, const N: usize>(points: [TPoint; N]) -> SmallVec<[f64; 4]>
+where
+    TPoint: Coordinate + Coordinate2D,
+{
+    find_bezier_roots_with_tolerance(points, FLAT_ENOUGH)
+}
+
+///
+/// Finds the points (as t-values) where a bezier curve's y coordinate is 0, with a particular tolerance for the subdivision
+///
+/// The curve is subdivided until each section containing a root is within `tolerance` of a straight line, at which point
+/// the root is found using Newton-Raphson. Larger tolerances need fewer subdivisions, but are more likely to start the
+/// Newton-Raphson search far from the root, or to miss roots that are close together.
+///
+pub fn find_bezier_roots_with_tolerance<TPoint, const N: usize>(points: [TPoint; N], tolerance: f64) -> SmallVec<[f64; 4]>
+where
+    TPoint: Coordinate + Coordinate2D,
+{
+    find_bezier_roots_counting_subdivisions(points, tolerance).0
+}
+
+///
+/// Finds the points (as t-values) where a bezier curve's y coordinate is 0, along with the direction the curve crosses the x-axis
+///
+/// This is the same as `find_bezier_roots_with_tolerance()`, except the direction is returned alongside each root. This is
+/// needed when pairing up intercepts or accumulating a winding number, where a curve going up through a point cancels out 
+/// one going down through it.
+///
+pub fn find_bezier_roots_with_sign<TPoint, const N: usize>(points: [TPoint; N], tolerance: f64) -> SmallVec<[(f64, CrossingDirection); 4]>
+where
+    TPoint: Coordinate + Coordinate2D,
+{
+    find_signed_bezier_roots_counting_subdivisions(points, tolerance).0
+}
+
+///
+/// Finds the roots of a polynomial of degree `N-1` using the bezier root finder
+///
+#[inline]
+fn find_roots_poly_n<const N: usize>(coefficients: &[f64]) -> SmallVec<[f64; 8]> {
+    let coefficients: [f64; N] = coefficients.try_into().unwrap();
+
+    find_bezier_roots(polynomial_to_bezier::<Coord2, N>(coefficients)).into_iter().collect()
+}
+
+///
+/// Finds the real roots in the range `0.0..=1.0` of a polynomial of up to degree 8
+///
+/// The polynomial has the form `c[0] + c[1]*x + c[2]*x^2 ...` where `c` is the list of coefficients. The polynomial is
+/// converted to a bezier curve using `polynomial_to_bezier()` and solved with `find_bezier_roots()`, so roots outside of
+/// the range `0.0..=1.0` are not returned. Zero coefficients for the highest powers are ignored.
+///
+/// Polynomials of a higher degree than 8 (more than 9 non-zero coefficients) are not supported, and produce an empty list
+/// of roots.
+///
+pub fn find_roots_poly(coefficients: &[f64]) -> SmallVec<[f64; 8]> {
+    // Ignore any high-order coefficients that are 0
+    let len             = coefficients.iter().rposition(|coefficient| *coefficient != 0.0).map(|idx| idx+1).unwrap_or(0);
+    let coefficients    = &coefficients[0..len];
+
+    match coefficients.len() {
+        0 | 1   => smallvec![],
+        2       => find_roots_poly_n::<2>(coefficients),
+        3       => find_roots_poly_n::<3>(coefficients),
+        4       => find_roots_poly_n::<4>(coefficients),
+        5       => find_roots_poly_n::<5>(coefficients),
+        6       => find_roots_poly_n::<6>(coefficients),
+        7       => find_roots_poly_n::<7>(coefficients),
+        8       => find_roots_poly_n::<8>(coefficients),
+        9       => find_roots_poly_n::<9>(coefficients),
+        _       => smallvec![]
+    }
+}
+
+///
+/// Performs the root-finding algorithm, returning the roots and the number of times the curve was subdivided
+///
+fn find_bezier_roots_counting_subdivisions<TPoint, const N: usize>(points: [TPoint; N], tolerance: f64) -> (SmallVec<[f64; 4]>, usize)
+where
+    TPoint: Coordinate + Coordinate2D,
+{
+    let (roots, num_subdivisions) = find_signed_bezier_roots_counting_subdivisions(points, tolerance);
+
+    (roots.into_iter().map(|(root, _direction)| root).collect(), num_subdivisions)
+}
+
+///
+/// Performs the root-finding algorithm, returning the roots with their crossing directions and the number of times the curve was subdivided
+///
+fn find_signed_bezier_roots_counting_subdivisions<TPoint, const N: usize>(points: [TPoint; N], tolerance: f64) -> (SmallVec<[(f64, CrossingDirection); 4]>, usize)
 where
     TPoint: Coordinate + Coordinate2D,
 {
     // See "A bezier curve-based root-finder", Philip J Schneider, Graphics Gems
 
     // List of sections waiting to be processed
-    let mut sections    = vec![points];
-    let mut roots       = smallvec![];
+    let mut sections            = vec![points];
+    let mut roots               = smallvec![];
+    let mut num_subdivisions    = 0;
 
     loop {
         // Get the next section to process
-        let section = if let Some(section) = sections.pop() { section } else { return roots; };
+        let section = if let Some(section) = sections.pop() { section } else { return (roots, num_subdivisions); };
 
         // Find out how many times the polygon crosses the x
         let num_crossings = count_x_axis_crossings(&section);
@@ -145,10 +250,13 @@ where
             continue;
         }
 
-        if num_crossings == 1 && flat_enough(&section) {
+        if num_crossings == 1 && flat_enough(&section, tolerance) {
+            // Sections that are flat enough are also monotonic, so the direction of the crossing is the direction from the start to the end
+            let direction = if section[N-1].y() > section[0].y() { CrossingDirection::NegativeToPositive } else { CrossingDirection::PositiveToNegative };
+
             // Find an x-intercept for this section
             let intercept = find_x_intercept(&section);
-            roots.push(de_casteljau_n(intercept, section.into()).x());
+            roots.push((de_casteljau_n(intercept, section.into()).x(), direction));
             continue;
         }
 
@@ -156,13 +264,13 @@ where
         let (left, right) = subdivide_n(0.5, section);
         sections.push(right);
         sections.push(left);
+        num_subdivisions += 1;
     }
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
-    use super::super::polynomial_to_bezier::*;
 
     #[test]
     fn find_roots_simple_polynomial() {
@@ -178,4 +286,143 @@ mod test {
         debug_assert!((roots[3]-0.4).abs() < 0.001, "{:?}", roots);
         debug_assert!((roots[4]-0.5).abs() < 0.001, "{:?}", roots);
     }
+
+    #[test]
+    fn looser_tolerance_subdivides_less() {
+        // (x-0.5)(x-0.4)(x-0.3)(x-0.2)(x-0.1)
+        let bezier                      = polynomial_to_bezier::<Coord2, 6>([-0.0012, 0.0274, -0.225, 0.85, -1.5, 1.0]);
+        let (loose_roots, loose_count)  = find_bezier_roots_counting_subdivisions(bezier, 0.5);
+        let (tight_roots, tight_count)  = find_bezier_roots_counting_subdivisions(bezier, 0.0001);
+
+        assert!(loose_count < tight_count, "{} {}", loose_count, tight_count);
+
+        // The tight tolerance should find all the roots accurately
+        assert!(tight_roots.len() == 5, "{:?}", tight_roots);
+        for (root, expected) in tight_roots.iter().zip([0.1, 0.2, 0.3, 0.4, 0.5].iter()) {
+            assert!((root-expected).abs() < 1e-6, "{:?}", tight_roots);
+        }
+
+        // The loose tolerance should still find some roots
+        for root in loose_roots.iter() {
+            assert!([0.1, 0.2, 0.3, 0.4, 0.5].iter().any(|expected| (root-expected).abs() < 0.01), "{:?}", loose_roots);
+        }
+    }
+
+    #[test]
+    fn find_roots_with_opposite_crossings() {
+        // -(x-0.25)(x-0.75) == -0.1875 + x - x^2 rises through 0.25 and falls through 0.75
+        let bezier  = polynomial_to_bezier::<Coord2, 3>([-0.1875, 1.0, -1.0]);
+        let mut roots = find_bezier_roots_with_sign(bezier, 0.0001);
+        roots.sort_by(|(t1, _), (t2, _)| t1.partial_cmp(t2).unwrap());
+
+        assert!(roots.len() == 2, "{:?}", roots);
+        assert!((roots[0].0-0.25).abs() < 1e-6, "{:?}", roots);
+        assert!((roots[1].0-0.75).abs() < 1e-6, "{:?}", roots);
+        assert!(roots[0].1 == CrossingDirection::NegativeToPositive, "{:?}", roots);
+        assert!(roots[1].1 == CrossingDirection::PositiveToNegative, "{:?}", roots);
+    }
+
+    #[test]
+    fn signed_roots_match_unsigned_roots() {
+        // (x-0.5)(x-0.4)(x-0.3)(x-0.2)(x-0.1)
+        let bezier          = polynomial_to_bezier::<Coord2, 6>([-0.0012, 0.0274, -0.225, 0.85, -1.5, 1.0]);
+        let roots           = find_bezier_roots_with_tolerance(bezier, FLAT_ENOUGH);
+        let signed_roots    = find_bezier_roots_with_sign(bezier, FLAT_ENOUGH);
+
+        assert!(roots.len() == signed_roots.len());
+        assert!(roots.iter().zip(signed_roots.iter()).all(|(root, (signed_root, _))| root == signed_root));
+
+        // Odd-degree polynomial with a positive leading coefficient alternates direction, rising through the first root
+        let mut signed_roots = signed_roots.into_iter().collect::<Vec<_>>();
+        signed_roots.sort_by(|(t1, _), (t2, _)| t1.partial_cmp(t2).unwrap());
+        let directions = signed_roots.iter().map(|(_, direction)| *direction).collect::<Vec<_>>();
+
+        assert!(directions == vec![CrossingDirection::NegativeToPositive, CrossingDirection::PositiveToNegative, CrossingDirection::NegativeToPositive, CrossingDirection::PositiveToNegative, CrossingDirection::NegativeToPositive], "{:?}", directions);
+    }
+
+    #[test]
+    fn default_tolerance_matches_find_bezier_roots() {
+        let bezier  = polynomial_to_bezier::<Coord2, 4>([-0.056, 0.62, -1.7, 1.0]);
+
+        assert!(find_bezier_roots(bezier) == find_bezier_roots_with_tolerance(bezier, FLAT_ENOUGH));
+    }
+
+    #[test]
+    fn find_roots_degree_5_polynomial() {
+        // (x-0.15)(x-0.35)(x-0.5)(x-0.7)(x-0.9)
+        let mut coefficients = vec![1.0];
+        for root in [0.15, 0.35, 0.5, 0.7, 0.9].iter() {
+            // Multiply by (x - root)
+            let mut next = vec![0.0; coefficients.len()+1];
+            for (power, coefficient) in coefficients.iter().enumerate() {
+                next[power]     -= coefficient * root;
+                next[power+1]   += coefficient;
+            }
+            coefficients = next;
+        }
+
+        let roots = find_roots_poly(&coefficients);
+
+        assert!(roots.len() == 5, "{:?}", roots);
+        for (root, expected) in roots.iter().zip([0.15, 0.35, 0.5, 0.7, 0.9].iter()) {
+            assert!((root-expected).abs() < 0.001, "{:?}", roots);
+        }
+    }
+
+    #[test]
+    fn find_roots_poly_ignores_zero_high_order_coefficients() {
+        // 2x - 1, with some extra 0 coefficients
+        let roots = find_roots_poly(&[-1.0, 2.0, 0.0, 0.0]);
+
+        assert!(roots.len() == 1, "{:?}", roots);
+        assert!((roots[0]-0.5).abs() < 0.001, "{:?}", roots);
+    }
+
+    #[test]
+    fn find_roots_poly_constant() {
+        assert!(find_roots_poly(&[]).is_empty());
+        assert!(find_roots_poly(&[1.0]).is_empty());
+        assert!(find_roots_poly(&[0.0, 0.0]).is_empty());
+    }
+
+    #[test]
+    fn find_roots_poly_outside_range() {
+        // (x-2)(x+1) has no roots in the range 0..1
+        assert!(find_roots_poly(&[-2.0, -1.0, 1.0]).is_empty());
+    }
+
+    #[test]
+    fn flat_line_is_flat_enough() {
+        let line    = polynomial_to_bezier::<Coord2, 4>([-0.5, 1.0, 0.0, 0.0]);
+
+        assert!(flat_enough(&line, FLAT_ENOUGH));
+        assert!(flat_enough(&line, 0.0000001));
+    }
+
+    #[test]
+    fn curved_polygon_is_not_flat_enough() {
+        // (x-0.25)(x-0.75)(x-1.5) changes direction in the range 0..1
+        let curve   = polynomial_to_bezier::<Coord2, 4>([-0.28125, 1.6875, -2.5, 1.0]);
+
+        assert!(!flat_enough(&curve, FLAT_ENOUGH));
+    }
+
+    #[test]
+    fn x_intercept_of_line() {
+        let line    = polynomial_to_bezier::<Coord2, 4>([-0.25, 1.0, 0.0, 0.0]);
+
+        assert!((find_x_intercept(&line) - 0.25).abs() < 1e-6);
+    }
+
+    #[test]
+    fn find_roots_cubic_at_quarters() {
+        // (x-0.25)(x-0.75)(x-1.5) == -0.28125 + 1.6875x - 2.5x^2 + x^3, which crosses 0 at t=0.25 and t=0.75 in the range 0..1
+        let bezier  = polynomial_to_bezier::<Coord2, 4>([-0.28125, 1.6875, -2.5, 1.0]);
+        let mut roots = find_bezier_roots(bezier);
+        roots.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+        assert!(roots.len() == 2, "{:?}", roots);
+        assert!((roots[0]-0.25).abs() < 1e-6, "{:?}", roots);
+        assert!((roots[1]-0.75).abs() < 1e-6, "{:?}", roots);
+    }
 }
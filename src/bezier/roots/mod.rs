@@ -5,3 +5,4 @@ mod find_roots;
 mod nearest_point_bezier_root_finder;
 
 pub use nearest_point_bezier_root_finder::*;
+pub use find_roots::*;
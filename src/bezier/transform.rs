@@ -0,0 +1,30 @@
+use super::curve::*;
+use super::path::*;
+use crate::geo::*;
+
+impl Transform2D {
+    ///
+    /// Applies this transform to a bezier curve
+    ///
+    pub fn transform_curve<C: BezierCurveFactory>(&self, curve: &C) -> C
+    where
+        C::Point: Coordinate+Coordinate2D,
+    {
+        let (cp1, cp2) = curve.control_points();
+
+        C::from_points(self.transform_point(&curve.start_point()), (self.transform_point(&cp1), self.transform_point(&cp2)), self.transform_point(&curve.end_point()))
+    }
+
+    ///
+    /// Applies this transform to a bezier path
+    ///
+    pub fn transform_path<P: BezierPathFactory>(&self, path: &P) -> P
+    where
+        P::Point: Coordinate+Coordinate2D,
+    {
+        let points = path.points()
+            .map(|(cp1, cp2, end_point)| (self.transform_point(&cp1), self.transform_point(&cp2), self.transform_point(&end_point)));
+
+        P::from_points(self.transform_point(&path.start_point()), points)
+    }
+}
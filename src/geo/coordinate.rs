@@ -48,6 +48,24 @@ pub trait Coordinate : Sized+Copy+Add<Self, Output=Self>+Mul<f64, Output=Self>+S
     /// 
     fn from_smallest_components(p1: Self, p2: Self) -> Self;
 
+    ///
+    /// Returns the components of this coordinate as an array, in the same order as they are returned by `get()`
+    ///
+    #[inline]
+    fn to_f64_array(&self) -> SmallVec<[f64; 4]> {
+        (0..Self::len()).map(|component| self.get(component)).collect()
+    }
+
+    ///
+    /// Creates a coordinate from an array of components generated by `to_f64_array()`
+    ///
+    /// The array must contain at least `len()` components.
+    ///
+    #[inline]
+    fn from_f64_array(components: &[f64]) -> Self {
+        Self::from_components(components)
+    }
+
     ///
     /// Computes the distance between this coordinate and another of the same type
     /// 
@@ -235,8 +253,38 @@ impl Coordinate for f64 {
 
 /// Represents a 2D point
 #[derive(Copy, Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Coord2(pub f64, pub f64);
 
+impl Coord2 {
+    /// The point at (0, 0)
+    pub const ORIGIN: Coord2 = Coord2(0.0, 0.0);
+
+    ///
+    /// A unit vector along the x axis
+    ///
+    #[inline]
+    pub fn unit_x() -> Coord2 {
+        Coord2(1.0, 0.0)
+    }
+
+    ///
+    /// A unit vector along the y axis
+    ///
+    #[inline]
+    pub fn unit_y() -> Coord2 {
+        Coord2(0.0, 1.0)
+    }
+
+    ///
+    /// Creates a vector of a particular length at an angle in radians measured anticlockwise from the x axis
+    ///
+    #[inline]
+    pub fn from_angle(radians: f64, length: f64) -> Coord2 {
+        Coord2(radians.cos() * length, radians.sin() * length)
+    }
+}
+
 impl Coordinate2D for Coord2 {
     ///
     /// X component of this coordinate
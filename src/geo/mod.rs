@@ -9,6 +9,8 @@
 //! possible to request bounding boxes in types other than the default `Bounds` type supplied by the
 //! library.
 //!
+//! `Transform2D` describes affine transformations, which can be applied to points, curves and paths.
+//!
 
 mod geo;
 mod sweep;
@@ -16,6 +18,7 @@ mod has_bounds;
 mod coordinate;
 mod coordinate_ext;
 mod bounding_box;
+mod transform;
 
 pub use self::geo::*;
 pub use self::sweep::*;
@@ -23,4 +26,5 @@ pub use self::has_bounds::*;
 pub use self::coordinate::*;
 pub use self::bounding_box::*;
 pub use self::coordinate_ext::*;
+pub use self::transform::*;
 
@@ -0,0 +1,66 @@
+use super::coordinate::*;
+
+///
+/// A 2D affine transform, stored as the top two rows of a 3x3 matrix
+///
+/// A point `(x, y)` is transformed to `(m[0][0]*x + m[0][1]*y + m[0][2], m[1][0]*x + m[1][1]*y + m[1][2])`. Bezier curves keep
+/// their shape under affine transforms, so transforming the control points of a curve produces exactly the transformed curve
+/// (see `transform_curve()` and `transform_path()`).
+///
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Transform2D(pub [[f64; 3]; 2]);
+
+impl Transform2D {
+    ///
+    /// The transform that leaves every point where it is
+    ///
+    pub fn identity() -> Transform2D {
+        Transform2D([[1.0, 0.0, 0.0], [0.0, 1.0, 0.0]])
+    }
+
+    ///
+    /// A transform that moves points by an offset
+    ///
+    pub fn translate(dx: f64, dy: f64) -> Transform2D {
+        Transform2D([[1.0, 0.0, dx], [0.0, 1.0, dy]])
+    }
+
+    ///
+    /// A transform that scales points around the origin
+    ///
+    pub fn scale(scale_x: f64, scale_y: f64) -> Transform2D {
+        Transform2D([[scale_x, 0.0, 0.0], [0.0, scale_y, 0.0]])
+    }
+
+    ///
+    /// A transform that rotates points anticlockwise around the origin by an angle in radians
+    ///
+    pub fn rotate(radians: f64) -> Transform2D {
+        let (sin, cos) = radians.sin_cos();
+
+        Transform2D([[cos, -sin, 0.0], [sin, cos, 0.0]])
+    }
+
+    ///
+    /// Combines this transform with another one, returning a transform that applies `other` first and then this transform
+    ///
+    pub fn multiply(&self, other: &Transform2D) -> Transform2D {
+        let Transform2D(a) = self;
+        let Transform2D(b) = other;
+
+        Transform2D([
+            [a[0][0]*b[0][0] + a[0][1]*b[1][0], a[0][0]*b[0][1] + a[0][1]*b[1][1], a[0][0]*b[0][2] + a[0][1]*b[1][2] + a[0][2]],
+            [a[1][0]*b[0][0] + a[1][1]*b[1][0], a[1][0]*b[0][1] + a[1][1]*b[1][1], a[1][0]*b[0][2] + a[1][1]*b[1][2] + a[1][2]],
+        ])
+    }
+
+    ///
+    /// Applies this transform to a point
+    ///
+    pub fn transform_point<Point: Coordinate+Coordinate2D>(&self, point: &Point) -> Point {
+        let Transform2D(m) = self;
+        let (x, y)          = (point.x(), point.y());
+
+        Point::from_components(&[m[0][0]*x + m[0][1]*y + m[0][2], m[1][0]*x + m[1][1]*y + m[1][2]])
+    }
+}
@@ -0,0 +1,139 @@
+#![allow(clippy::all)]  // Tests are lower priority to fix
+
+extern crate flo_curves;
+
+use flo_curves::*;
+use flo_curves::arc::*;
+use flo_curves::bezier::*;
+
+use std::f64;
+
+///
+/// Returns how far a point is from being on an ellipse (0 for points on the ellipse, as a fraction of the radius)
+///
+fn ellipse_error(point: Coord2, center: Coord2, rx: f64, ry: f64, rotation_degrees: f64) -> f64 {
+    let (sin, cos)  = rotation_degrees.to_radians().sin_cos();
+    let offset      = point - center;
+    let (x, y)      = (offset.0*cos + offset.1*sin, -offset.0*sin + offset.1*cos);
+
+    ((x*x)/(rx*rx) + (y*y)/(ry*ry)).sqrt() - 1.0
+}
+
+fn point_on_ellipse(angle: f64, center: Coord2, rx: f64, ry: f64, rotation_degrees: f64) -> Coord2 {
+    let (sin, cos)  = rotation_degrees.to_radians().sin_cos();
+    let (x, y)      = (rx*angle.cos(), ry*angle.sin());
+
+    Coord2(center.0 + x*cos - y*sin, center.1 + x*sin + y*cos)
+}
+
+fn assert_on_ellipse(curves: &[Curve<Coord2>], center: Coord2, rx: f64, ry: f64, rotation_degrees: f64) {
+    for curve in curves {
+        for t in 0..=20 {
+            let point = curve.point_at_pos((t as f64)/20.0);
+            let error = ellipse_error(point, center, rx, ry, rotation_degrees);
+
+            assert!(error.abs() < 0.001, "{:?} {}", point, error);
+        }
+    }
+}
+
+#[test]
+fn all_flag_combinations() {
+    let center          = Coord2(5.0, 5.0);
+    let (rx, ry, rot)   = (4.0, 2.0, 30.0);
+    let start           = point_on_ellipse(0.3, center, rx, ry, rot);
+    let end             = point_on_ellipse(2.0, center, rx, ry, rot);
+    let other_center    = start + end - center;
+
+    for (large_arc, sweep) in [(false, false), (false, true), (true, false), (true, true)].iter() {
+        let curves = svg_arc_to_beziers::<Curve<Coord2>>(start, rx, ry, rot, *large_arc, *sweep, end);
+
+        // The arc should start and end at exactly the requested points, and be made up of sections of at most 90 degrees
+        assert!(curves[0].start_point() == start);
+        assert!(curves[curves.len()-1].end_point() == end);
+        assert!(curves.len() <= 4);
+        assert!(curves.iter().zip(curves.iter().skip(1)).all(|(a, b)| a.end_point() == b.start_point()));
+
+        // The arc from 0.3 to 2.0 radians is the small arc in the positive direction around the original center
+        let arc_center = if large_arc != sweep { center } else { other_center };
+        assert_on_ellipse(&curves, arc_center, rx, ry, rot);
+
+        // Check which way around the ellipse the arc goes
+        if !*large_arc && *sweep {
+            assert!(curves.len() == 2);
+            let midpoint = point_on_ellipse(1.15, center, rx, ry, rot);
+            assert!(curves.iter().any(|curve| curve.distance_to(&midpoint) < 0.001));
+        } else if *large_arc && !*sweep {
+            assert!(curves.len() == 3);
+            let midpoint = point_on_ellipse(1.15 + f64::consts::PI, center, rx, ry, rot);
+            assert!(curves.iter().any(|curve| curve.distance_to(&midpoint) < 0.001));
+        }
+    }
+}
+
+#[test]
+fn radius_too_small_is_scaled_up() {
+    // Circle needs to have a radius of 2 to reach between these points, so the result is a semicircle
+    let curves = svg_arc_to_beziers::<Curve<Coord2>>(Coord2(0.0, 0.0), 1.0, 1.0, 0.0, false, true, Coord2(4.0, 0.0));
+
+    assert!(curves.len() == 2);
+    assert_on_ellipse(&curves, Coord2(2.0, 0.0), 2.0, 2.0, 0.0);
+
+    // Increasing angles from the start point at 180 degrees go through 270 degrees
+    assert!(curves[0].end_point().distance_to(&Coord2(2.0, -2.0)) < 1e-9, "{:?}", curves[0].end_point());
+}
+
+#[test]
+fn flat_ellipse_with_zero_radius_is_line() {
+    let curves = svg_arc_to_beziers::<Curve<Coord2>>(Coord2(1.0, 2.0), 0.0, 3.0, 0.0, false, false, Coord2(5.0, 6.0));
+
+    assert!(curves.len() == 1);
+    assert!(curves[0].start_point() == Coord2(1.0, 2.0));
+    assert!(curves[0].end_point() == Coord2(5.0, 6.0));
+    assert!(curves[0].point_at_pos(0.5).distance_to(&Coord2(3.0, 4.0)) < 0.001);
+}
+
+#[test]
+fn arc_to_same_point_is_empty() {
+    assert!(svg_arc_to_beziers::<Curve<Coord2>>(Coord2(1.0, 2.0), 3.0, 3.0, 0.0, true, true, Coord2(1.0, 2.0)).is_empty());
+}
+
+#[test]
+fn fit_circle_to_exact_points() {
+    let points = (0..20).map(|idx| point_on_ellipse((idx as f64) * 0.3, Coord2(3.0, -2.0), 5.0, 5.0, 0.0)).collect::<Vec<_>>();
+    let circle = fit_circle(&points).unwrap();
+
+    assert!(circle.center.distance_to(&Coord2(3.0, -2.0)) < 1e-9, "{:?}", circle.center);
+    assert!((circle.radius - 5.0).abs() < 1e-9, "{}", circle.radius);
+}
+
+#[test]
+fn fit_circle_to_noisy_points() {
+    // Perturb the distance from the center by a small, repeatable amount
+    let points = (0..100).map(|idx| {
+        let angle   = (idx as f64) * f64::consts::PI * 2.0 / 100.0;
+        let noise   = ((idx * 37) % 11) as f64 / 100.0 - 0.05;
+
+        point_on_ellipse(angle, Coord2(10.0, 20.0), 8.0 + noise, 8.0 + noise, 0.0)
+    }).collect::<Vec<_>>();
+    let circle = fit_circle(&points).unwrap();
+
+    assert!(circle.center.distance_to(&Coord2(10.0, 20.0)) < 0.02, "{:?}", circle.center);
+    assert!((circle.radius - 8.0).abs() < 0.02, "{}", circle.radius);
+}
+
+#[test]
+fn fit_circle_to_partial_arc() {
+    let points = (0..10).map(|idx| point_on_ellipse(1.0 + (idx as f64) * 0.15, Coord2(-4.0, 1.0), 2.0, 2.0, 0.0)).collect::<Vec<_>>();
+    let circle = fit_circle(&points).unwrap();
+
+    assert!(circle.center.distance_to(&Coord2(-4.0, 1.0)) < 1e-6, "{:?}", circle.center);
+    assert!((circle.radius - 2.0).abs() < 1e-6, "{}", circle.radius);
+}
+
+#[test]
+fn fit_circle_to_collinear_points() {
+    assert!(fit_circle(&[Coord2(0.0, 0.0), Coord2(1.0, 1.0), Coord2(2.0, 2.0), Coord2(5.0, 5.0)]).is_none());
+    assert!(fit_circle(&[Coord2(0.0, 0.0), Coord2(1.0, 1.0)]).is_none());
+    assert!(fit_circle(&[Coord2(1.0, 1.0), Coord2(1.0, 1.0), Coord2(1.0, 1.0)]).is_none());
+}
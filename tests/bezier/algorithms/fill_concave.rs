@@ -136,6 +136,34 @@ fn fill_concave_circle_offset() {
     }
 }
 
+#[test]
+fn fill_concave_circle_large_scale() {
+    // All of the settings need to be scaled up when filling a very large region
+    let circle_center   = Coord2(10000.0, 10000.0);
+    let radius          = 5000.0;
+    let circle_ray_cast = circle_ray_cast(circle_center, radius);
+    let settings        = FillSettings::default()
+        .with_step(200.0)
+        .with_fit_error(50.0)
+        .with_min_gap(Some(500.0))
+        .with_interior_point_tolerance(100.0);
+
+    // Flood-fill this curve
+    let path = flood_fill_concave::<SimpleBezierPath, _, _, _,_>(circle_center, &settings, circle_ray_cast);
+
+    assert!(path.is_some());
+    assert!(path.as_ref().unwrap().len() == 1);
+
+    for curve in path.unwrap()[0].to_curves::<Curve<Coord2>>() {
+        for t in 0..100 {
+            let t           = (t as f64)/100.0;
+            let distance    = circle_center.distance_to(&curve.point_at_pos(t));
+
+            assert!((distance-radius).abs() < 100.0);
+        }
+    }
+}
+
 #[test]
 fn fill_concave_doughnut() {
     // A 'doughnut' shape is one of the harder shapes to fill in this manner as eventually we'll have to raycast over areas we've already filled
@@ -370,3 +398,11 @@ fn fill_doughnut_without_escaping_gaps() {
         }
     }
 }
+
+#[test]
+fn fill_with_no_collisions_is_none() {
+    // Ray casting function that never hits anything
+    let path = flood_fill_concave::<SimpleBezierPath, _, (), _, _>(Coord2(0.0, 0.0), &FillSettings::default(), |_from, _to| vec![]);
+
+    assert!(path.is_none());
+}
@@ -136,3 +136,11 @@ fn fill_convex_doughnut() {
         }
     }
 }
+
+#[test]
+fn fill_with_no_collisions_is_none() {
+    // Ray casting function that never hits anything
+    let path = flood_fill_convex::<SimpleBezierPath, _, (), _, _>(Coord2(0.0, 0.0), &FillSettings::default(), |_from, _to| vec![]);
+
+    assert!(path.is_none());
+}
@@ -22,3 +22,22 @@ fn basis_agrees_with_de_casteljau() {
         assert!(approx_equal(basis, de_casteljau));
     }
 }
+
+#[test]
+fn elevated_quadratic_matches_original() {
+    let (w1, w2, w3)        = (Coord2(1.0, 2.0), Coord2(3.0, 7.0), Coord2(6.0, -1.0));
+    let (p1, p2, p3, p4)    = bezier::elevate_quadratic(w1, w2, w3);
+    let cubic               = bezier::Curve::from_points(p1, (p2, p3), p4);
+
+    for x in 0..100 {
+        let t           = (x as f64)/99.0;
+        let original    = bezier::de_casteljau3(t, w1, w2, w3);
+
+        assert!(original.distance_to(&cubic.point_at_pos(t)) < 1e-9);
+    }
+}
+
+#[test]
+fn elevate_empty_curve() {
+    assert!(bezier::elevate_bezier::<Coord2>(&[]).is_empty());
+}
@@ -21,3 +21,30 @@ fn get_curved_line_bounds() {
     assert!(bounds.0.distance_to(&Coord2(-0.3, 1.0)) < 0.0001);
     assert!(bounds.1.distance_to(&Coord2(2.0, 3.0)) < 0.0001);
 }
+
+#[test]
+fn bounding_box_matches_dense_samples() {
+    let curves = vec![
+        bezier::Curve::from_points(Coord2(0.0, 1.0), (Coord2(-1.1875291, 1.5), Coord2(1.5, 2.5)), Coord2(2.0, 3.0)),
+        bezier::Curve::from_points(Coord2(10.0, 100.0), (Coord2(90.0, 30.0), Coord2(40.0, 140.0)), Coord2(220.0, 220.0)),
+        bezier::Curve::from_points(Coord2(0.0, 0.0), (Coord2(300.0, 0.0), Coord2(-200.0, 100.0)), Coord2(100.0, 100.0)),
+        bezier::Curve::from_points(Coord2(0.0, 0.0), (Coord2(10.0, 10.0), Coord2(-5.0, 10.0)), Coord2(5.0, 0.0)),
+    ];
+
+    for curve in curves {
+        let (min, max) = curve.bounding_box::<(Coord2, Coord2)>();
+
+        // Find the bounds by sampling the curve
+        let samples         = (0..=1000).map(|idx| curve.point_at_pos((idx as f64) / 1000.0)).collect::<Vec<_>>();
+        let sampled_min     = samples.iter().fold(samples[0], |min, point| Coord2::from_smallest_components(min, *point));
+        let sampled_max     = samples.iter().fold(samples[0], |max, point| Coord2::from_biggest_components(max, *point));
+
+        // The bounds should contain all the samples, and be no bigger than the samples (allowing for the gap between samples)
+        for point in samples.iter() {
+            assert!(point.0 >= min.0 - 1e-9 && point.1 >= min.1 - 1e-9 && point.0 <= max.0 + 1e-9 && point.1 <= max.1 + 1e-9);
+        }
+
+        assert!(sampled_min.distance_to(&min) < 0.001, "{:?} {:?}", sampled_min, min);
+        assert!(sampled_max.distance_to(&max) < 0.001, "{:?} {:?}", sampled_max, max);
+    }
+}
@@ -0,0 +1,49 @@
+use flo_curves::bezier::*;
+
+#[test]
+fn curves_that_do_not_meet() {
+    let first   = Curve::from_points(Coord2(0.0, 0.0), (Coord2(1.0, 0.0), Coord2(2.0, 0.0)), Coord2(3.0, 0.0));
+    let second  = Curve::from_points(Coord2(4.0, 0.0), (Coord2(5.0, 0.0), Coord2(6.0, 0.0)), Coord2(7.0, 0.0));
+
+    assert!(join_continuity(&first, &second, 0.001) == Continuity::Discontinuous);
+}
+
+#[test]
+fn curves_with_corner() {
+    let first   = Curve::from_points(Coord2(0.0, 0.0), (Coord2(1.0, 0.0), Coord2(2.0, 0.0)), Coord2(3.0, 0.0));
+    let second  = Curve::from_points(Coord2(3.0, 0.0), (Coord2(3.0, 1.0), Coord2(3.0, 2.0)), Coord2(3.0, 3.0));
+
+    assert!(join_continuity(&first, &second, 0.001) == Continuity::C0);
+}
+
+#[test]
+fn curves_with_same_direction() {
+    let first   = Curve::from_points(Coord2(0.0, 0.0), (Coord2(1.0, 0.0), Coord2(2.0, 0.0)), Coord2(3.0, 0.0));
+    let second  = Curve::from_points(Coord2(3.0, 0.0), (Coord2(5.0, 0.0), Coord2(6.0, 1.0)), Coord2(6.0, 3.0));
+
+    assert!(join_continuity(&first, &second, 0.001) == Continuity::G1);
+}
+
+#[test]
+fn curves_with_same_derivative() {
+    let first   = Curve::from_points(Coord2(0.0, 0.0), (Coord2(1.0, 0.0), Coord2(2.0, 0.0)), Coord2(3.0, 0.0));
+    let second  = Curve::from_points(Coord2(3.0, 0.0), (Coord2(4.0, 0.0), Coord2(6.0, 1.0)), Coord2(6.0, 3.0));
+
+    assert!(join_continuity(&first, &second, 0.001) == Continuity::C1);
+}
+
+#[test]
+fn degenerate_control_point_at_join() {
+    // Second control point is at the end of the first curve, so the direction comes from the first control point
+    let first   = Curve::from_points(Coord2(0.0, 0.0), (Coord2(1.0, 0.0), Coord2(3.0, 0.0)), Coord2(3.0, 0.0));
+    let second  = Curve::from_points(Coord2(3.0, 0.0), (Coord2(4.0, 0.0), Coord2(6.0, 1.0)), Coord2(6.0, 3.0));
+
+    assert!(join_continuity(&first, &second, 0.001) == Continuity::G1);
+}
+
+#[test]
+fn continuity_ordering() {
+    assert!(Continuity::Discontinuous < Continuity::C0);
+    assert!(Continuity::C0 < Continuity::G1);
+    assert!(Continuity::G1 < Continuity::C1);
+}
@@ -0,0 +1,79 @@
+use flo_curves::*;
+use flo_curves::arc::*;
+use flo_curves::bezier::*;
+
+#[test]
+fn straight_line_has_no_curvature() {
+    let line = Curve::from_points(Coord2(0.0, 0.0), (Coord2(1.0, 1.0), Coord2(2.0, 2.0)), Coord2(3.0, 3.0));
+
+    for t in 0..=10 {
+        assert!(curvature_at_pos(&line, (t as f64)/10.0).abs() < 1e-10);
+    }
+}
+
+#[test]
+fn circle_curvature_is_reciprocal_of_radius() {
+    // Anticlockwise arcs have positive curvature
+    let arc = Circle::new(Coord2(5.0, 5.0), 4.0).arc(0.0, -1.0).to_bezier_curve::<Curve<Coord2>>();
+
+    for t in 0..=10 {
+        let curvature = curvature_at_pos(&arc, (t as f64)/10.0);
+        assert!((curvature - 0.25).abs() < 0.01, "{:?}", curvature);
+    }
+
+    // ... and clockwise arcs have negative curvature
+    let arc = Circle::new(Coord2(5.0, 5.0), 4.0).arc(0.0, 1.0).to_bezier_curve::<Curve<Coord2>>();
+
+    for t in 0..=10 {
+        let curvature = curvature_at_pos(&arc, (t as f64)/10.0);
+        assert!((curvature + 0.25).abs() < 0.01, "{:?}", curvature);
+    }
+}
+
+#[test]
+fn comb_hairs_point_towards_center_of_circle() {
+    let center  = Coord2(5.0, 5.0);
+    let arc     = Circle::new(center, 4.0).arc(0.0, 1.0).to_bezier_curve::<Curve<Coord2>>();
+    let comb    = curvature_comb(&arc, 10, 4.0);
+
+    assert!(comb.len() == 10);
+
+    for (point, tip) in comb {
+        // Scale cancels out the radius, so the hairs should be about 1 unit long and point towards the center
+        assert!((point.distance_to(&tip) - 1.0).abs() < 0.02);
+        assert!(tip.distance_to(&center) < point.distance_to(&center));
+    }
+}
+
+#[test]
+fn comb_hairs_are_longer_where_curve_bends_more() {
+    // Curve that gets tighter towards the end
+    let curve   = Curve::from_points(Coord2(0.0, 0.0), (Coord2(6.0, 0.0), Coord2(8.0, 1.0)), Coord2(8.0, 2.0));
+    let comb    = curvature_comb(&curve, 20, 1.0);
+
+    for idx in 0..comb.len() {
+        let t           = (idx as f64) / 19.0;
+        let hair_length = comb[idx].0.distance_to(&comb[idx].1);
+
+        assert!((hair_length - curvature_at_pos(&curve, t).abs()).abs() < 1e-6);
+    }
+
+    let first_length    = comb[0].0.distance_to(&comb[0].1);
+    let last_length     = comb[19].0.distance_to(&comb[19].1);
+    assert!(last_length > first_length * 4.0, "{} {}", first_length, last_length);
+}
+
+#[test]
+fn curvature_at_arc_midpoint() {
+    let arc = Circle::new(Coord2(5.0, 5.0), 10.0).arc(0.0, -1.0).to_bezier_curve::<Curve<Coord2>>();
+
+    assert!((arc.curvature_at(0.5) - 0.1).abs() < 0.001, "{}", arc.curvature_at(0.5));
+    assert!(arc.curvature_at(0.5) == curvature_at_pos(&arc, 0.5));
+}
+
+#[test]
+fn curvature_at_zero_speed_is_zero() {
+    let point = Curve::from_points(Coord2(1.0, 1.0), (Coord2(1.0, 1.0), Coord2(1.0, 1.0)), Coord2(1.0, 1.0));
+
+    assert!(point.curvature_at(0.5) == 0.0);
+}
@@ -813,3 +813,51 @@ fn collinear_lines_4() {
     assert!(line1_pos1.distance_to(&line2_pos1) <= 0.1, "Collision points mismatch");
     assert!(line1_pos2.distance_to(&line2_pos2) <= 0.1, "Collision points mismatch");
 }
+
+#[test]
+fn diagonal_curves_with_overlapping_bounds_are_disjoint() {
+    // Two parallel diagonal curves: their bounding boxes overlap, but the curves are some distance apart
+    let curve1 = bezier::Curve::from_points(Coord2(0.0, 0.0), (Coord2(3.0, 3.5), Coord2(6.0, 6.5)), Coord2(10.0, 10.0));
+    let curve2 = bezier::Curve::from_points(Coord2(2.0, 0.0), (Coord2(5.0, 2.5), Coord2(8.0, 5.5)), Coord2(12.0, 10.0));
+
+    let bounds1: (Coord2, Coord2) = curve1.bounding_box();
+    let bounds2: (Coord2, Coord2) = curve2.bounding_box();
+    assert!(bounds1.overlaps(&bounds2));
+
+    assert!(bezier::curves_definitely_disjoint(&curve1, &curve2));
+    assert!(bezier::curve_intersects_curve_clip(&curve1, &curve2, 0.01).is_empty());
+}
+
+#[test]
+fn crossing_curves_are_not_disjoint() {
+    let curve1 = bezier::Curve::from_points(Coord2(0.0, 0.0), (Coord2(3.0, 3.5), Coord2(6.0, 6.5)), Coord2(10.0, 10.0));
+    let curve2 = bezier::Curve::from_points(Coord2(0.0, 10.0), (Coord2(3.0, 6.5), Coord2(6.0, 3.5)), Coord2(10.0, 0.0));
+
+    assert!(!bezier::curves_definitely_disjoint(&curve1, &curve2));
+    assert!(!bezier::curve_intersects_curve_clip(&curve1, &curve2, 0.01).is_empty());
+}
+
+#[test]
+fn touching_curves_are_not_disjoint() {
+    // Curves that only meet at their end points still intersect
+    let curve1 = bezier::Curve::from_points(Coord2(0.0, 0.0), (Coord2(3.0, 4.0), Coord2(6.0, 4.0)), Coord2(10.0, 0.0));
+    let curve2 = bezier::Curve::from_points(Coord2(10.0, 0.0), (Coord2(13.0, 4.0), Coord2(16.0, 4.0)), Coord2(20.0, 0.0));
+
+    assert!(!bezier::curves_definitely_disjoint(&curve1, &curve2));
+}
+
+#[test]
+fn intersections_are_sorted_along_first_curve() {
+    // S-shaped curve that crosses a straight line 3 times
+    let curve1 = bezier::Curve::from_points(Coord2(0.0, 0.0), (Coord2(3.0, 20.0), Coord2(7.0, -10.0)), Coord2(10.0, 10.0));
+    let curve2 = bezier::Curve::from_points(Coord2(10.0, 5.0), (Coord2(6.0, 5.0), Coord2(3.0, 5.0)), Coord2(0.0, 5.0));
+
+    let intersections = bezier::curve_intersects_curve_clip(&curve1, &curve2, 0.01);
+    assert!(intersections.len() == 3, "{:?}", intersections);
+    assert!(intersections.iter().zip(intersections.iter().skip(1)).all(|((t1a, _), (t1b, _))| t1a <= t1b), "{:?}", intersections);
+
+    // Swapping the curves should sort by the other t value
+    let intersections = bezier::curve_intersects_curve_clip(&curve2, &curve1, 0.01);
+    assert!(intersections.len() == 3, "{:?}", intersections);
+    assert!(intersections.iter().zip(intersections.iter().skip(1)).all(|((t1a, _), (t1b, _))| t1a <= t1b), "{:?}", intersections);
+}
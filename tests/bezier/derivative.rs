@@ -1,6 +1,42 @@
+use flo_curves::*;
 use flo_curves::bezier;
 
 #[test]
 fn take_first_derivative() {
     assert!(bezier::derivative4(1.0, 2.0, 3.0, 4.0) == (3.0, 3.0, 3.0));
 }
+
+#[test]
+fn evaluate_with_derivatives_matches_individual_derivatives() {
+    let curve           = bezier::Curve::from_points(Coord2(1.0, 2.0), (Coord2(3.0, 7.0), Coord2(8.0, -1.0)), Coord2(10.0, 4.0));
+    let (w1, (w2, w3), w4) = (curve.start_point(), curve.control_points(), curve.end_point());
+
+    let (d1, d2, d3)    = bezier::derivative4(w1, w2, w3, w4);
+    let (e1, e2)        = bezier::derivative3(d1, d2, d3);
+    let third           = bezier::derivative2(e1, e2);
+
+    for x in 0..=10 {
+        let t       = (x as f64) / 10.0;
+        let values  = curve.evaluate_with_derivatives(t, 3);
+
+        assert!(values.len() == 4);
+        assert!(values[0].distance_to(&curve.point_at_pos(t)) < 1e-9);
+        assert!(values[1].distance_to(&bezier::de_casteljau3(t, d1, d2, d3)) < 1e-9);
+        assert!(values[2].distance_to(&bezier::de_casteljau2(t, e1, e2)) < 1e-9);
+        assert!(values[3].distance_to(&third) < 1e-9);
+    }
+}
+
+#[test]
+fn evaluate_with_higher_derivatives() {
+    let curve   = bezier::Curve::from_points(Coord2(1.0, 2.0), (Coord2(3.0, 7.0), Coord2(8.0, -1.0)), Coord2(10.0, 4.0));
+
+    let values  = curve.evaluate_with_derivatives(0.25, 5);
+    assert!(values.len() == 6);
+    assert!(values[4] == Coord2(0.0, 0.0));
+    assert!(values[5] == Coord2(0.0, 0.0));
+
+    let values  = curve.evaluate_with_derivatives(0.25, 0);
+    assert!(values.len() == 1);
+    assert!(values[0].distance_to(&curve.point_at_pos(0.25)) < 1e-9);
+}
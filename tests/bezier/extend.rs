@@ -0,0 +1,65 @@
+use flo_curves::bezier::*;
+
+#[test]
+fn extend_both_ends() {
+    let curve       = Curve::from_points(Coord2(1.0, 1.0), (Coord2(3.0, 1.0), Coord2(5.0, 3.0)), Coord2(5.0, 5.0));
+    let extended    = extend_curve(&curve, 2.0, 3.0);
+
+    assert!(extended.len() == 3);
+
+    // Start line should lead into the curve along the start tangent (which is along the x axis)
+    assert!(extended[0].start_point().distance_to(&Coord2(-1.0, 1.0)) < 0.0001, "{:?}", extended[0].start_point());
+    assert!(extended[0].end_point() == curve.start_point());
+
+    // The curve itself should be unchanged
+    assert!(extended[1] == curve);
+
+    // End line should continue along the tangent at the end (which is along the y axis)
+    assert!(extended[2].start_point() == curve.end_point());
+    assert!(extended[2].end_point().distance_to(&Coord2(5.0, 8.0)) < 0.0001, "{:?}", extended[2].end_point());
+}
+
+#[test]
+fn extensions_are_tangent_continuous() {
+    let curve       = Curve::from_points(Coord2(1.0, 1.0), (Coord2(2.0, 3.0), Coord2(6.0, 2.0)), Coord2(5.0, 5.0));
+    let extended    = extend_curve(&curve, 1.5, 1.5);
+
+    assert!(extended.len() == 3);
+    assert!(join_continuity(&extended[0], &extended[1], 0.0001) >= Continuity::G1);
+    assert!(join_continuity(&extended[1], &extended[2], 0.0001) >= Continuity::G1);
+
+    // New end points should be the requested distances away along the tangents
+    let start_tangent   = curve.tangent_at_pos(0.0).to_unit_vector();
+    let end_tangent     = curve.tangent_at_pos(1.0).to_unit_vector();
+
+    assert!(extended[0].start_point().distance_to(&(curve.start_point() - start_tangent*1.5)) < 0.0001);
+    assert!(extended[2].end_point().distance_to(&(curve.end_point() + end_tangent*1.5)) < 0.0001);
+}
+
+#[test]
+fn negative_lengths_trim() {
+    // Straight line of length 10
+    let curve       = Curve::from_points(Coord2(0.0, 0.0), (Coord2(3.0, 0.0), Coord2(7.0, 0.0)), Coord2(10.0, 0.0));
+    let trimmed     = extend_curve(&curve, -2.0, -3.0);
+
+    assert!(trimmed.len() == 1);
+    assert!(trimmed[0].start_point().distance_to(&Coord2(2.0, 0.0)) < 0.001, "{:?}", trimmed[0].start_point());
+    assert!(trimmed[0].end_point().distance_to(&Coord2(7.0, 0.0)) < 0.001, "{:?}", trimmed[0].end_point());
+}
+
+#[test]
+fn extend_one_end_and_trim_the_other() {
+    let curve       = Curve::from_points(Coord2(0.0, 0.0), (Coord2(3.0, 0.0), Coord2(7.0, 0.0)), Coord2(10.0, 0.0));
+    let result      = extend_curve(&curve, -2.0, 1.0);
+
+    assert!(result.len() == 2);
+    assert!(result[0].start_point().distance_to(&Coord2(2.0, 0.0)) < 0.001);
+    assert!(result[1].end_point().distance_to(&Coord2(11.0, 0.0)) < 0.001);
+}
+
+#[test]
+fn trim_entire_curve() {
+    let curve       = Curve::from_points(Coord2(0.0, 0.0), (Coord2(3.0, 0.0), Coord2(7.0, 0.0)), Coord2(10.0, 0.0));
+
+    assert!(extend_curve(&curve, -6.0, -6.0).is_empty());
+}
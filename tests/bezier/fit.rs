@@ -0,0 +1,176 @@
+use flo_curves::*;
+use flo_curves::bezier::*;
+
+#[test]
+fn fit_points_with_attributes() {
+    // Sine wave with a pressure that increases along its length
+    let points      = (0..250).map(|x| Coord2((x as f64)*0.1, ((x as f64)*0.05).sin()*10.0)).collect::<Vec<_>>();
+    let pressure    = (0..250).map(|x| (x as f64)/250.0).collect::<Vec<_>>();
+
+    let fitted      = fit_curve_with_attributes::<Curve<Coord2>>(&points, &pressure, 0.1).unwrap();
+
+    // The sine wave and the blocks of points mean this should need several curves
+    assert!(fitted.len() > 2);
+
+    // Attributes at each anchor should increase monotonically
+    for (_curve, (start_pressure, end_pressure)) in fitted.iter() {
+        assert!(start_pressure < end_pressure);
+    }
+
+    for idx in 1..fitted.len() {
+        assert!((fitted[idx-1].1).1 <= (fitted[idx].1).0);
+    }
+
+    // Should start and end with the attributes of the first and last point
+    assert!((fitted[0].1).0 == 0.0);
+    assert!((fitted[fitted.len()-1].1).1 == 249.0/250.0);
+}
+
+#[test]
+fn attributes_match_anchor_points() {
+    let points      = (0..50).map(|x| Coord2((x as f64)*0.2, ((x as f64)*0.3).cos()*4.0)).collect::<Vec<_>>();
+    let attributes  = (0..50).map(|x| x as f64).collect::<Vec<_>>();
+
+    let fitted      = fit_curve_with_attributes::<Curve<Coord2>>(&points, &attributes, 0.05).unwrap();
+
+    for (curve, (start_attr, end_attr)) in fitted {
+        // Attribute is the index of the point that the curve starts/ends at
+        assert!(curve.start_point().distance_to(&points[start_attr as usize]) < 0.0001);
+        assert!(curve.end_point().distance_to(&points[end_attr as usize]) < 0.0001);
+    }
+}
+
+#[test]
+fn fitting_with_attributes_matches_fit_curve() {
+    let points      = (0..120).map(|x| Coord2((x as f64)*0.1, ((x as f64)*0.1).sin()*3.0)).collect::<Vec<_>>();
+    let attributes  = vec![1.0; 120];
+
+    let plain       = fit_curve::<Curve<Coord2>>(&points, 0.1).unwrap();
+    let attributed  = fit_curve_with_attributes::<Curve<Coord2>>(&points, &attributes, 0.1).unwrap();
+
+    assert!(plain.len() == attributed.len());
+    for (plain, (attributed, _)) in plain.iter().zip(attributed.iter()) {
+        assert!(plain == attributed);
+    }
+}
+
+#[test]
+fn too_few_points_is_none() {
+    assert!(fit_curve_with_attributes::<Curve<Coord2>>(&[Coord2(1.0, 1.0)], &[1.0], 0.1).is_none());
+}
+
+#[test]
+fn mismatched_attributes_is_none() {
+    let points      = (0..10).map(|x| Coord2(x as f64, (x*x) as f64)).collect::<Vec<_>>();
+
+    assert!(fit_curve_with_attributes::<Curve<Coord2>>(&points, &[1.0; 9], 0.1).is_none());
+    assert!(fit_curve_with_attributes::<Curve<Coord2>>(&points, &[1.0; 11], 0.1).is_none());
+}
+
+#[test]
+fn fit_no_points() {
+    assert!(fit_curve::<Curve<Coord2>>(&[], 0.1).is_none());
+}
+
+#[test]
+fn fit_one_point() {
+    assert!(fit_curve::<Curve<Coord2>>(&[Coord2(1.0, 2.0)], 0.1).is_none());
+}
+
+#[test]
+fn fit_cubic_too_few_points() {
+    assert!(fit_curve_cubic::<Curve<Coord2>>(&[], &Coord2(1.0, 0.0), &Coord2(-1.0, 0.0), 0.1).is_empty());
+    assert!(fit_curve_cubic::<Curve<Coord2>>(&[Coord2(1.0, 2.0)], &Coord2(1.0, 0.0), &Coord2(-1.0, 0.0), 0.1).is_empty());
+}
+
+#[test]
+fn fit_points_in_same_place() {
+    let curves = fit_curve::<Curve<Coord2>>(&[Coord2(1.0, 2.0), Coord2(1.0, 2.0), Coord2(1.0, 2.0), Coord2(1.0, 2.0)], 0.1).unwrap();
+
+    // Should generate a curve that's just a point (and in particular should not generate NaN control points)
+    assert!(curves.len() == 1);
+    assert!(curves[0].start_point() == Coord2(1.0, 2.0));
+    assert!(curves[0].control_points() == (Coord2(1.0, 2.0), Coord2(1.0, 2.0)));
+    assert!(curves[0].end_point() == Coord2(1.0, 2.0));
+}
+
+fn noisy_arch(num_points: usize) -> Vec<Coord2> {
+    // An arch that is nearly symmetrical around x=5, with a little bit of noise on one side
+    (0..num_points).map(|idx| {
+        let t       = (idx as f64) / ((num_points-1) as f64);
+        let noise   = if idx % 3 == 0 && t > 0.5 { 0.01 } else { 0.0 };
+        let angle   = t * std::f64::consts::PI;
+
+        Coord2(5.0 - 4.0*angle.cos(), 2.0 + 3.0*angle.sin() + noise)
+    }).collect()
+}
+
+fn assert_mirror_symmetric(curves: &[Curve<Coord2>], axis: SymmetryAxis) {
+    let num_curves = curves.len();
+
+    // The second half is generated by mirroring the first half, so this is exact
+    for idx in 0..(num_curves/2) {
+        let curve           = &curves[idx];
+        let mirror_curve    = &curves[num_curves-1-idx];
+        let (cp1, cp2)      = curve.control_points();
+        let (mcp1, mcp2)    = mirror_curve.control_points();
+
+        assert!(axis.mirror(&curve.start_point()) == mirror_curve.end_point());
+        assert!(axis.mirror(&cp1) == mcp2);
+        assert!(axis.mirror(&cp2) == mcp1);
+        assert!(axis.mirror(&curve.end_point()) == mirror_curve.start_point());
+    }
+}
+
+#[test]
+fn fit_symmetric_points() {
+    for num_points in [40, 41].iter() {
+        let points  = noisy_arch(*num_points);
+        let curves  = fit_curve_with_symmetry::<Curve<Coord2>>(&points, 0.1, Some(SymmetryAxis::Vertical(5.0))).unwrap();
+
+        assert!(curves.len() % 2 == 0);
+        assert_mirror_symmetric(&curves, SymmetryAxis::Vertical(5.0));
+
+        // The curves should join at the axis
+        let middle = curves[curves.len()/2].start_point();
+        assert!(middle.0 == 5.0);
+        assert!((middle.1 - 5.0).abs() < 0.1, "{:?}", middle);
+
+        // The fitted curves should be close to the points
+        for point in points.iter() {
+            let distance = curves.iter().map(|curve| curve.distance_to(point)).fold(f64::MAX, f64::min);
+            assert!(distance < 0.2, "{:?} {}", point, distance);
+        }
+    }
+}
+
+#[test]
+fn fit_many_symmetric_points() {
+    // Each half has more points than are fitted at once, so gets divided into blocks
+    let points  = noisy_arch(501);
+    let curves  = fit_curve_with_symmetry::<Curve<Coord2>>(&points, 0.1, Some(SymmetryAxis::Vertical(5.0))).unwrap();
+
+    assert_mirror_symmetric(&curves, SymmetryAxis::Vertical(5.0));
+    assert!(curves[curves.len()/2].start_point().0 == 5.0);
+
+    for point in points.iter() {
+        let distance = curves.iter().map(|curve| curve.distance_to(point)).fold(f64::MAX, f64::min);
+        assert!(distance < 0.2, "{:?} {}", point, distance);
+    }
+}
+
+#[test]
+fn fit_symmetric_points_horizontal() {
+    let points  = noisy_arch(30).into_iter().map(|Coord2(x, y)| Coord2(y, x)).collect::<Vec<_>>();
+    let curves  = fit_curve_with_symmetry::<Curve<Coord2>>(&points, 0.1, Some(SymmetryAxis::Horizontal(5.0))).unwrap();
+
+    assert_mirror_symmetric(&curves, SymmetryAxis::Horizontal(5.0));
+}
+
+#[test]
+fn fit_without_symmetry() {
+    let points = noisy_arch(40);
+
+    assert!(fit_curve_with_symmetry::<Curve<Coord2>>(&points, 0.1, None) == fit_curve::<Curve<Coord2>>(&points, 0.1));
+    assert!(fit_curve_with_symmetry::<Curve<Coord2>>(&points[0..1], 0.1, Some(SymmetryAxis::Vertical(5.0))).is_none());
+}
@@ -0,0 +1,36 @@
+use flo_curves::*;
+use flo_curves::bezier::*;
+
+#[test]
+fn identical_curves_overlap_by_hull_area() {
+    // Hull of the control points is the quadrilateral (0,0), (10,0), (10,10), (0,10)
+    let curve = Curve::from_points(Coord2(0.0, 0.0), (Coord2(10.0, 0.0), Coord2(0.0, 10.0)), Coord2(10.0, 10.0));
+
+    assert!((hull_overlap_area(&curve, &curve) - 100.0).abs() < 1e-9, "{}", hull_overlap_area(&curve, &curve));
+}
+
+#[test]
+fn disjoint_curves_do_not_overlap() {
+    let curve1 = Curve::from_points(Coord2(0.0, 0.0), (Coord2(10.0, 0.0), Coord2(0.0, 10.0)), Coord2(10.0, 10.0));
+    let curve2 = Curve::from_points(Coord2(20.0, 0.0), (Coord2(30.0, 0.0), Coord2(20.0, 10.0)), Coord2(30.0, 10.0));
+
+    assert!(hull_overlap_area(&curve1, &curve2) == 0.0);
+}
+
+#[test]
+fn partly_overlapping_curves() {
+    // Second hull is the same square moved 5 units to the right and 5 units up, so a quarter of it overlaps
+    let curve1 = Curve::from_points(Coord2(0.0, 0.0), (Coord2(10.0, 0.0), Coord2(0.0, 10.0)), Coord2(10.0, 10.0));
+    let curve2 = Curve::from_points(Coord2(5.0, 5.0), (Coord2(15.0, 5.0), Coord2(5.0, 15.0)), Coord2(15.0, 15.0));
+
+    assert!((hull_overlap_area(&curve1, &curve2) - 25.0).abs() < 1e-9, "{}", hull_overlap_area(&curve1, &curve2));
+    assert!((hull_overlap_area(&curve2, &curve1) - 25.0).abs() < 1e-9, "{}", hull_overlap_area(&curve2, &curve1));
+}
+
+#[test]
+fn straight_line_has_no_overlap() {
+    let curve1 = Curve::from_points(Coord2(0.0, 0.0), (Coord2(10.0, 0.0), Coord2(0.0, 10.0)), Coord2(10.0, 10.0));
+    let line   = Curve::from_points(Coord2(0.0, 0.0), (Coord2(3.0, 3.0), Coord2(6.0, 6.0)), Coord2(10.0, 10.0));
+
+    assert!(hull_overlap_area(&curve1, &line) == 0.0);
+}
@@ -479,3 +479,39 @@ fn collinear_lines_2() {
         assert!(distance < 0.01, "Distance between points {} at curve_t {}, line_t {}", distance, curve_t, ray_t);
     }
 }
+
+#[test]
+fn curve_crosses_circle_twice() {
+    // Curve runs from well outside the circle, across it and out the other side
+    let curve           = bezier::Curve::from_points(Coord2(-10.0, 0.0), (Coord2(-3.0, 4.0), Coord2(3.0, 4.0)), Coord2(10.0, 0.0));
+    let intersections   = bezier::curve_intersects_circle(&curve, &Coord2(0.0, 0.0), 5.0);
+
+    assert!(intersections.len() == 2, "{:?}", intersections);
+    assert!(intersections[0] < intersections[1]);
+
+    for t in intersections {
+        let pos = curve.point_at_pos(t);
+        assert!((pos.distance_to(&Coord2(0.0, 0.0)) - 5.0).abs() < 0.001, "{:?} {:?}", t, pos);
+    }
+}
+
+#[test]
+fn curve_crosses_large_circle_twice() {
+    let curve           = bezier::Curve::from_points(Coord2(900.0, 1000.0), (Coord2(970.0, 1040.0), Coord2(1030.0, 1040.0)), Coord2(1100.0, 1000.0));
+    let intersections   = bezier::curve_intersects_circle(&curve, &Coord2(1000.0, 1000.0), 50.0);
+
+    assert!(intersections.len() == 2, "{:?}", intersections);
+
+    for t in intersections {
+        let pos = curve.point_at_pos(t);
+        assert!((pos.distance_to(&Coord2(1000.0, 1000.0)) - 50.0).abs() < 0.001, "{:?} {:?}", t, pos);
+    }
+}
+
+#[test]
+fn curve_inside_circle_does_not_cross() {
+    let curve           = bezier::Curve::from_points(Coord2(-1.0, 0.0), (Coord2(-0.5, 1.0), Coord2(0.5, 1.0)), Coord2(1.0, 0.0));
+    let intersections   = bezier::curve_intersects_circle(&curve, &Coord2(0.0, 0.0), 5.0);
+
+    assert!(intersections.len() == 0);
+}
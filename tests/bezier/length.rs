@@ -1,5 +1,9 @@
+use flo_curves::*;
+use flo_curves::arc::*;
 use flo_curves::bezier::*;
 
+use std::f64;
+
 ///
 /// Estimates a curve's length by subdividing it a lot
 ///
@@ -58,3 +62,139 @@ fn measure_length_4() {
 
     assert!((by_measuring - by_subdivision).abs() < 1.0);
 }
+
+#[test]
+fn integral_of_speed_is_length() {
+    let curve   = Curve::from_points(Coord2(0.0, 1.0), (Coord2(2.0, 3.0), Coord2(5.0, -2.0)), Coord2(6.0, 4.0));
+
+    // Integrate using Simpson's rule
+    let steps   = 1000;
+    let h       = 1.0 / (steps as f64);
+    let mut sum = curve.speed_at_pos(0.0) + curve.speed_at_pos(1.0);
+    for step in 1..steps {
+        let weight = if step % 2 == 1 { 4.0 } else { 2.0 };
+        sum += weight * curve.speed_at_pos((step as f64) * h);
+    }
+    let integral = sum * h / 3.0;
+
+    assert!((integral - curve_length(&curve, 0.000001)).abs() < 0.001, "{} {}", integral, curve_length(&curve, 0.000001));
+}
+
+#[test]
+fn speed_of_straight_line() {
+    // Evenly spaced control points produce a constant speed that equals the length of the line
+    let curve = Curve::from_points(Coord2(0.0, 0.0), (Coord2(1.0, 0.0), Coord2(2.0, 0.0)), Coord2(3.0, 0.0));
+
+    for t in 0..=10 {
+        assert!((curve.speed_at_pos((t as f64)/10.0) - 3.0).abs() < 1e-10);
+    }
+}
+
+#[test]
+fn speed_is_zero_at_cusp() {
+    // Control points crossed over so the curve has a cusp at t=0.5
+    let curve = Curve::from_points(Coord2(0.0, 0.0), (Coord2(3.0, 3.0), Coord2(0.0, 3.0)), Coord2(3.0, 0.0));
+
+    assert!(curve.speed_at_pos(0.5) < 1e-10, "{}", curve.speed_at_pos(0.5));
+    assert!(curve.speed_at_pos(0.25) > 0.1);
+}
+
+#[test]
+fn bounded_length_of_straight_line_is_exact() {
+    let line = Curve::from_points(Coord2(1.0, 2.0), (Coord2(4.0, 6.0), Coord2(7.0, 10.0)), Coord2(10.0, 14.0));
+
+    assert!((curve_length_with_error_bound(&line, 1e-6) - 15.0).abs() < 1e-9);
+}
+
+#[test]
+fn bounded_length_of_semicircle() {
+    // The semicircle is made up of two quarter-circle arcs (which are very close to being exact)
+    let radius      = 100.0;
+    let first_half  = Circle::new(Coord2(0.0, 0.0), radius).arc(0.0, f64::consts::PI/2.0).to_bezier_curve::<Curve<Coord2>>();
+    let second_half = Circle::new(Coord2(0.0, 0.0), radius).arc(f64::consts::PI/2.0, f64::consts::PI).to_bezier_curve::<Curve<Coord2>>();
+
+    let length      = curve_length_with_error_bound(&first_half, 0.0005) + curve_length_with_error_bound(&second_half, 0.0005);
+
+    assert!((length - f64::consts::PI*radius).abs() < 0.05, "{}", length);
+}
+
+#[test]
+fn bounded_length_of_s_curve() {
+    let s_curve     = Curve::from_points(Coord2(0.0, 0.0), (Coord2(300.0, 0.0), Coord2(-200.0, 100.0)), Coord2(100.0, 100.0));
+    let reference   = walk_curve_unevenly(&s_curve, 100000).map(|section| chord_length(&section)).sum::<f64>();
+
+    for max_error in [1.0, 0.01, 0.0001].iter() {
+        let length = curve_length_with_error_bound(&s_curve, *max_error);
+
+        // The reference measurement is slightly too short, by much less than the smallest error bound
+        assert!((length - reference).abs() <= *max_error, "{} {} {}", length, reference, max_error);
+    }
+}
+
+#[test]
+fn arc_length_table_halfway() {
+    let curve       = Curve::from_points(Coord2(0.0, 0.0), (Coord2(300.0, 0.0), Coord2(-200.0, 100.0)), Coord2(100.0, 100.0));
+    let table       = ArcLengthTable::new(&curve, 16);
+    let length      = curve_length_with_error_bound(&curve, 1e-6);
+
+    assert!((table.total_length() - length).abs() < 1e-4);
+
+    let halfway_t   = table.t_for_distance(length/2.0);
+    let halfway_len = curve_length_with_error_bound(&curve.section(0.0, halfway_t), 1e-6);
+
+    assert!((halfway_len - length/2.0).abs() < 1e-3, "{} {}", halfway_len, length/2.0);
+}
+
+#[test]
+fn arc_length_table_many_distances() {
+    let curve   = Curve::from_points(Coord2(10.0, 20.0), (Coord2(100.0, 200.0), Coord2(150.0, -40.0)), Coord2(200.0, 60.0));
+    let table   = ArcLengthTable::new(&curve, 8);
+    let length  = table.total_length();
+
+    let mut last_t = 0.0;
+    for x in 0..=20 {
+        let distance    = length * (x as f64)/20.0;
+        let t           = table.t_for_distance(distance);
+        let measured    = curve_length_with_error_bound(&curve.section(0.0, t), 1e-6);
+
+        assert!(t >= last_t);
+        assert!((measured - distance).abs() < 1e-3, "{} {}", measured, distance);
+
+        last_t = t;
+    }
+}
+
+#[test]
+fn arc_length_table_out_of_range() {
+    let curve   = Curve::from_points(Coord2(10.0, 20.0), (Coord2(100.0, 200.0), Coord2(150.0, -40.0)), Coord2(200.0, 60.0));
+    let table   = ArcLengthTable::new(&curve, 8);
+
+    assert!(table.t_for_distance(-10.0) == 0.0);
+    assert!(table.t_for_distance(table.total_length() + 10.0) == 1.0);
+}
+
+#[test]
+fn sample_s_curve_evenly() {
+    let curve   = Curve::from_points(Coord2(10.0, 100.0), (Coord2(90.0, 30.0), Coord2(40.0, 140.0)), Coord2(220.0, 220.0));
+    let points  = curve.sample_evenly_by_arc_length(20);
+
+    assert!(points.len() == 20);
+    assert!(points[0] == curve.start_point());
+    assert!(points[19] == curve.end_point());
+
+    // Straight-line distances will be slightly shorter than the arc lengths but should still be very close to each other
+    let spacings    = points.iter().zip(points.iter().skip(1)).map(|(p1, p2)| p1.distance_to(p2)).collect::<Vec<_>>();
+    let mean        = spacings.iter().sum::<f64>() / (spacings.len() as f64);
+    let variance    = spacings.iter().map(|spacing| (spacing - mean) * (spacing - mean)).sum::<f64>() / (spacings.len() as f64);
+
+    assert!(variance < 0.01, "{} {:?}", variance, spacings);
+}
+
+#[test]
+fn sample_evenly_with_few_points() {
+    let curve = Curve::from_points(Coord2(0.0, 0.0), (Coord2(300.0, 0.0), Coord2(-200.0, 100.0)), Coord2(100.0, 100.0));
+
+    assert!(curve.sample_evenly_by_arc_length(0).is_empty());
+    assert!(curve.sample_evenly_by_arc_length(1) == vec![curve.start_point()]);
+    assert!(curve.sample_evenly_by_arc_length(2) == vec![curve.start_point(), curve.end_point()]);
+}
@@ -7,15 +7,19 @@ mod path;
 mod algorithms;
 
 mod basis;
+mod quadratic;
 mod section;
 mod subdivide;
 mod derivative;
 mod tangent;
 mod normal;
 mod bounds;
+mod oriented_bounds;
+mod hull_overlap;
 mod deform;
 mod search;
 mod solve;
+mod roots;
 mod offset;
 mod overlaps;
 mod intersection;
@@ -24,7 +28,13 @@ mod self_intersection;
 mod curve_intersection_clip;
 mod length;
 mod walk;
+mod fit;
+mod continuity;
+mod extend;
+mod curvature;
 mod distort;
+mod reflect;
+mod transform;
 mod nearest_point_tests;
 
 pub fn approx_equal(a: f64, b: f64) -> bool {
@@ -53,3 +63,82 @@ fn read_curve_points() {
         assert!(point.distance_to(&another_point) < 0.001);
     }
 }
+
+#[test]
+fn read_curve_control_point_array() {
+    let curve = bezier::Curve::from_points(Coord2(1.0, 1.0), (Coord2(3.0, 3.0), Coord2(4.0, 4.0)), Coord2(2.0, 2.0));
+    let array = curve.control_point_array();
+
+    assert!(array[0] == curve.start_point());
+    assert!((array[1], array[2]) == curve.control_points());
+    assert!(array[3] == curve.end_point());
+}
+
+#[test]
+fn de_casteljau_steps_end_at_curve_point() {
+    let curve = bezier::Curve::from_points(Coord2(1.0, 1.0), (Coord2(3.0, 5.0), Coord2(6.0, 4.0)), Coord2(8.0, 2.0));
+
+    for x in 0..=10 {
+        let t       = (x as f64)/10.0;
+        let steps   = curve.de_casteljau_steps(t);
+
+        assert!(steps.len() == 4);
+        assert!(steps.iter().map(|step| step.len()).collect::<Vec<_>>() == vec![4, 3, 2, 1]);
+        assert!(steps[0] == curve.control_point_array().to_vec());
+        assert!(steps[3][0].distance_to(&curve.point_at_pos(t)) < 0.0001);
+    }
+}
+
+#[test]
+fn de_casteljau_steps_subdivide_curve() {
+    let curve           = bezier::Curve::from_points(Coord2(1.0, 1.0), (Coord2(3.0, 5.0), Coord2(6.0, 4.0)), Coord2(8.0, 2.0));
+    let steps           = curve.de_casteljau_steps(0.3);
+    let (first, _)      = curve.subdivide::<bezier::Curve<_>>(0.3);
+
+    // The first point of each level forms the control polygon of the first half of the subdivided curve
+    assert!(steps[1][0].distance_to(&first.control_points().0) < 0.0001);
+    assert!(steps[2][0].distance_to(&first.control_points().1) < 0.0001);
+    assert!(steps[3][0].distance_to(&first.end_point()) < 0.0001);
+}
+
+#[test]
+fn reverse_curve() {
+    let curve       = bezier::Curve::from_points(Coord2(1.0, 1.0), (Coord2(3.0, 5.0), Coord2(6.0, 4.0)), Coord2(8.0, 2.0));
+    let reversed    = curve.reverse::<bezier::Curve<_>>();
+
+    assert!(reversed.start_point() == curve.end_point());
+    assert!(reversed.end_point() == curve.start_point());
+    assert!(reversed.control_points() == (Coord2(6.0, 4.0), Coord2(3.0, 5.0)));
+
+    for x in 0..=100 {
+        let t = (x as f64)/100.0;
+
+        assert!(reversed.point_at_pos(t).distance_to(&curve.point_at_pos(1.0-t)) < 1e-9);
+    }
+}
+
+#[test]
+fn similar_curves_have_same_cache_key() {
+    let curve1 = bezier::Curve::from_points(Coord2(1.0, 1.0), (Coord2(3.0, 5.0), Coord2(6.0, 4.0)), Coord2(8.0, 2.0));
+    let curve2 = bezier::Curve::from_points(Coord2(1.0001, 1.0), (Coord2(3.0, 4.9999), Coord2(6.0, 4.0)), Coord2(8.0, 2.0002));
+
+    assert!(curve1.curve_cache_key(0.01) == curve2.curve_cache_key(0.01));
+}
+
+#[test]
+fn different_curves_have_different_cache_keys() {
+    let curve1 = bezier::Curve::from_points(Coord2(1.0, 1.0), (Coord2(3.0, 5.0), Coord2(6.0, 4.0)), Coord2(8.0, 2.0));
+    let curve2 = bezier::Curve::from_points(Coord2(1.0, 1.0), (Coord2(3.0, 5.0), Coord2(6.0, 4.5)), Coord2(8.0, 2.0));
+    let curve3 = curve1.reverse::<bezier::Curve<_>>();
+
+    assert!(curve1.curve_cache_key(0.01) != curve2.curve_cache_key(0.01));
+    assert!(curve1.curve_cache_key(0.01) != curve3.curve_cache_key(0.01));
+}
+
+#[test]
+fn cache_key_is_stable() {
+    let curve = bezier::Curve::from_points(Coord2(1.0, 1.0), (Coord2(3.0, 5.0), Coord2(6.0, 4.0)), Coord2(8.0, 2.0));
+
+    assert!(curve.curve_cache_key(0.01) == curve.clone().curve_cache_key(0.01));
+    assert!(curve.curve_cache_key(0.01) != curve.curve_cache_key(0.001));
+}
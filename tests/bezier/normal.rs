@@ -155,3 +155,41 @@ fn normal_at_start_of_curve_matches_control_points() {
     assert!(normal.x() < 0.0);
     assert!(normal.y().abs() < 0.01);
 }
+
+#[test]
+fn frenet_frame_is_orthonormal() {
+    let curve = bezier::Curve::from_points(Coord2(10.0, 100.0), (Coord2(90.0, 30.0), Coord2(40.0, 140.0)), Coord2(220.0, 220.0));
+
+    for x in 0..=20 {
+        let (tangent, normal) = curve.frenet_frame_at((x as f64) / 20.0);
+
+        assert!((tangent.magnitude() - 1.0).abs() < 1e-9);
+        assert!((normal.magnitude() - 1.0).abs() < 1e-9);
+        assert!(tangent.dot(&normal).abs() < 1e-9);
+    }
+}
+
+#[test]
+fn frenet_frames_are_continuous() {
+    // S-shaped curve, where the curvature changes direction
+    let curve   = bezier::Curve::from_points(Coord2(0.0, 0.0), (Coord2(100.0, 0.0), Coord2(0.0, 100.0)), Coord2(100.0, 100.0));
+    let frames  = curve.frenet_frames(50);
+
+    assert!(frames.len() == 50);
+    assert!(frames[0].0 == curve.start_point());
+    assert!(frames[49].0 == curve.end_point());
+
+    for ((_, tangent1, normal1), (_, tangent2, normal2)) in frames.iter().zip(frames.iter().skip(1)) {
+        // Neighbouring frames should point in nearly the same direction
+        assert!(tangent1.dot(tangent2) > 0.9, "{:?} {:?}", tangent1, tangent2);
+        assert!(normal1.dot(normal2) > 0.9, "{:?} {:?}", normal1, normal2);
+    }
+}
+
+#[test]
+fn frenet_frames_for_point() {
+    let point   = bezier::Curve::from_points(Coord2(5.0, 5.0), (Coord2(5.0, 5.0), Coord2(5.0, 5.0)), Coord2(5.0, 5.0));
+
+    assert!(point.frenet_frames(0).is_empty());
+    assert!(point.frenet_frames(3).iter().all(|(pos, tangent, normal)| *pos == Coord2(5.0, 5.0) && tangent.magnitude() == 0.0 && normal.magnitude() == 0.0));
+}
@@ -286,3 +286,127 @@ fn offset_lms_sampling_arc_fit_single_curve() {
     // We should be able to find a single bezier curve that fits these points
     assert!(offset_arc.len() == 1);
 }
+
+#[test]
+fn offset_error_is_low_for_accurate_offset() {
+    let curve           = Curve::from_points(Coord2(10.0, 10.0), (Coord2(10.0, 40.0), Coord2(40.0, 60.0)), Coord2(90.0, 30.0));
+    let offset_curve    = offset(&curve, 10.0, 10.0);
+    let error           = offset_error(&curve, &offset_curve, 10.0, 50);
+
+    assert!(error < 0.5, "{}", error);
+}
+
+#[test]
+fn offset_error_is_high_for_poor_offset() {
+    // Moving the whole curve along the normal at the start is a very poor approximation of the offset curve
+    let curve           = Curve::from_points(Coord2(10.0, 10.0), (Coord2(10.0, 40.0), Coord2(40.0, 60.0)), Coord2(90.0, 30.0));
+    let start_normal    = Coord2::from_components(&Coord2::to_normal(&curve.start_point(), &curve.tangent_at_pos(0.0).to_unit_vector())) * 10.0;
+    let moved_curve     = Curve::from_points(curve.start_point() + start_normal, (curve.control_points().0 + start_normal, curve.control_points().1 + start_normal), curve.end_point() + start_normal);
+
+    let poor_error      = offset_error(&curve, &[moved_curve], 10.0, 50);
+    let good_error      = offset_error(&curve, &offset(&curve, 10.0, 10.0), 10.0, 50);
+
+    assert!(poor_error > 5.0, "{}", poor_error);
+    assert!(poor_error > good_error * 10.0);
+}
+
+#[test]
+fn offset_error_for_wrong_distance() {
+    let curve           = Curve::from_points(Coord2(10.0, 10.0), (Coord2(10.0, 40.0), Coord2(40.0, 60.0)), Coord2(90.0, 30.0));
+    let offset_curve    = offset(&curve, 10.0, 10.0);
+    let error           = offset_error(&curve, &offset_curve, 12.0, 50);
+
+    assert!((error - 2.0).abs() < 0.5, "{}", error);
+}
+
+#[test]
+fn offset_with_accuracy_is_within_error() {
+    // Curve that bends sharply in the middle
+    let curve           = Curve::from_points(Coord2(10.0, 100.0), (Coord2(90.0, 30.0), Coord2(40.0, 140.0)), Coord2(220.0, 220.0));
+    let max_error       = 0.1;
+    let offset_curves   = offset_with_accuracy(&curve, 5.0, max_error);
+
+    assert!(!offset_curves.is_empty());
+
+    for offset_curve in offset_curves.iter() {
+        for sample in 0..=20 {
+            let t           = (sample as f64) / 20.0;
+            let point       = offset_curve.point_at_pos(t);
+            let distance    = curve.distance_to(&point);
+
+            assert!((distance - 5.0).abs() <= max_error, "{:?} {}", point, distance);
+        }
+    }
+}
+
+#[test]
+fn offset_with_accuracy_is_continuous() {
+    let curve           = Curve::from_points(Coord2(10.0, 100.0), (Coord2(90.0, 30.0), Coord2(40.0, 140.0)), Coord2(220.0, 220.0));
+    let offset_curves   = offset_with_accuracy(&curve, 5.0, 0.01);
+
+    for (first, second) in offset_curves.iter().zip(offset_curves.iter().skip(1)) {
+        assert!(first.end_point().distance_to(&second.start_point()) < 0.01);
+    }
+}
+
+#[test]
+fn offset_with_accuracy_is_no_worse_than_offset() {
+    let curve           = Curve::from_points(Coord2(10.0, 10.0), (Coord2(10.0, 40.0), Coord2(40.0, 60.0)), Coord2(90.0, 30.0));
+    let simple_error    = offset_error(&curve, &offset(&curve, 10.0, 10.0), 10.0, 50);
+    let accurate_error  = offset_error(&curve, &offset_with_accuracy(&curve, 10.0, 0.01), 10.0, 50);
+
+    assert!(accurate_error <= simple_error.max(0.01), "{} {}", accurate_error, simple_error);
+    assert!(accurate_error < 0.05, "{}", accurate_error);
+}
+
+///
+/// Finds the largest distance between the offset curves and the points found by moving along the normal by the offset profile
+///
+fn variable_offset_error(curve: &Curve<Coord2>, offset_curves: &[Curve<Coord2>], profile: (f64, f64, f64, f64)) -> f64 {
+    (0..=50)
+        .map(|sample| {
+            let t               = (sample as f64) / 50.0;
+            let distance        = basis(t, profile.0, profile.1, profile.2, profile.3);
+            let expected_point  = curve.point_at_pos(t) + curve.normal_at_pos(t).to_unit_vector() * distance;
+
+            offset_curves.iter()
+                .map(|offset_curve| offset_curve.distance_to(&expected_point))
+                .fold(f64::MAX, f64::min)
+        })
+        .fold(0.0, f64::max)
+}
+
+#[test]
+fn variable_offset_follows_profile() {
+    let curve           = Curve::from_points(Coord2(10.0, 10.0), (Coord2(10.0, 40.0), Coord2(40.0, 60.0)), Coord2(90.0, 30.0));
+    let offset_curves   = offset_variable(&curve, 2.0, 4.0, 10.0, 8.0);
+    let error           = variable_offset_error(&curve, &offset_curves, (2.0, 10.0, 8.0, 4.0));
+
+    assert!(!offset_curves.is_empty());
+    assert!(error < 0.5, "{}", error);
+}
+
+#[test]
+fn variable_offset_with_constant_profile_matches_offset() {
+    let curve           = Curve::from_points(Coord2(10.0, 10.0), (Coord2(10.0, 40.0), Coord2(40.0, 60.0)), Coord2(90.0, 30.0));
+    let offset_curves   = offset_variable(&curve, 10.0, 10.0, 10.0, 10.0);
+
+    assert!(offset_error(&curve, &offset_curves, 10.0, 50) < 0.5);
+}
+
+#[test]
+fn variable_offset_pinches_through_zero() {
+    // The profile goes from one side of the curve to the other
+    let curve           = Curve::from_points(Coord2(10.0, 10.0), (Coord2(10.0, 40.0), Coord2(40.0, 60.0)), Coord2(90.0, 30.0));
+    let offset_curves   = offset_variable(&curve, 5.0, -5.0, 5.0, -5.0);
+    let error           = variable_offset_error(&curve, &offset_curves, (5.0, 5.0, -5.0, -5.0));
+
+    assert!(!offset_curves.is_empty());
+    assert!(offset_curves.iter().all(|offset_curve| offset_curve.control_point_array().iter().all(|point| !point.is_nan())));
+    assert!(error < 0.5, "{}", error);
+
+    // Where the profile is 0, the offset curve should cross the original curve
+    let pinch_point     = curve.point_at_pos(0.5);
+    let pinch_distance  = offset_curves.iter().map(|offset_curve| offset_curve.distance_to(&pinch_point)).fold(f64::MAX, f64::min);
+    assert!(pinch_distance < 0.5, "{}", pinch_distance);
+}
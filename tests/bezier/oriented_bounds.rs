@@ -0,0 +1,68 @@
+use flo_curves::*;
+use flo_curves::bezier::*;
+
+fn assert_contains_curve(curve: &Curve<Coord2>, bounds: &OrientedBox<Coord2>) {
+    let grown = OrientedBox { half_extents: (bounds.half_extents.0 + 1e-6, bounds.half_extents.1 + 1e-6), ..*bounds };
+
+    for idx in 0..=1000 {
+        let point = curve.point_at_pos((idx as f64) / 1000.0);
+        assert!(grown.contains_point(&point), "{:?} {:?}", point, bounds);
+    }
+}
+
+#[test]
+fn oriented_bounds_of_diagonal_line() {
+    let line    = Curve::from_points(Coord2(1.0, 1.0), (Coord2(3.0, 3.0), Coord2(6.0, 6.0)), Coord2(9.0, 9.0));
+    let bounds  = line.oriented_bounds();
+
+    // One axis follows the line, the other has no width
+    let direction = Coord2(1.0, 1.0).to_unit_vector();
+
+    assert!(bounds.axes.0.dot(&direction).abs() > 1.0 - 1e-9, "{:?}", bounds);
+    assert!(bounds.half_extents.1.abs() < 1e-9, "{:?}", bounds);
+    assert!((bounds.half_extents.0 - 8.0*f64::sqrt(2.0)/2.0).abs() < 1e-9, "{:?}", bounds);
+    assert!(bounds.center.distance_to(&Coord2(5.0, 5.0)) < 1e-9, "{:?}", bounds);
+    assert!(bounds.area().abs() < 1e-9);
+}
+
+#[test]
+fn oriented_bounds_contain_curves() {
+    let curves = vec![
+        Curve::from_points(Coord2(10.0, 100.0), (Coord2(90.0, 30.0), Coord2(40.0, 140.0)), Coord2(220.0, 220.0)),
+        Curve::from_points(Coord2(0.0, 0.0), (Coord2(300.0, 0.0), Coord2(-200.0, 100.0)), Coord2(100.0, 100.0)),
+        Curve::from_points(Coord2(0.0, 0.0), (Coord2(10.0, 10.0), Coord2(-5.0, 10.0)), Coord2(5.0, 0.0)),
+    ];
+
+    for curve in curves {
+        let bounds          = curve.oriented_bounds();
+        let (min, max)      = curve.bounding_box::<(Coord2, Coord2)>();
+        let aligned_area    = (max.0-min.0) * (max.1-min.1);
+
+        assert_contains_curve(&curve, &bounds);
+        assert!(bounds.area() <= aligned_area + 1e-6, "{} {}", bounds.area(), aligned_area);
+        assert!(bounds.axes.0.dot(&bounds.axes.1).abs() < 1e-9);
+    }
+}
+
+#[test]
+fn oriented_bounds_of_rotated_arch() {
+    // An arch rotated by 45 degrees should have a box that's much smaller than its axis-aligned box
+    let rotate          = |Coord2(x, y): Coord2| Coord2(x*0.5f64.sqrt() - y*0.5f64.sqrt(), x*0.5f64.sqrt() + y*0.5f64.sqrt());
+    let curve           = Curve::from_points(rotate(Coord2(0.0, 0.0)), (rotate(Coord2(0.0, 2.0)), rotate(Coord2(20.0, 2.0))), rotate(Coord2(20.0, 0.0)));
+    let bounds          = curve.oriented_bounds();
+    let (min, max)      = curve.bounding_box::<(Coord2, Coord2)>();
+    let aligned_area    = (max.0-min.0) * (max.1-min.1);
+
+    assert_contains_curve(&curve, &bounds);
+    assert!(bounds.area() < aligned_area * 0.5, "{} {}", bounds.area(), aligned_area);
+    assert!((bounds.area() - 20.0*1.5).abs() < 0.1, "{:?} {}", bounds, bounds.area());
+}
+
+#[test]
+fn corners_of_oriented_box() {
+    let bounds  = OrientedBox { center: Coord2(1.0, 2.0), axes: (Coord2(0.0, 1.0), Coord2(-1.0, 0.0)), half_extents: (2.0, 1.0) };
+    let corners = bounds.corners();
+
+    assert!(corners == [Coord2(2.0, 0.0), Coord2(2.0, 4.0), Coord2(0.0, 4.0), Coord2(0.0, 0.0)], "{:?}", corners);
+    assert!(bounds.area() == 8.0);
+}
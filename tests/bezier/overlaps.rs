@@ -248,3 +248,39 @@ fn collinear_lines_same_lines() {
     let overlaps = overlapping_region(&line1, &line2);
     assert!(overlaps.is_some(), "{:?}", overlaps);
 }
+
+#[test]
+fn same_shape_as_self() {
+    let curve = Curve::from_points(Coord2(10.0, 100.0), (Coord2(90.0, 30.0), Coord2(40.0, 140.0)), Coord2(220.0, 220.0));
+
+    assert!(same_shape(&curve, &curve, 0.01));
+}
+
+#[test]
+fn same_shape_as_reverse() {
+    let curve       = Curve::from_points(Coord2(10.0, 100.0), (Coord2(90.0, 30.0), Coord2(40.0, 140.0)), Coord2(220.0, 220.0));
+    let reversed    = curve.reverse::<Curve<_>>();
+
+    assert!(same_shape(&curve, &reversed, 0.01));
+    assert!(same_shape(&reversed, &curve, 0.01));
+}
+
+#[test]
+fn same_shape_with_different_parameterization() {
+    // Straight lines with the control points in different places
+    let line1 = Curve::from_points(Coord2(0.0, 0.0), (Coord2(3.0, 3.0), Coord2(6.0, 6.0)), Coord2(9.0, 9.0));
+    let line2 = Curve::from_points(Coord2(0.0, 0.0), (Coord2(1.0, 1.0), Coord2(5.0, 5.0)), Coord2(9.0, 9.0));
+
+    assert!(same_shape(&line1, &line2, 0.01));
+    assert!(same_shape(&line1, &line2.reverse::<Curve<_>>(), 0.01));
+}
+
+#[test]
+fn different_shapes() {
+    let curve1  = Curve::from_points(Coord2(10.0, 100.0), (Coord2(90.0, 30.0), Coord2(40.0, 140.0)), Coord2(220.0, 220.0));
+    let curve2  = Curve::from_points(Coord2(10.0, 100.0), (Coord2(90.0, 40.0), Coord2(40.0, 140.0)), Coord2(220.0, 220.0));
+    let section = curve1.section(0.0, 0.5);
+
+    assert!(!same_shape(&curve1, &curve2, 0.01));
+    assert!(!same_shape(&curve1, &section, 0.01));
+}
@@ -0,0 +1,55 @@
+use flo_curves::*;
+use flo_curves::arc::*;
+use flo_curves::bezier;
+use flo_curves::bezier::path::*;
+
+#[test]
+fn circle_equals_itself() {
+    let circle = Circle::new(Coord2(5.0, 5.0), 4.0).to_path::<SimpleBezierPath>();
+
+    assert!(paths_approx_equal(&circle, &circle, 0.001));
+}
+
+#[test]
+fn circle_equals_subdivided_circle() {
+    let circle      = Circle::new(Coord2(5.0, 5.0), 4.0).to_path::<SimpleBezierPath>();
+
+    // Split every curve in the circle in half
+    let subdivided  = circle.to_curves::<bezier::Curve<_>>()
+        .into_iter()
+        .flat_map(|curve| {
+            let (first, second) = curve.subdivide::<bezier::Curve<_>>(0.5);
+            vec![first, second]
+        })
+        .map(|curve| {
+            let (cp1, cp2) = curve.control_points();
+            (cp1, cp2, curve.end_point())
+        })
+        .collect::<Vec<_>>();
+    let subdivided  = SimpleBezierPath::from_points(circle.start_point(), subdivided);
+
+    assert!(subdivided.num_segments() == 8);
+    assert!(paths_approx_equal(&circle, &subdivided, 0.01));
+}
+
+#[test]
+fn different_circles_are_not_equal() {
+    let circle1 = Circle::new(Coord2(5.0, 5.0), 4.0).to_path::<SimpleBezierPath>();
+    let circle2 = Circle::new(Coord2(5.0, 5.0), 4.1).to_path::<SimpleBezierPath>();
+
+    assert!(!paths_approx_equal(&circle1, &circle2, 0.01));
+    assert!(paths_approx_equal(&circle1, &circle2, 0.2));
+}
+
+#[test]
+fn line_equals_line_in_two_parts() {
+    let line1 = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0))
+        .line_to(Coord2(10.0, 0.0))
+        .build();
+    let line2 = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0))
+        .line_to(Coord2(3.0, 0.0))
+        .line_to(Coord2(10.0, 0.0))
+        .build();
+
+    assert!(paths_approx_equal(&line1, &line2, 0.01));
+}
@@ -0,0 +1,269 @@
+use flo_curves::*;
+use flo_curves::arc::*;
+use flo_curves::bezier;
+use flo_curves::bezier::path::*;
+
+#[test]
+fn anchor_positions_for_equal_segments() {
+    let path = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0))
+        .line_to(Coord2(3.0, 0.0))
+        .line_to(Coord2(3.0, 3.0))
+        .line_to(Coord2(0.0, 3.0))
+        .build();
+
+    let positions = anchor_arc_positions(&path, 0.001);
+
+    assert!(positions.len() == 4);
+    assert!(positions[0] == 0.0);
+    assert!((positions[1] - 3.0).abs() < 0.01, "{:?}", positions);
+    assert!((positions[2] - 6.0).abs() < 0.01, "{:?}", positions);
+    assert!((positions[3] - 9.0).abs() < 0.01, "{:?}", positions);
+}
+
+#[test]
+fn anchor_positions_for_circle() {
+    let circle: SimpleBezierPath    = Circle::new(Coord2(5.0, 5.0), 2.0).to_path();
+    let positions                   = anchor_arc_positions(&circle, 0.0001);
+
+    assert!(positions.len() == circle.num_segments() + 1);
+
+    // Positions should increase along the path and end at the circumference
+    for idx in 1..positions.len() {
+        assert!(positions[idx] > positions[idx-1]);
+    }
+    assert!((positions[positions.len()-1] - 4.0*std::f64::consts::PI).abs() < 0.01, "{:?}", positions);
+}
+
+#[test]
+fn anchor_positions_for_path_with_no_curves() {
+    let path: SimpleBezierPath = (Coord2(1.0, 1.0), vec![]);
+
+    assert!(anchor_arc_positions(&path, 0.001) == vec![0.0]);
+}
+
+#[test]
+fn rotate_circle_start() {
+    let circle: SimpleBezierPath    = Circle::new(Coord2(5.0, 5.0), 2.0).to_path();
+    let circumference               = 4.0*std::f64::consts::PI;
+
+    // Circle paths are made from symmetric arcs, so moving 1/8th of the way around should start half way along the first arc
+    let curves                      = circle.to_curves::<bezier::Curve<_>>();
+    let num_curves                  = curves.len();
+    let expected_start              = curves[0].point_at_pos(0.5);
+
+    let rotated: SimpleBezierPath   = reparameterize_start(&circle, circumference / (2.0 * num_curves as f64));
+
+    assert!(rotated.num_segments() == circle.num_segments()+1);
+    assert!(rotated.start_point().distance_to(&expected_start) < 0.001, "{:?} {:?}", rotated.start_point(), expected_start);
+    assert!(rotated.points().last().unwrap().2.distance_to(&expected_start) < 0.001);
+    assert!((rotated.approx_length(0.0001) - circle.approx_length(0.0001)).abs() < 0.001);
+
+    // Every point on the rotated path should be on the original circle
+    for curve in rotated.to_curves::<bezier::Curve<_>>() {
+        for t in 0..=20 {
+            let pos = curve.point_at_pos((t as f64)/20.0);
+            assert!((pos.distance_to(&Coord2(5.0, 5.0)) - 2.0).abs() < 0.01);
+        }
+    }
+}
+
+#[test]
+fn rotate_start_to_anchor() {
+    let square = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0))
+        .line_to(Coord2(2.0, 0.0))
+        .line_to(Coord2(2.0, 2.0))
+        .line_to(Coord2(0.0, 2.0))
+        .line_to(Coord2(0.0, 0.0))
+        .build();
+
+    let rotated: SimpleBezierPath = reparameterize_start(&square, 4.0);
+    let points = rotated.points().collect::<Vec<_>>();
+
+    // Starting at an anchor shouldn't add any curves
+    assert!(rotated.num_segments() == 4);
+    assert!(rotated.start_point().distance_to(&Coord2(2.0, 2.0)) < 0.001, "{:?}", rotated.start_point());
+    assert!(points[0].2.distance_to(&Coord2(0.0, 2.0)) < 0.001);
+    assert!(points[1].2.distance_to(&Coord2(0.0, 0.0)) < 0.001);
+    assert!(points[2].2.distance_to(&Coord2(2.0, 0.0)) < 0.001);
+    assert!(points[3].2.distance_to(&Coord2(2.0, 2.0)) < 0.001);
+}
+
+#[test]
+fn rotate_start_wraps_around() {
+    let square = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0))
+        .line_to(Coord2(2.0, 0.0))
+        .line_to(Coord2(2.0, 2.0))
+        .line_to(Coord2(0.0, 2.0))
+        .line_to(Coord2(0.0, 0.0))
+        .build();
+
+    let rotated: SimpleBezierPath = reparameterize_start(&square, 9.0);
+    assert!(rotated.start_point().distance_to(&Coord2(1.0, 0.0)) < 0.001, "{:?}", rotated.start_point());
+
+    let rotated: SimpleBezierPath = reparameterize_start(&square, -1.0);
+    assert!(rotated.start_point().distance_to(&Coord2(0.0, 1.0)) < 0.001, "{:?}", rotated.start_point());
+}
+
+#[test]
+fn half_way_along_symmetric_path() {
+    // Symmetric path with a short curve in the middle (so the mid-point isn't at t=0.5 if parameterised by curve index)
+    let path = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0))
+        .line_to(Coord2(4.0, 0.0))
+        .curve_to((Coord2(4.5, 1.0), Coord2(5.5, 1.0)), Coord2(6.0, 0.0))
+        .line_to(Coord2(10.0, 0.0))
+        .build();
+
+    let (curve_idx, t)  = path_t_at_fraction(&path, 0.5);
+    let pos             = path.to_curves::<bezier::Curve<_>>()[curve_idx].point_at_pos(t);
+
+    assert!(curve_idx == 1);
+    assert!(pos.distance_to(&Coord2(5.0, 0.75)) < 0.001, "{:?}", pos);
+}
+
+#[test]
+fn fraction_along_line() {
+    let path = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0))
+        .line_to(Coord2(2.0, 0.0))
+        .line_to(Coord2(2.0, 6.0))
+        .build();
+    let curves = path.to_curves::<bezier::Curve<_>>();
+
+    let (curve_idx, t) = path_t_at_fraction(&path, 0.125);
+    assert!(curve_idx == 0);
+    assert!(curves[curve_idx].point_at_pos(t).distance_to(&Coord2(1.0, 0.0)) < 0.001);
+
+    let (curve_idx, t) = path_t_at_fraction(&path, 0.5);
+    assert!(curve_idx == 1);
+    assert!(curves[curve_idx].point_at_pos(t).distance_to(&Coord2(2.0, 2.0)) < 0.001);
+}
+
+#[test]
+fn fraction_at_ends_of_path() {
+    let circle: SimpleBezierPath = Circle::new(Coord2(5.0, 5.0), 2.0).to_path();
+
+    assert!(path_t_at_fraction(&circle, 0.0) == (0, 0.0));
+    assert!(path_t_at_fraction(&circle, -1.0) == (0, 0.0));
+    assert!(path_t_at_fraction(&circle, 1.0) == (circle.num_segments()-1, 1.0));
+    assert!(path_t_at_fraction(&circle, 2.0) == (circle.num_segments()-1, 1.0));
+}
+
+#[test]
+fn split_path_mid_segment() {
+    let path                = polygon_to_path::<SimpleBezierPath>(&[Coord2(0.0, 0.0), Coord2(4.0, 0.0), Coord2(4.0, 3.0), Coord2(0.0, 3.0)], false);
+    let (first, second)     = split_path_at_distance(&path, 5.5);
+    let second              = second.unwrap();
+
+    // Split 1.5 units along the second line
+    assert!(first.num_segments() == 2);
+    assert!(second.num_segments() == 2);
+    assert!(first.points().last().unwrap().2.distance_to(&Coord2(4.0, 1.5)) < 1e-6, "{:?}", first);
+    assert!(second.start_point() == first.points().last().unwrap().2);
+
+    assert!((first.approx_length(1e-8) - 5.5).abs() < 1e-6);
+    assert!((second.approx_length(1e-8) - 5.5).abs() < 1e-6);
+
+    // Rejoining the two parts follows the original path
+    let rejoined        = SimpleBezierPath::from_points(first.start_point(), first.points().chain(second.points()));
+    let rejoined_curves = rejoined.to_curves::<bezier::Curve<_>>();
+
+    for t in 0..=10 {
+        let t = (t as f64) / 10.0;
+        assert!(path_signed_distance(&[path.clone()], &rejoined_curves[1].point_at_pos(t)).abs() < 1e-6);
+        assert!(path_signed_distance(&[path.clone()], &rejoined_curves[2].point_at_pos(t)).abs() < 1e-6);
+    }
+}
+
+#[test]
+fn split_curved_path_rejoins() {
+    let circle          = Circle::new(Coord2(5.0, 5.0), 4.0).to_path::<SimpleBezierPath>();
+    let length          = circle.approx_length(1e-8);
+    let (first, second) = split_path_at_distance(&circle, length * 0.4);
+    let second          = second.unwrap();
+
+    assert!(first.num_segments() + second.num_segments() == circle.num_segments() + 1);
+    assert!((first.approx_length(1e-8) + second.approx_length(1e-8) - length).abs() < 1e-6);
+    assert!((first.approx_length(1e-8) - length * 0.4).abs() < 1e-6);
+    assert!(second.points().last().unwrap().2 == circle.points().last().unwrap().2);
+}
+
+#[test]
+fn split_path_out_of_range() {
+    let path = polygon_to_path::<SimpleBezierPath>(&[Coord2(0.0, 0.0), Coord2(4.0, 0.0), Coord2(4.0, 3.0)], false);
+
+    let (first, second) = split_path_at_distance(&path, 10.0);
+    assert!(first == path);
+    assert!(second.is_none());
+
+    let (first, second) = split_path_at_distance(&path, -1.0);
+    assert!(first.num_segments() == 0 && first.start_point() == Coord2(0.0, 0.0));
+    assert!(second == Some(path.clone()));
+
+    // Splitting at an anchor point doesn't create any new curves
+    let (first, second) = split_path_at_distance(&path, 4.0);
+    assert!(first.num_segments() == 1);
+    assert!(second.unwrap().num_segments() == 1);
+}
+
+#[test]
+fn dash_straight_line() {
+    let line    = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0)).line_to(Coord2(100.0, 0.0)).build();
+    let dashes  = dash_path::<_, SimpleBezierPath>(&line, &[10.0, 5.0], 0.0);
+
+    // Dashes start every 15 units, and the last one ends exactly at the end of the line
+    assert!(dashes.len() == 7, "{}", dashes.len());
+
+    for (idx, dash) in dashes.iter().enumerate() {
+        let start = 15.0 * (idx as f64);
+
+        assert!(dash.start_point().distance_to(&Coord2(start, 0.0)) < 0.001, "{:?}", dash.start_point());
+        assert!((dash.approx_length(1e-6) - 10.0).abs() < 0.001, "{}", dash.approx_length(1e-6));
+    }
+}
+
+#[test]
+fn dash_straight_line_with_phase() {
+    let line    = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0)).line_to(Coord2(100.0, 0.0)).build();
+    let dashes  = dash_path::<_, SimpleBezierPath>(&line, &[10.0, 5.0], 5.0);
+    let lengths = dashes.iter().map(|dash| dash.approx_length(1e-6)).collect::<Vec<_>>();
+
+    // The line starts half way through the first dash, and ends half way through the last one
+    assert!(dashes.len() == 7, "{:?}", lengths);
+    assert!((lengths[0] - 5.0).abs() < 0.001, "{:?}", lengths);
+    assert!(lengths[1..6].iter().all(|length| (length - 10.0).abs() < 0.001), "{:?}", lengths);
+    assert!((lengths[6] - 10.0).abs() < 0.001, "{:?}", lengths);
+    assert!(dashes[1].start_point().distance_to(&Coord2(10.0, 0.0)) < 0.001);
+}
+
+#[test]
+fn dash_continues_around_corners() {
+    let corner  = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0)).line_to(Coord2(12.0, 0.0)).line_to(Coord2(12.0, 12.0)).build();
+    let dashes  = dash_path::<_, SimpleBezierPath>(&corner, &[8.0, 2.0], 0.0);
+    let lengths = dashes.iter().map(|dash| dash.approx_length(1e-6)).collect::<Vec<_>>();
+
+    // The second dash goes around the corner, so it's made up of two curves
+    assert!(dashes.len() == 3, "{:?}", lengths);
+    assert!(dashes[1].points().count() == 2);
+    assert!((lengths[1] - 8.0).abs() < 0.001, "{:?}", lengths);
+    assert!((lengths[2] - 4.0).abs() < 0.001, "{:?}", lengths);
+}
+
+#[test]
+fn dash_curve_by_arc_length() {
+    let curve   = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(10.0, 100.0))
+        .curve_to((Coord2(90.0, 30.0), Coord2(40.0, 140.0)), Coord2(220.0, 220.0))
+        .build();
+    let dashes  = dash_path::<_, SimpleBezierPath>(&curve, &[7.0], 0.0);
+    let lengths = dashes.iter().map(|dash| dash.approx_length(1e-6)).collect::<Vec<_>>();
+
+    // Every dash except the last should have the same length along the curve
+    assert!(lengths[..lengths.len()-1].iter().all(|length| (length - 7.0).abs() < 0.01), "{:?}", lengths);
+}
+
+#[test]
+fn empty_dash_pattern_is_solid() {
+    let line    = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0)).line_to(Coord2(100.0, 0.0)).build();
+    let dashes  = dash_path::<_, SimpleBezierPath>(&line, &[], 0.0);
+
+    assert!(dashes.len() == 1);
+    assert!(dashes[0] == line);
+}
@@ -0,0 +1,169 @@
+use flo_curves::*;
+use flo_curves::arc::*;
+use flo_curves::bezier::path::*;
+
+use std::f64;
+
+#[test]
+fn area_of_square() {
+    let square = polygon_to_path::<SimpleBezierPath>(&[Coord2(1.0, 1.0), Coord2(5.0, 1.0), Coord2(5.0, 5.0), Coord2(1.0, 5.0)], true);
+
+    assert!((path_area(&square) - 16.0).abs() < 0.0001, "{}", path_area(&square));
+}
+
+#[test]
+fn area_of_clockwise_square_is_negative() {
+    let square = polygon_to_path::<SimpleBezierPath>(&[Coord2(1.0, 1.0), Coord2(1.0, 5.0), Coord2(5.0, 5.0), Coord2(5.0, 1.0)], true);
+
+    assert!(square.is_clockwise());
+    assert!((path_area(&square) + 16.0).abs() < 0.0001, "{}", path_area(&square));
+}
+
+#[test]
+fn area_of_circle() {
+    let circle  = Circle::new(Coord2(5.0, 5.0), 4.0).to_path::<SimpleBezierPath>();
+    let area    = path_area(&circle);
+
+    // The circle is only approximated by bezier curves, so the area isn't exact
+    assert!(circle.is_clockwise());
+    assert!((area.abs() - f64::consts::PI*16.0).abs() < 0.05, "{}", area);
+    assert!(area < 0.0);
+}
+
+#[test]
+fn area_of_empty_path() {
+    let path = SimpleBezierPath::from_points(Coord2(1.0, 2.0), vec![]);
+
+    assert!(path_area(&path) == 0.0);
+}
+
+#[test]
+fn area_of_open_path_includes_closing_line() {
+    let open    = polygon_to_path::<SimpleBezierPath>(&[Coord2(1.0, 1.0), Coord2(5.0, 1.0), Coord2(5.0, 5.0)], false);
+
+    assert!((path_area(&open) - 8.0).abs() < 0.0001, "{}", path_area(&open));
+}
+
+#[test]
+fn close_pie_slice() {
+    let center  = Coord2(2.0, 3.0);
+    let slice   = BezierPathBuilder::<SimpleBezierPath>::start(center)
+        .arc_to(center, 4.0, 0.0, f64::consts::PI/2.0)
+        .build();
+    let closed  = close_path(&slice);
+
+    assert!(closed.points().count() == slice.points().count() + 1);
+    assert!(closed.points().last().unwrap().2 == center);
+    assert!((path_area(&closed) - f64::consts::PI*4.0).abs() < 0.01, "{}", path_area(&closed));
+}
+
+#[test]
+fn close_already_closed_path() {
+    let square  = polygon_to_path::<SimpleBezierPath>(&[Coord2(1.0, 1.0), Coord2(5.0, 1.0), Coord2(5.0, 5.0), Coord2(1.0, 5.0)], true);
+    let closed  = close_path(&square);
+
+    assert!(closed.points().count() == square.points().count());
+    assert!(closed.points().last().unwrap().2 == Coord2(1.0, 1.0));
+}
+
+#[test]
+fn close_nearly_closed_path() {
+    let square  = polygon_to_path::<SimpleBezierPath>(&[Coord2(1.0, 1.0), Coord2(5.0, 1.0), Coord2(5.0, 5.0), Coord2(1.0, 5.0), Coord2(1.0, 1.0001)], false);
+    let closed  = close_path(&square);
+
+    assert!(closed.points().count() == square.points().count());
+    assert!(closed.points().last().unwrap().2 == Coord2(1.0, 1.0));
+}
+
+#[test]
+fn centroid_of_square() {
+    let square      = polygon_to_path::<SimpleBezierPath>(&[Coord2(1.0, 1.0), Coord2(5.0, 1.0), Coord2(5.0, 5.0), Coord2(1.0, 5.0)], true);
+    let centroid    = path_centroid(&square);
+
+    assert!(centroid.distance_to(&Coord2(3.0, 3.0)) < 0.0001, "{:?}", centroid);
+}
+
+#[test]
+fn centroid_of_circle() {
+    let circle      = Circle::new(Coord2(5.0, 7.0), 4.0).to_path::<SimpleBezierPath>();
+    let centroid    = path_centroid(&circle);
+
+    assert!(centroid.distance_to(&Coord2(5.0, 7.0)) < 0.0001, "{:?}", centroid);
+}
+
+#[test]
+fn centroid_of_triangle() {
+    // Clockwise triangle, with an open path
+    let triangle    = polygon_to_path::<SimpleBezierPath>(&[Coord2(0.0, 0.0), Coord2(0.0, 6.0), Coord2(3.0, 0.0)], false);
+    let centroid    = path_centroid(&triangle);
+
+    assert!(centroid.distance_to(&Coord2(1.0, 2.0)) < 0.0001, "{:?}", centroid);
+}
+
+#[test]
+fn centroid_of_curved_shape() {
+    // Half a circle: the centroid is 4r/3pi from the center of the straight edge
+    let half_circle = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(-4.0, 0.0))
+        .line_to(Coord2(4.0, 0.0))
+        .curve_to((Coord2(4.0, 4.0*0.5522847), Coord2(4.0*0.5522847, 4.0)), Coord2(0.0, 4.0))
+        .curve_to((Coord2(-4.0*0.5522847, 4.0), Coord2(-4.0, 4.0*0.5522847)), Coord2(-4.0, 0.0))
+        .build();
+    let centroid    = path_centroid(&half_circle);
+
+    assert!(centroid.0.abs() < 0.0001, "{:?}", centroid);
+    assert!((centroid.1 - 16.0/(3.0*f64::consts::PI)).abs() < 0.01, "{:?}", centroid);
+}
+
+#[test]
+fn centroid_of_empty_path() {
+    let path = SimpleBezierPath::from_points(Coord2(1.0, 2.0), vec![]);
+
+    assert!(path_centroid(&path) == Coord2(1.0, 2.0));
+}
+
+#[test]
+fn largest_subpath_of_circles() {
+    let small   = Circle::new(Coord2(5.0, 5.0), 1.0).to_path::<SimpleBezierPath>();
+    let big     = Circle::new(Coord2(20.0, 5.0), 10.0).to_path::<SimpleBezierPath>().reversed::<SimpleBezierPath>();
+    let paths   = vec![small.clone(), big.clone(), small];
+
+    assert!(largest_subpath(&paths) == Some(&big));
+}
+
+#[test]
+fn largest_subpath_of_nothing() {
+    let paths: Vec<SimpleBezierPath> = vec![];
+
+    assert!(largest_subpath(&paths).is_none());
+}
+
+#[test]
+fn perimeter_of_square() {
+    let square = polygon_to_path::<SimpleBezierPath>(&[Coord2(1.0, 1.0), Coord2(5.0, 1.0), Coord2(5.0, 5.0), Coord2(1.0, 5.0)], true);
+
+    assert!((path_perimeter(&square, 0.0001) - 16.0).abs() < 0.0001);
+}
+
+#[test]
+fn circle_compactness() {
+    let circle = Circle::new(Coord2(5.0, 5.0), 4.0).to_path::<SimpleBezierPath>();
+
+    assert!((compactness(&circle) - 1.0).abs() < 0.001, "{}", compactness(&circle));
+}
+
+#[test]
+fn thin_rectangle_compactness() {
+    let square      = polygon_to_path::<SimpleBezierPath>(&[Coord2(1.0, 1.0), Coord2(5.0, 1.0), Coord2(5.0, 5.0), Coord2(1.0, 5.0)], true);
+    let rectangle   = polygon_to_path::<SimpleBezierPath>(&[Coord2(0.0, 0.0), Coord2(100.0, 0.0), Coord2(100.0, 1.0), Coord2(0.0, 1.0)], true);
+
+    // A square has a compactness of pi/4
+    assert!((compactness(&square) - f64::consts::PI/4.0).abs() < 0.0001);
+    assert!(compactness(&rectangle) < 0.05, "{}", compactness(&rectangle));
+}
+
+#[test]
+fn empty_path_compactness() {
+    let path = SimpleBezierPath::from_points(Coord2(1.0, 2.0), vec![]);
+
+    assert!(compactness(&path) == 0.0);
+}
@@ -3,6 +3,8 @@ use flo_curves::arc::*;
 use flo_curves::bezier::path::*;
 use flo_curves::debug::*;
 
+use std::f64;
+
 use super::svg::*;
 use super::checks::*;
 
@@ -870,3 +872,74 @@ fn rectangle_add_with_shared_point_6() {
         Coord2(1.0, 1.0),
     ], 0.1));
 }
+
+#[test]
+fn add_overlapping_circles_area() {
+    // The area of the union of two circles can be found from the area of the region where they overlap
+    let circle1     = Circle::new(Coord2(5.0, 5.0), 4.0).to_path::<SimpleBezierPath>();
+    let circle2     = Circle::new(Coord2(12.0, 5.0), 4.0).to_path::<SimpleBezierPath>();
+    let overlap     = circle_overlap_area(4.0, 7.0);
+
+    let result      = path_add::<SimpleBezierPath>(&vec![circle1], &vec![circle2], 0.01);
+    let area        = total_area(&result);
+    let expected    = 2.0*f64::consts::PI*16.0 - overlap;
+
+    assert!((area - expected).abs() < 0.1, "{} {}", area, expected);
+}
+
+#[test]
+fn add_open_path() {
+    // The first rectangle is missing its final edge, so it should be closed with a straight line
+    let open_rectangle      = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(1.0, 1.0))
+        .line_to(Coord2(5.0, 1.0))
+        .line_to(Coord2(5.0, 5.0))
+        .line_to(Coord2(1.0, 5.0))
+        .build();
+    let closed_rectangle    = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(1.0, 1.0))
+        .line_to(Coord2(5.0, 1.0))
+        .line_to(Coord2(5.0, 5.0))
+        .line_to(Coord2(1.0, 5.0))
+        .line_to(Coord2(1.0, 1.0))
+        .build();
+    let rectangle2          = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(3.0, 3.0))
+        .line_to(Coord2(7.0, 3.0))
+        .line_to(Coord2(7.0, 7.0))
+        .line_to(Coord2(3.0, 7.0))
+        .line_to(Coord2(3.0, 3.0))
+        .build();
+
+    let open_result     = path_add::<SimpleBezierPath>(&vec![open_rectangle], &vec![rectangle2.clone()], 0.01);
+    let closed_result   = path_add::<SimpleBezierPath>(&vec![closed_rectangle], &vec![rectangle2], 0.01);
+
+    assert!(open_result.len() == 1);
+    assert!(open_result.iter().all(|path| path.is_closed(0.01)));
+    assert!((total_area(&open_result) - 28.0).abs() < 0.01, "{}", total_area(&open_result));
+    assert!((total_area(&open_result) - total_area(&closed_result)).abs() < 0.01);
+}
+
+#[test]
+fn path_union_is_path_add() {
+    let circle1     = Circle::new(Coord2(5.0, 5.0), 4.0).to_path::<SimpleBezierPath>();
+    let circle2     = Circle::new(Coord2(12.0, 5.0), 4.0).to_path::<SimpleBezierPath>();
+
+    let union       = path_union::<SimpleBezierPath>(&vec![circle1.clone()], &vec![circle2.clone()], 0.01);
+    let add         = path_add::<SimpleBezierPath>(&vec![circle1], &vec![circle2], 0.01);
+
+    assert!(union == add);
+}
+
+#[test]
+fn add_open_path_to_nothing() {
+    // The open path should be closed even when there's nothing to add it to
+    let open_rectangle  = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(1.0, 1.0))
+        .line_to(Coord2(5.0, 1.0))
+        .line_to(Coord2(5.0, 5.0))
+        .line_to(Coord2(1.0, 5.0))
+        .build();
+
+    let result          = path_add::<SimpleBezierPath>(&vec![open_rectangle], &Vec::<SimpleBezierPath>::new(), 0.01);
+
+    assert!(result.len() == 1);
+    assert!(result[0].is_closed(0.0));
+    assert!(result[0].points().count() == 4);
+}
@@ -1,3 +1,5 @@
+use super::checks::*;
+
 use flo_curves::*;
 use flo_curves::arc::*;
 use flo_curves::line::*;
@@ -473,3 +475,17 @@ fn repeatedly_full_intersect_circle_f32_intermediate_representation() {
 
     assert!(remaining.len() == 1);
 }
+
+#[test]
+fn intersect_overlapping_circles_area() {
+    // The area of the intersection of two circles can be found from the area of the region where they overlap
+    let circle1     = Circle::new(Coord2(5.0, 5.0), 4.0).to_path::<SimpleBezierPath>();
+    let circle2     = Circle::new(Coord2(12.0, 5.0), 4.0).to_path::<SimpleBezierPath>();
+    let overlap     = circle_overlap_area(4.0, 7.0);
+
+    let result      = path_intersect::<SimpleBezierPath>(&vec![circle1], &vec![circle2], 0.01);
+    let area        = total_area(&result);
+    let expected    = overlap;
+
+    assert!((area - expected).abs() < 0.1, "{} {}", area, expected);
+}
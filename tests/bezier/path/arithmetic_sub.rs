@@ -5,6 +5,8 @@ use flo_curves::*;
 use flo_curves::arc::*;
 use flo_curves::bezier::path::*;
 
+use std::f64;
+
 #[test]
 fn subtract_circles() {
     // Two overlapping circles
@@ -692,3 +694,28 @@ fn subtract_multiple_partial_overlap() {
         }
     }
 }
+
+#[test]
+fn subtract_overlapping_circles_area() {
+    // The area of the difference of two circles can be found from the area of the region where they overlap
+    let circle1     = Circle::new(Coord2(5.0, 5.0), 4.0).to_path::<SimpleBezierPath>();
+    let circle2     = Circle::new(Coord2(12.0, 5.0), 4.0).to_path::<SimpleBezierPath>();
+    let overlap     = circle_overlap_area(4.0, 7.0);
+
+    let result      = path_sub::<SimpleBezierPath>(&vec![circle1], &vec![circle2], 0.01);
+    let area        = total_area(&result);
+    let expected    = f64::consts::PI*16.0 - overlap;
+
+    assert!((area - expected).abs() < 0.1, "{} {}", area, expected);
+}
+
+#[test]
+fn path_difference_is_path_sub() {
+    let circle1     = Circle::new(Coord2(5.0, 5.0), 4.0).to_path::<SimpleBezierPath>();
+    let circle2     = Circle::new(Coord2(12.0, 5.0), 4.0).to_path::<SimpleBezierPath>();
+
+    let difference  = path_difference::<SimpleBezierPath>(&vec![circle1.clone()], &vec![circle2.clone()], 0.01);
+    let sub         = path_sub::<SimpleBezierPath>(&vec![circle1], &vec![circle2], 0.01);
+
+    assert!(difference == sub);
+}
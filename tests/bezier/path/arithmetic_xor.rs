@@ -0,0 +1,78 @@
+use super::checks::*;
+
+use flo_curves::*;
+use flo_curves::arc::*;
+use flo_curves::bezier::path::*;
+
+use std::f64;
+
+#[test]
+fn xor_overlapping_circles() {
+    let circle1 = Circle::new(Coord2(5.0, 5.0), 4.0).to_path::<SimpleBezierPath>();
+    let circle2 = Circle::new(Coord2(8.0, 5.0), 4.0).to_path::<SimpleBezierPath>();
+
+    let xored   = path_xor::<SimpleBezierPath>(&vec![circle1], &vec![circle2], 0.01);
+    let inside  = |point: Coord2| xored.iter().filter(|path| path_contains_point(*path, &point)).count() % 2 == 1;
+
+    assert!(xored.len() == 2, "{:?}", xored.len());
+
+    // Points in only one of the circles are in the result
+    assert!(inside(Coord2(2.0, 5.0)));
+    assert!(inside(Coord2(11.0, 5.0)));
+
+    // Points in both circles or neither circle are not
+    assert!(!inside(Coord2(6.5, 5.0)));
+    assert!(!inside(Coord2(20.0, 5.0)));
+}
+
+#[test]
+fn xor_with_empty_path() {
+    let circle  = Circle::new(Coord2(5.0, 5.0), 4.0).to_path::<SimpleBezierPath>();
+    let xored   = path_xor::<SimpleBezierPath>(&vec![circle.clone()], &Vec::<SimpleBezierPath>::new(), 0.01);
+
+    assert!(xored.len() == 1);
+    assert!(xored[0] == circle);
+}
+
+#[test]
+fn difference_between_slightly_different_circles() {
+    let before  = Circle::new(Coord2(5.0, 5.0), 4.0).to_path::<SimpleBezierPath>();
+    let after   = Circle::new(Coord2(5.5, 5.0), 4.0).to_path::<SimpleBezierPath>();
+
+    let changed = path_difference_outline(&[before], &[after], 0.01);
+    let inside  = |point: Coord2| changed.iter().filter(|path| path_contains_point(*path, &point)).count() % 2 == 1;
+
+    // Two thin crescents on either side
+    assert!(changed.len() == 2, "{:?}", changed.len());
+    assert!(inside(Coord2(1.25, 5.0)));
+    assert!(inside(Coord2(9.25, 5.0)));
+    assert!(!inside(Coord2(5.0, 5.0)));
+}
+
+#[test]
+fn difference_between_identical_shapes_is_empty() {
+    let circle  = Circle::new(Coord2(5.0, 5.0), 4.0).to_path::<SimpleBezierPath>();
+    let changed = path_difference_outline(&[circle.clone()], &[circle], 0.01);
+
+    assert!(changed.is_empty());
+}
+
+#[test]
+fn xor_overlapping_circles_area() {
+    // The area of the xor of two circles can be found from the area of the region where they overlap
+    let circle1     = Circle::new(Coord2(50.0, 50.0), 40.0).to_path::<SimpleBezierPath>();
+    let circle2     = Circle::new(Coord2(120.0, 50.0), 40.0).to_path::<SimpleBezierPath>();
+    let overlap     = circle_overlap_area(40.0, 70.0);
+
+    let result      = path_xor::<SimpleBezierPath>(&vec![circle1], &vec![circle2], 0.01);
+
+    // The overlapping region is covered by both of the result paths, so measure the area using the even-odd rule
+    let mut area    = 0.0;
+    rasterize_scanlines(&result, (200, 100), FillRule::EvenOdd, |_y, ranges| {
+        area += ranges.iter().map(|range| range.end - range.start).sum::<f64>();
+    });
+
+    let expected    = 2.0*f64::consts::PI*1600.0 - 2.0*overlap;
+
+    assert!((area - expected).abs() < expected * 0.001, "{} {}", area, expected);
+}
@@ -31,3 +31,86 @@ fn circle_path_fast_bounds() {
     assert!(bounds.1.x() >= 9.0);
     assert!(bounds.1.y() >= 9.0);
 }
+
+#[test]
+fn empty_path_bounds() {
+    // A path with no curves is just its start point
+    let path: SimpleBezierPath = (Coord2(3.0, 4.0), vec![]);
+
+    let bounds: (Coord2, Coord2)        = path.bounding_box();
+    let fast_bounds: (Coord2, Coord2)   = path.fast_bounding_box();
+
+    assert!(bounds == (Coord2(3.0, 4.0), Coord2(3.0, 4.0)));
+    assert!(fast_bounds == (Coord2(3.0, 4.0), Coord2(3.0, 4.0)));
+}
+
+#[test]
+fn bounds_for_no_points() {
+    let bounds = Bounds::<Coord2>::bounds_for_points(Vec::<Coord2>::new());
+
+    assert!(bounds.min() == Coord2(0.0, 0.0));
+    assert!(bounds.max() == Coord2(0.0, 0.0));
+}
+
+#[test]
+fn empty_path_does_not_contain_points() {
+    let path: SimpleBezierPath = (Coord2(3.0, 4.0), vec![]);
+
+    assert!(!path_contains_point(&path, &Coord2(3.0, 4.0)));
+    assert!(!path_contains_point(&path, &Coord2(0.0, 0.0)));
+}
+
+#[test]
+fn circle_offset_bounds() {
+    let circle: SimpleBezierPath    = Circle::new(Coord2(5.0, 5.0), 4.0).to_path();
+
+    let tight: (Coord2, Coord2)     = circle.bounding_box();
+    let offset: (Coord2, Coord2)    = offset_bounds(&circle, 2.0);
+
+    assert!(offset.0.distance_to(&(tight.0 - Coord2(2.0, 2.0))) < 0.0001);
+    assert!(offset.1.distance_to(&(tight.1 + Coord2(2.0, 2.0))) < 0.0001);
+    assert!(offset.0.distance_to(&Coord2(-1.0, -1.0)) < 0.1);
+    assert!(offset.1.distance_to(&Coord2(11.0, 11.0)) < 0.1);
+}
+
+#[test]
+fn negative_offset_bounds_contain_path() {
+    // The bounds are padded outwards whatever the sign of the offset
+    let circle: SimpleBezierPath    = Circle::new(Coord2(5.0, 5.0), 4.0).to_path();
+    let offset: (Coord2, Coord2)    = offset_bounds(&circle, -2.0);
+
+    assert!(offset.0.distance_to(&Coord2(-1.0, -1.0)) < 0.1);
+    assert!(offset.1.distance_to(&Coord2(11.0, 11.0)) < 0.1);
+}
+
+#[test]
+fn morph_bounds_contain_both_paths() {
+    let circle: SimpleBezierPath    = Circle::new(Coord2(5.0, 5.0), 4.0).to_path();
+    let rectangle                   = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(8.0, 3.0))
+        .line_to(Coord2(20.0, 3.0))
+        .line_to(Coord2(20.0, 6.0))
+        .line_to(Coord2(8.0, 6.0))
+        .line_to(Coord2(8.0, 3.0))
+        .build();
+
+    let bounds: (Coord2, Coord2)            = morph_bounds(&circle, &rectangle);
+    let circle_bounds: (Coord2, Coord2)     = circle.bounding_box();
+    let rectangle_bounds: (Coord2, Coord2)  = rectangle.bounding_box();
+
+    for (min, max) in vec![circle_bounds, rectangle_bounds] {
+        assert!(bounds.0.x() <= min.x() && bounds.0.y() <= min.y());
+        assert!(bounds.1.x() >= max.x() && bounds.1.y() >= max.y());
+    }
+
+    assert!(bounds.0.distance_to(&Coord2(1.0, 1.0)) < 0.1);
+    assert!(bounds.1.distance_to(&Coord2(20.0, 9.0)) < 0.1);
+
+    // A frame halfway through the morph should also be inside the bounds
+    let halfway: SimpleBezierPath           = (circle.start_point()*0.5 + rectangle.start_point()*0.5, circle.points().zip(rectangle.points())
+        .map(|((cp1a, cp2a, enda), (cp1b, cp2b, endb))| (cp1a*0.5 + cp1b*0.5, cp2a*0.5 + cp2b*0.5, enda*0.5 + endb*0.5))
+        .collect());
+    let halfway_bounds: (Coord2, Coord2)    = halfway.bounding_box();
+
+    assert!(bounds.0.x() <= halfway_bounds.0.x() && bounds.0.y() <= halfway_bounds.0.y());
+    assert!(bounds.1.x() >= halfway_bounds.1.x() && bounds.1.y() >= halfway_bounds.1.y());
+}
@@ -1,6 +1,8 @@
 use flo_curves::bezier::*;
 use flo_curves::bezier::path::*;
 
+use std::f64;
+
 ///
 /// Returns true if the end points in the path match the expected end points (in any sequence, forwards or backwards)
 ///
@@ -175,6 +177,20 @@ where
     true
 }
 
+///
+/// The area of the region where two circles with the same radius overlap
+///
+pub fn circle_overlap_area(radius: f64, distance: f64) -> f64 {
+    2.0*radius*radius*f64::acos(distance/(2.0*radius)) - (distance/2.0)*f64::sqrt(4.0*radius*radius - distance*distance)
+}
+
+///
+/// The total area of a set of paths (holes are subtracted if they go in the opposite direction)
+///
+pub fn total_area<P: BezierPath<Point=Coord2>>(paths: &[P]) -> f64 {
+    paths.iter().map(|path| path_area(path)).sum::<f64>().abs()
+}
+
 #[test]
 fn check_end_points_forward_no_offset() {
     let rectangle1 = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(1.0, 1.0))
@@ -0,0 +1,67 @@
+use flo_curves::*;
+use flo_curves::arc::*;
+use flo_curves::bezier::*;
+use flo_curves::bezier::path::*;
+
+#[test]
+fn clip_line_to_circle() {
+    // Line through the middle of a circle
+    let circle  = Circle::new(Coord2(5.0, 5.0), 4.0).to_path::<SimpleBezierPath>();
+    let line    = Curve::from_points(Coord2(0.0, 5.0), (Coord2(3.0, 5.0), Coord2(7.0, 5.0)), Coord2(10.0, 5.0));
+    let clipped = curve_clipped_to_path(&line, &[circle], FillRule::NonZero);
+
+    assert!(clipped.len() == 1, "{:?}", clipped);
+    assert!(clipped[0].start_point().distance_to(&Coord2(1.0, 5.0)) < 0.01, "{:?}", clipped);
+    assert!(clipped[0].end_point().distance_to(&Coord2(9.0, 5.0)) < 0.01, "{:?}", clipped);
+
+    // Every point on the clipped curve should be inside the circle
+    for t in 0..=10 {
+        let point = clipped[0].point_at_pos((t as f64) / 10.0);
+        assert!(point.distance_to(&Coord2(5.0, 5.0)) <= 4.01);
+    }
+}
+
+#[test]
+fn clip_curve_leaving_and_entering_circle() {
+    // Curve that dips below the circle in the middle, so it's inside the circle twice
+    let circle  = Circle::new(Coord2(5.0, 5.0), 4.0).to_path::<SimpleBezierPath>();
+    let curve   = Curve::from_points(Coord2(2.0, 5.0), (Coord2(3.0, -5.0), Coord2(7.0, -5.0)), Coord2(8.0, 5.0));
+    let clipped = curve_clipped_to_path(&curve, &[circle], FillRule::NonZero);
+
+    assert!(clipped.len() == 2, "{:?}", clipped);
+    assert!(clipped[0].start_point() == Coord2(2.0, 5.0));
+    assert!(clipped[1].end_point() == Coord2(8.0, 5.0));
+
+    for section in clipped.iter() {
+        for t in 1..10 {
+            let point = section.point_at_pos((t as f64) / 10.0);
+            assert!(point.distance_to(&Coord2(5.0, 5.0)) <= 4.01);
+        }
+    }
+}
+
+#[test]
+fn clip_to_region_with_hole() {
+    // Region is a ring, so the even-odd rule removes the middle section of the line
+    let outer   = Circle::new(Coord2(5.0, 5.0), 4.0).to_path::<SimpleBezierPath>();
+    let inner   = Circle::new(Coord2(5.0, 5.0), 2.0).to_path::<SimpleBezierPath>();
+    let line    = Curve::from_points(Coord2(0.0, 5.0), (Coord2(3.0, 5.0), Coord2(7.0, 5.0)), Coord2(10.0, 5.0));
+
+    let even_odd    = curve_clipped_to_path(&line, &[outer.clone(), inner.clone()], FillRule::EvenOdd);
+    let non_zero    = curve_clipped_to_path(&line, &[outer, inner], FillRule::NonZero);
+
+    assert!(even_odd.len() == 2, "{:?}", even_odd);
+    assert!(even_odd[0].end_point().distance_to(&Coord2(3.0, 5.0)) < 0.01, "{:?}", even_odd);
+    assert!(even_odd[1].start_point().distance_to(&Coord2(7.0, 5.0)) < 0.01, "{:?}", even_odd);
+
+    // Both circles go in the same direction, so the middle is filled with the non-zero rule
+    assert!(non_zero.len() == 1, "{:?}", non_zero);
+}
+
+#[test]
+fn clip_curve_outside_region() {
+    let circle  = Circle::new(Coord2(5.0, 5.0), 4.0).to_path::<SimpleBezierPath>();
+    let line    = Curve::from_points(Coord2(20.0, 5.0), (Coord2(23.0, 5.0), Coord2(27.0, 5.0)), Coord2(30.0, 5.0));
+
+    assert!(curve_clipped_to_path(&line, &[circle], FillRule::NonZero).is_empty());
+}
@@ -0,0 +1,75 @@
+use flo_curves::*;
+use flo_curves::arc::*;
+use flo_curves::bezier::*;
+use flo_curves::bezier::path::*;
+
+#[test]
+fn smooth_path_with_one_corner() {
+    // Path is smooth everywhere except where it closes at the origin
+    let path: SimpleBezierPath = (Coord2(0.0, 0.0), vec![
+        (Coord2(1.0, 0.0), Coord2(2.0, 0.0), Coord2(3.0, 0.0)),
+        (Coord2(4.0, 0.0), Coord2(5.0, 1.0), Coord2(5.0, 2.0)),
+        (Coord2(5.0, 3.0), Coord2(4.0, 4.0), Coord2(3.0, 4.0)),
+        (Coord2(1.0, 4.0), Coord2(0.0, 4.0), Coord2(0.0, 3.0)),
+        (Coord2(0.0, 2.0), Coord2(0.0, 1.0), Coord2(0.0, 0.0)),
+    ]);
+
+    let report = continuity_report(&path, 0.001);
+
+    assert!(report == vec![Continuity::C1, Continuity::C1, Continuity::G1, Continuity::C1, Continuity::C0], "{:?}", report);
+}
+
+#[test]
+fn open_path_has_no_wraparound_join() {
+    let path = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0))
+        .line_to(Coord2(3.0, 0.0))
+        .line_to(Coord2(3.0, 3.0))
+        .line_to(Coord2(0.0, 3.0))
+        .build();
+
+    let report = continuity_report(&path, 0.001);
+
+    assert!(report == vec![Continuity::C0, Continuity::C0], "{:?}", report);
+}
+
+#[test]
+fn circle_is_smooth() {
+    let circle: SimpleBezierPath    = Circle::new(Coord2(5.0, 5.0), 2.0).to_path();
+    let report                      = continuity_report(&circle, 0.01);
+
+    assert!(report.len() == circle.num_segments());
+    assert!(report.iter().all(|continuity| *continuity >= Continuity::G1), "{:?}", report);
+}
+
+#[test]
+fn open_path_end_directions() {
+    // An open 'U' shape: down from (1, 5), across to (5, 1), and up to (5, 5)
+    let path = polygon_to_path::<SimpleBezierPath>(&[Coord2(1.0, 5.0), Coord2(1.0, 1.0), Coord2(5.0, 1.0), Coord2(5.0, 5.0)], false);
+
+    // Both directions point away from the path, so arrowheads at each end point upwards
+    assert!(path_start_direction(&path).distance_to(&Coord2(0.0, 1.0)) < 1e-9);
+    assert!(path_end_direction(&path).distance_to(&Coord2(0.0, 1.0)) < 1e-9);
+}
+
+#[test]
+fn curved_path_end_directions_are_unit_vectors() {
+    let path = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(1.0, 1.0))
+        .curve_to((Coord2(2.0, 2.0), Coord2(3.0, 3.0)), Coord2(5.0, 1.0))
+        .curve_to((Coord2(6.0, 0.0), Coord2(8.0, 0.0)), Coord2(9.0, 0.0))
+        .build();
+
+    let start   = path_start_direction(&path);
+    let end     = path_end_direction(&path);
+
+    assert!((start.magnitude() - 1.0).abs() < 1e-9);
+    assert!(start.distance_to(&(Coord2(-1.0, -1.0).to_unit_vector())) < 1e-9);
+    assert!(end.distance_to(&Coord2(1.0, 0.0)) < 1e-9);
+}
+
+#[test]
+fn zero_length_end_curves_are_skipped() {
+    let path = polygon_to_path::<SimpleBezierPath>(&[Coord2(1.0, 1.0), Coord2(1.0, 1.0), Coord2(4.0, 1.0), Coord2(4.0, 1.0)], false);
+
+    assert!(path_start_direction(&path).distance_to(&Coord2(-1.0, 0.0)) < 1e-9);
+    assert!(path_end_direction(&path).distance_to(&Coord2(1.0, 0.0)) < 1e-9);
+}
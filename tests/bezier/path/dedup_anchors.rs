@@ -0,0 +1,58 @@
+use flo_curves::*;
+use flo_curves::bezier::path::*;
+
+#[test]
+fn remove_duplicate_anchor() {
+    let path: SimpleBezierPath = (Coord2(0.0, 0.0), vec![
+        (Coord2(3.0, 0.0), Coord2(7.0, 0.0), Coord2(10.0, 0.0)),
+        (Coord2(10.0, 0.0), Coord2(10.0, 0.0), Coord2(10.0, 0.0)),
+        (Coord2(10.0, 3.0), Coord2(10.0, 7.0), Coord2(10.0, 10.0)),
+    ]);
+
+    let deduped = dedup_anchors(&path, 0.001);
+
+    assert!(deduped.points().count() == 2);
+    assert!(deduped.points().collect::<Vec<_>>() == vec![
+        (Coord2(3.0, 0.0), Coord2(7.0, 0.0), Coord2(10.0, 0.0)),
+        (Coord2(10.0, 3.0), Coord2(10.0, 7.0), Coord2(10.0, 10.0)),
+    ]);
+    assert!(paths_approx_equal(&path, &deduped, 0.001));
+}
+
+#[test]
+fn remove_nearly_duplicate_anchors() {
+    let path: SimpleBezierPath = (Coord2(0.0, 0.0), vec![
+        (Coord2(0.0, 0.0), Coord2(0.0005, 0.0), Coord2(0.0005, 0.0)),
+        (Coord2(3.0, 0.0), Coord2(7.0, 0.0), Coord2(10.0, 0.0)),
+        (Coord2(10.0, 3.0), Coord2(10.0, 7.0), Coord2(10.0, 10.0)),
+        (Coord2(10.0, 10.0), Coord2(10.0, 10.0), Coord2(10.0, 10.0)),
+    ]);
+
+    let deduped = dedup_anchors(&path, 0.001);
+
+    assert!(deduped.start_point() == Coord2(0.0, 0.0));
+    assert!(deduped.points().count() == 2);
+    assert!(paths_approx_equal(&path, &deduped, 0.01));
+}
+
+#[test]
+fn small_loops_are_not_duplicates() {
+    // The second curve starts and ends at the same point but isn't a point
+    let path: SimpleBezierPath = (Coord2(0.0, 0.0), vec![
+        (Coord2(3.0, 0.0), Coord2(7.0, 0.0), Coord2(10.0, 0.0)),
+        (Coord2(12.0, 2.0), Coord2(8.0, 2.0), Coord2(10.0, 0.0)),
+    ]);
+
+    let deduped = dedup_anchors(&path, 0.001);
+
+    assert!(deduped == path);
+}
+
+#[test]
+fn closed_path_keeps_closing_anchor() {
+    let square  = polygon_to_path::<SimpleBezierPath>(&[Coord2(0.0, 0.0), Coord2(10.0, 0.0), Coord2(10.0, 10.0), Coord2(0.0, 10.0)], true);
+    let deduped = dedup_anchors(&square, 0.001);
+
+    assert!(deduped == square);
+    assert!(deduped.is_closed(0.001));
+}
@@ -0,0 +1,36 @@
+use flo_curves::*;
+use flo_curves::arc::*;
+use flo_curves::bezier::path::*;
+
+#[test]
+fn enclosing_circle_for_circle() {
+    let circle              = Circle::new(Coord2(5.0, 3.0), 4.0).to_path::<SimpleBezierPath>();
+    let (center, radius)    = minimum_enclosing_circle(&circle);
+
+    assert!(center.distance_to(&Coord2(5.0, 3.0)) < 0.01, "{:?}", center);
+    assert!((radius - 4.0).abs() < 0.01, "{:?}", radius);
+}
+
+#[test]
+fn enclosing_circle_for_rectangle() {
+    let rectangle = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(1.0, 1.0))
+        .line_to(Coord2(7.0, 1.0))
+        .line_to(Coord2(7.0, 9.0))
+        .line_to(Coord2(1.0, 9.0))
+        .line_to(Coord2(1.0, 1.0))
+        .build();
+    let (center, radius)    = minimum_enclosing_circle(&rectangle);
+
+    assert!(center.distance_to(&Coord2(4.0, 5.0)) < 0.01, "{:?}", center);
+    assert!((radius - 5.0).abs() < 0.01, "{:?}", radius);
+}
+
+#[test]
+fn enclosing_circle_for_triangle_points() {
+    // Obtuse triangle: the circle is defined by the longest edge
+    let points              = vec![Coord2(0.0, 0.0), Coord2(10.0, 0.0), Coord2(5.0, 1.0)];
+    let (center, radius)    = minimum_enclosing_circle_for_points(&points);
+
+    assert!(center.distance_to(&Coord2(5.0, 0.0)) < 0.001, "{:?}", center);
+    assert!((radius - 5.0).abs() < 0.001, "{:?}", radius);
+}
@@ -0,0 +1,80 @@
+use flo_curves::*;
+use flo_curves::bezier::path::*;
+
+use std::f64;
+
+fn pentagram() -> SimpleBezierPath {
+    // Five-pointed star drawn with a single self-intersecting line, which winds around the center twice
+    let points = (0..5).map(|idx| {
+        let angle = f64::consts::PI/2.0 + (idx as f64) * 4.0 * f64::consts::PI / 5.0;
+        Coord2(5.0 + 4.0*angle.cos(), 5.0 + 4.0*angle.sin())
+    }).collect::<Vec<_>>();
+
+    polygon_to_path(&points, true)
+}
+
+fn figure_eight() -> SimpleBezierPath {
+    // Two triangles that cross over at (5, 5)
+    polygon_to_path(&[Coord2(1.0, 3.0), Coord2(9.0, 7.0), Coord2(9.0, 3.0), Coord2(1.0, 7.0)], true)
+}
+
+fn is_filled(paths: &[SimpleBezierPath], point: Coord2) -> bool {
+    paths.iter().filter(|path| path_contains_point(*path, &point)).count() % 2 == 1
+}
+
+#[test]
+fn figure_eight_non_zero() {
+    let regions = resolve_fill_regions(&[figure_eight()], FillRule::NonZero, 0.01);
+
+    assert!(regions.len() == 2, "{:?}", regions.len());
+    assert!(is_filled(&regions, Coord2(2.0, 5.0)));
+    assert!(is_filled(&regions, Coord2(8.0, 5.0)));
+    assert!(!is_filled(&regions, Coord2(5.0, 6.5)));
+}
+
+#[test]
+fn figure_eight_even_odd() {
+    let regions = resolve_fill_regions(&[figure_eight()], FillRule::EvenOdd, 0.01);
+
+    assert!(regions.len() == 2, "{:?}", regions.len());
+    assert!(is_filled(&regions, Coord2(2.0, 5.0)));
+    assert!(is_filled(&regions, Coord2(8.0, 5.0)));
+    assert!(!is_filled(&regions, Coord2(5.0, 6.5)));
+}
+
+#[test]
+fn pentagram_non_zero_fills_center() {
+    let regions = resolve_fill_regions(&[pentagram()], FillRule::NonZero, 0.01);
+
+    assert!(is_filled(&regions, Coord2(5.0, 5.0)));
+    assert!(is_filled(&regions, Coord2(5.0, 8.5)));
+}
+
+#[test]
+fn pentagram_even_odd_leaves_hole_in_center() {
+    let regions = resolve_fill_regions(&[pentagram()], FillRule::EvenOdd, 0.01);
+
+    assert!(!is_filled(&regions, Coord2(5.0, 5.0)));
+    assert!(is_filled(&regions, Coord2(5.0, 8.5)));
+}
+
+#[test]
+fn count_regions_in_simple_path() {
+    let square = polygon_to_path::<SimpleBezierPath>(&[Coord2(1.0, 1.0), Coord2(1.0, 5.0), Coord2(5.0, 5.0), Coord2(5.0, 1.0)], true);
+
+    assert!(count_overlap_regions(&square, FillRule::NonZero, 0.01) == 1);
+    assert!(count_overlap_regions(&square, FillRule::EvenOdd, 0.01) == 1);
+}
+
+#[test]
+fn count_regions_in_figure_eight() {
+    assert!(count_overlap_regions(&figure_eight(), FillRule::NonZero, 0.01) == 2);
+    assert!(count_overlap_regions(&figure_eight(), FillRule::EvenOdd, 0.01) == 2);
+}
+
+#[test]
+fn count_regions_in_pentagram() {
+    // The even-odd rule leaves a hole in the center, which separates the 5 points of the star from each other
+    assert!(count_overlap_regions(&pentagram(), FillRule::NonZero, 0.01) == 1);
+    assert!(count_overlap_regions(&pentagram(), FillRule::EvenOdd, 0.01) == 5);
+}
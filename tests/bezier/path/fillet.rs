@@ -0,0 +1,91 @@
+use flo_curves::*;
+use flo_curves::arc::*;
+use flo_curves::bezier::*;
+use flo_curves::bezier::path::*;
+
+fn square() -> SimpleBezierPath {
+    BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0))
+        .line_to(Coord2(10.0, 0.0))
+        .line_to(Coord2(10.0, 10.0))
+        .line_to(Coord2(0.0, 10.0))
+        .line_to(Coord2(0.0, 0.0))
+        .build()
+}
+
+#[test]
+fn fillet_square_corners() {
+    let square      = square();
+    let filleted    = fillet_corners(&square, 2.0);
+    let corners     = vec![Coord2(0.0, 0.0), Coord2(10.0, 0.0), Coord2(10.0, 10.0), Coord2(0.0, 10.0)];
+
+    // One arc is added at each of the 4 corners
+    assert!(filleted.points().count() == 8);
+    assert!(filleted.is_closed(0.001));
+
+    for curve in path_to_curves::<_, Curve<_>>(&filleted) {
+        for t in 0..=10 {
+            let point = curve.point_at_pos((t as f64) / 10.0);
+
+            // Every point should still be inside the square
+            assert!(point.x() >= -0.001 && point.x() <= 10.001 && point.y() >= -0.001 && point.y() <= 10.001);
+
+            // Points near a corner should be within the radius of it
+            let nearest = corners.iter().map(|corner| corner.distance_to(&point)).fold(f64::MAX, f64::min);
+            if nearest < 2.0 {
+                assert!(corners.iter().all(|corner| corner.distance_to(&point) >= 2.0 * (2.0f64.sqrt() - 1.0) - 0.01));
+            }
+        }
+    }
+
+    // The arcs should be circular
+    for curve in path_to_curves::<_, Curve<_>>(&filleted).skip(1).step_by(2) {
+        let midpoint    = curve.point_at_pos(0.5);
+        let corner      = corners.iter().cloned().min_by(|a, b| a.distance_to(&midpoint).partial_cmp(&b.distance_to(&midpoint)).unwrap()).unwrap();
+        let start       = curve.start_point();
+        let end         = curve.end_point();
+        let center      = start + end - corner;
+
+        assert!(corner.distance_to(&start) < 2.001 && corner.distance_to(&start) > 1.999);
+        assert!(corner.distance_to(&end) < 2.001 && corner.distance_to(&end) > 1.999);
+
+        for t in 0..=10 {
+            let distance = curve.point_at_pos((t as f64) / 10.0).distance_to(&center);
+            assert!((distance - 2.0).abs() < 0.01);
+        }
+    }
+}
+
+#[test]
+fn fillet_clamps_large_radius() {
+    let square      = square();
+    let filleted    = fillet_corners(&square, 100.0);
+
+    // The corners are trimmed to the middle of each side, which produces a circle of radius 5
+    for curve in path_to_curves::<_, Curve<_>>(&filleted) {
+        for t in 0..=10 {
+            let distance = curve.point_at_pos((t as f64) / 10.0).distance_to(&Coord2(5.0, 5.0));
+            assert!((distance - 5.0).abs() < 0.01);
+        }
+    }
+}
+
+#[test]
+fn fillet_leaves_smooth_path_unchanged() {
+    let circle      = Circle::new(Coord2(5.0, 5.0), 4.0).to_path::<SimpleBezierPath>();
+    let filleted    = fillet_corners(&circle, 1.0);
+
+    assert!(paths_approx_equal(&circle, &filleted, 0.001));
+}
+
+#[test]
+fn fillet_open_path_keeps_end_points() {
+    let path        = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0))
+        .line_to(Coord2(10.0, 0.0))
+        .line_to(Coord2(10.0, 10.0))
+        .build();
+    let filleted    = fillet_corners(&path, 3.0);
+
+    assert!(filleted.points().count() == 3);
+    assert!(filleted.start_point() == Coord2(0.0, 0.0));
+    assert!(filleted.points().last().unwrap().2 == Coord2(10.0, 10.0));
+}
@@ -135,3 +135,69 @@ fn circle_intersects_circle() {
 
     assert!(intersections.len() == 2);
 }
+
+#[test]
+fn path_intersections_are_sorted_along_first_path() {
+    let circle1: SimpleBezierPath   = Circle::new(Coord2(5.0, 5.0), 4.0).to_path();
+    let square                      = polygon_to_path::<SimpleBezierPath>(&[Coord2(3.0, 3.0), Coord2(7.0, 3.0), Coord2(7.0, 12.0), Coord2(3.0, 12.0)], true);
+
+    for (path1, path2) in [(&circle1, &square), (&square, &circle1)].iter() {
+        let intersections = path_intersects_path(*path1, *path2, 0.01);
+
+        assert!(intersections.len() == 2, "{:?}", intersections);
+        assert!(intersections.iter().zip(intersections.iter().skip(1)).all(|(((idx_a, t_a), _), ((idx_b, t_b), _))| idx_a < idx_b || (idx_a == idx_b && t_a <= t_b)), "{:?}", intersections);
+    }
+}
+
+#[test]
+fn horizontal_guide_through_circle() {
+    let circle = Circle::new(Coord2(5.0, 5.0), 4.0).to_path::<SimpleBezierPath>();
+
+    let points = path_intersections_with_horizontal(&circle, 5.0);
+
+    assert!(points.len() == 2, "{:?}", points);
+    assert!(points[0].distance_to(&Coord2(1.0, 5.0)) < 0.01, "{:?}", points);
+    assert!(points[1].distance_to(&Coord2(9.0, 5.0)) < 0.01, "{:?}", points);
+}
+
+#[test]
+fn vertical_guide_through_circle() {
+    let circle = Circle::new(Coord2(5.0, 5.0), 4.0).to_path::<SimpleBezierPath>();
+
+    let points = path_intersections_with_vertical(&circle, 7.0);
+    let y      = 12.0f64.sqrt();
+
+    assert!(points.len() == 2, "{:?}", points);
+    assert!(points[0].distance_to(&Coord2(7.0, 5.0-y)) < 0.01, "{:?}", points);
+    assert!(points[1].distance_to(&Coord2(7.0, 5.0+y)) < 0.01, "{:?}", points);
+}
+
+#[test]
+fn guide_through_corners() {
+    // Guide passes through the corners of the rectangle, where two curves meet
+    let rectangle = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(1.0, 1.0))
+        .line_to(Coord2(5.0, 1.0))
+        .line_to(Coord2(5.0, 3.0))
+        .line_to(Coord2(1.0, 3.0))
+        .line_to(Coord2(1.0, 1.0))
+        .build();
+
+    let points = path_intersections_with_horizontal(&rectangle, 3.0);
+
+    assert!(points.len() == 2, "{:?}", points);
+    assert!(points[0].distance_to(&Coord2(1.0, 3.0)) < 0.01, "{:?}", points);
+    assert!(points[1].distance_to(&Coord2(5.0, 3.0)) < 0.01, "{:?}", points);
+
+    let points = path_intersections_with_vertical(&rectangle, 3.0);
+
+    assert!(points.len() == 2, "{:?}", points);
+    assert!(points[0].distance_to(&Coord2(3.0, 1.0)) < 0.01, "{:?}", points);
+    assert!(points[1].distance_to(&Coord2(3.0, 3.0)) < 0.01, "{:?}", points);
+}
+
+#[test]
+fn guide_missing_path() {
+    let circle = Circle::new(Coord2(5.0, 5.0), 4.0).to_path::<SimpleBezierPath>();
+
+    assert!(path_intersections_with_horizontal(&circle, 10.0).is_empty());
+}
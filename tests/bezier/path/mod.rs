@@ -1,17 +1,36 @@
 mod svg;
 mod checks;
+mod clip;
+mod offset;
+mod stroke;
+mod sweep;
 mod permute;
 mod to_curves;
 mod point;
 mod path;
+mod path_builder;
+mod polygon;
+mod rasterize;
 mod intersection;
+mod tangency;
 mod bounds;
 mod graph_path;
 mod is_clockwise;
+mod dedup_anchors;
+mod fillet;
+mod simplify;
+mod orientation;
+mod approx_equal;
+mod arc_length;
+mod area;
+mod continuity;
+mod enclosing_circle;
 mod arithmetic_add;
 mod arithmetic_chain_add;
 mod arithmetic_sub;
 mod arithmetic_cut;
 mod arithmetic_intersect;
+mod arithmetic_xor;
+mod fill_regions;
 mod arithmetic_complicated_paths;
 mod rays;
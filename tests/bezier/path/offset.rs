@@ -0,0 +1,64 @@
+use flo_curves::*;
+use flo_curves::arc::*;
+use flo_curves::bezier::*;
+use flo_curves::bezier::path::*;
+
+#[test]
+fn offset_smooth_s_curve_is_smooth() {
+    // Two curves that join smoothly to make an S shape
+    let path: SimpleBezierPath = (Coord2(0.0, 0.0), vec![
+        (Coord2(0.0, 50.0), Coord2(50.0, 100.0), Coord2(100.0, 100.0)),
+        (Coord2(150.0, 100.0), Coord2(200.0, 150.0), Coord2(200.0, 200.0)),
+    ]);
+
+    let offset_paths = offset_path_continuous(&path, 10.0);
+    assert!(offset_paths.len() == 1);
+
+    // Every join in the offset path should be smooth
+    let report = continuity_report(&offset_paths[0], 0.001);
+    assert!(!report.is_empty());
+    assert!(report.iter().all(|continuity| *continuity >= Continuity::G1), "{:?}", report);
+
+    // The offset should stay about 10 units away from the original path
+    for curve in path_to_curves::<_, Curve<_>>(&offset_paths[0]) {
+        for t in 0..=10 {
+            let point       = curve.point_at_pos((t as f64) / 10.0);
+            let distance    = path_to_curves::<_, Curve<_>>(&path)
+                .map(|original| original.distance_to(&point))
+                .fold(f64::MAX, f64::min);
+
+            assert!((distance - 10.0).abs() < 0.5, "{:?} {}", point, distance);
+        }
+    }
+}
+
+#[test]
+fn offset_splits_at_corners() {
+    let path: SimpleBezierPath = (Coord2(0.0, 0.0), vec![
+        (Coord2(30.0, 0.0), Coord2(70.0, 0.0), Coord2(100.0, 0.0)),
+        (Coord2(100.0, 30.0), Coord2(100.0, 70.0), Coord2(100.0, 100.0)),
+    ]);
+
+    let offset_paths = offset_path_continuous(&path, 5.0);
+
+    assert!(offset_paths.len() == 2);
+    assert!(offset_paths[0].start_point().distance_to(&Coord2(0.0, 5.0)) < 0.1 || offset_paths[0].start_point().distance_to(&Coord2(0.0, -5.0)) < 0.1, "{:?}", offset_paths[0].start_point());
+}
+
+#[test]
+fn offset_closed_smooth_path_is_closed() {
+    let circle_path = Circle::new(Coord2(0.0, 0.0), 100.0).to_path::<SimpleBezierPath>();
+
+    let offset_paths = offset_path_continuous(&circle_path, 10.0);
+
+    assert!(offset_paths.len() == 1);
+    assert!(offset_paths[0].is_closed(0.0001));
+
+    let report = continuity_report(&offset_paths[0], 0.001);
+    assert!(report.iter().all(|continuity| *continuity >= Continuity::G1), "{:?}", report);
+
+    for (_, _, point) in offset_paths[0].points() {
+        let radius = point.magnitude();
+        assert!((radius - 110.0).abs() < 0.5 || (radius - 90.0).abs() < 0.5, "{}", radius);
+    }
+}
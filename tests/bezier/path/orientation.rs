@@ -0,0 +1,58 @@
+use flo_curves::*;
+use flo_curves::arc::*;
+use flo_curves::bezier::path::*;
+
+fn nested_rings() -> Vec<SimpleBezierPath> {
+    // Outer ring, hole and an island inside the hole, all with the same direction
+    vec![
+        Circle::new(Coord2(5.0, 5.0), 4.0).to_path::<SimpleBezierPath>(),
+        Circle::new(Coord2(5.0, 5.0), 3.0).to_path::<SimpleBezierPath>(),
+        Circle::new(Coord2(5.0, 5.0), 2.0).to_path::<SimpleBezierPath>(),
+    ]
+}
+
+#[test]
+fn truetype_orientation() {
+    let rings       = normalize_orientation(&nested_rings(), OutlineConvention::TrueType);
+
+    assert!(rings.len() == 3);
+    assert!(rings[0].is_clockwise());
+    assert!(!rings[1].is_clockwise());
+    assert!(rings[2].is_clockwise());
+}
+
+#[test]
+fn postscript_orientation() {
+    let rings       = normalize_orientation(&nested_rings(), OutlineConvention::PostScript);
+
+    assert!(rings.len() == 3);
+    assert!(!rings[0].is_clockwise());
+    assert!(rings[1].is_clockwise());
+    assert!(!rings[2].is_clockwise());
+}
+
+#[test]
+fn orientation_does_not_depend_on_order() {
+    let mut rings   = nested_rings();
+    rings.reverse();
+
+    let rings       = normalize_orientation(&rings, OutlineConvention::TrueType);
+
+    assert!(rings[0].is_clockwise());
+    assert!(!rings[1].is_clockwise());
+    assert!(rings[2].is_clockwise());
+}
+
+#[test]
+fn separate_shapes_are_all_outer() {
+    let shapes = vec![
+        Circle::new(Coord2(5.0, 5.0), 2.0).to_path::<SimpleBezierPath>(),
+        Circle::new(Coord2(15.0, 5.0), 2.0).to_path::<SimpleBezierPath>().reversed::<SimpleBezierPath>(),
+    ];
+
+    let shapes = normalize_orientation(&shapes, OutlineConvention::TrueType);
+
+    assert!(shapes[0].is_clockwise());
+    assert!(shapes[1].is_clockwise());
+}
+
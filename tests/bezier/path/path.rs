@@ -1,4 +1,6 @@
 use flo_curves::*;
+use flo_curves::arc::*;
+use flo_curves::bezier;
 use flo_curves::bezier::path::*;
 
 #[test]
@@ -40,3 +42,99 @@ fn reverse_unclosed_rectangle() {
     assert!(points[1].2 == Coord2(1.0, 5.0));
     assert!(points[2].2 == Coord2(1.0, 1.0));
 }
+
+#[test]
+fn num_segments_matches_to_curves() {
+    let rectangle = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(1.0, 1.0))
+        .line_to(Coord2(1.0, 5.0))
+        .line_to(Coord2(5.0, 5.0))
+        .line_to(Coord2(5.0, 1.0))
+        .line_to(Coord2(1.0, 1.0))
+        .build();
+
+    assert!(rectangle.num_segments() == 4);
+    assert!(rectangle.num_segments() == rectangle.to_curves::<bezier::Curve<_>>().len());
+}
+
+#[test]
+fn approx_length_of_rectangle() {
+    let rectangle = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(1.0, 1.0))
+        .line_to(Coord2(1.0, 5.0))
+        .line_to(Coord2(5.0, 5.0))
+        .line_to(Coord2(5.0, 1.0))
+        .line_to(Coord2(1.0, 1.0))
+        .build();
+
+    assert!((rectangle.approx_length(0.01) - 16.0).abs() < 0.01);
+}
+
+#[test]
+fn try_from_valid_points() {
+    let path = SimpleBezierPath::try_from_points(Coord2(1.0, 2.0), vec![(Coord2(2.0, 3.0), Coord2(3.0, 4.0), Coord2(4.0, 5.0))]);
+
+    assert!(path.is_ok());
+
+    let path = path.unwrap();
+    assert!(path.start_point() == Coord2(1.0, 2.0));
+    assert!(path.points().collect::<Vec<_>>() == vec![(Coord2(2.0, 3.0), Coord2(3.0, 4.0), Coord2(4.0, 5.0))]);
+}
+
+#[test]
+fn try_from_nan_start_point() {
+    let path = SimpleBezierPath::try_from_points(Coord2(f64::NAN, 2.0), vec![(Coord2(2.0, 3.0), Coord2(3.0, 4.0), Coord2(4.0, 5.0))]);
+
+    assert!(path == Err(PathError::NonFiniteCoordinate(0)));
+}
+
+#[test]
+fn try_from_nan_control_point() {
+    let path = SimpleBezierPath::try_from_points(Coord2(1.0, 2.0), vec![
+        (Coord2(2.0, 3.0), Coord2(3.0, 4.0), Coord2(4.0, 5.0)),
+        (Coord2(2.0, 3.0), Coord2(3.0, f64::NAN), Coord2(4.0, 5.0)),
+    ]);
+
+    assert!(path == Err(PathError::NonFiniteCoordinate(2)));
+}
+
+#[test]
+fn try_from_infinite_end_point() {
+    let path = SimpleBezierPath::try_from_points(Coord2(1.0, 2.0), vec![(Coord2(2.0, 3.0), Coord2(3.0, 4.0), Coord2(f64::INFINITY, 5.0))]);
+
+    assert!(path == Err(PathError::NonFiniteCoordinate(1)));
+}
+
+#[test]
+fn path_to_control_point_arrays() {
+    let path    = (Coord2(1.0, 2.0), vec![(Coord2(2.0, 3.0), Coord2(3.0, 4.0), Coord2(4.0, 5.0)), (Coord2(5.0, 6.0), Coord2(6.0, 7.0), Coord2(7.0, 8.0))]);
+    let arrays  = path.to_control_point_arrays();
+
+    assert!(arrays == vec![
+        [Coord2(1.0, 2.0), Coord2(2.0, 3.0), Coord2(3.0, 4.0), Coord2(4.0, 5.0)],
+        [Coord2(4.0, 5.0), Coord2(5.0, 6.0), Coord2(6.0, 7.0), Coord2(7.0, 8.0)],
+    ]);
+}
+
+#[test]
+fn circle_is_closed() {
+    let circle = Circle::new(Coord2(5.0, 5.0), 4.0).to_path::<SimpleBezierPath>();
+
+    assert!(circle.is_closed(0.001));
+}
+
+#[test]
+fn arc_is_not_closed() {
+    let arc = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(9.0, 5.0))
+        .arc_to(Coord2(5.0, 5.0), 4.0, 0.0, 3.0)
+        .build();
+
+    assert!(!arc.is_closed(0.001));
+    assert!(close_path(&arc).is_closed(0.001));
+}
+
+#[test]
+fn nearly_closed_path_is_closed_within_epsilon() {
+    let path = polygon_to_path::<SimpleBezierPath>(&[Coord2(1.0, 1.0), Coord2(5.0, 1.0), Coord2(5.0, 5.0), Coord2(1.0, 1.1)], false);
+
+    assert!(!path.is_closed(0.01));
+    assert!(path.is_closed(0.2));
+}
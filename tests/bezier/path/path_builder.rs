@@ -0,0 +1,117 @@
+use flo_curves::*;
+use flo_curves::bezier;
+use flo_curves::bezier::path::*;
+
+use std::f64;
+
+#[test]
+fn relative_lines_and_curves() {
+    let path = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(1.0, 1.0))
+        .rel_line_to(Coord2(4.0, 0.0))
+        .rel_curve_to((Coord2(1.0, 1.0), Coord2(1.0, 3.0)), Coord2(0.0, 4.0))
+        .build();
+
+    let points = path.points().collect::<Vec<_>>();
+
+    assert!(points.len() == 2);
+    assert!(points[0].2 == Coord2(5.0, 1.0));
+    assert!(points[1] == (Coord2(6.0, 2.0), Coord2(6.0, 4.0), Coord2(5.0, 5.0)));
+}
+
+#[test]
+fn rounded_rectangle_from_arcs() {
+    // Rectangle from (0,0) to (10, 6) with corners of radius 2
+    let half_pi = f64::consts::PI / 2.0;
+    let rounded = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(2.0, 0.0))
+        .line_to(Coord2(8.0, 0.0))
+        .arc_to(Coord2(8.0, 2.0), 2.0, -half_pi, 0.0)
+        .line_to(Coord2(10.0, 4.0))
+        .arc_to(Coord2(8.0, 4.0), 2.0, 0.0, half_pi)
+        .line_to(Coord2(2.0, 6.0))
+        .arc_to(Coord2(2.0, 4.0), 2.0, half_pi, 2.0*half_pi)
+        .line_to(Coord2(0.0, 2.0))
+        .arc_to(Coord2(2.0, 2.0), 2.0, 2.0*half_pi, 3.0*half_pi)
+        .build();
+
+    // 4 lines and 4 arcs
+    assert!(rounded.num_segments() == 8);
+
+    // Path should finish where it started
+    assert!(rounded.points().last().unwrap().2.distance_to(&Coord2(2.0, 0.0)) < 0.001);
+
+    // Should fit within the rectangle
+    let (min, max) = rounded.bounding_box::<(Coord2, Coord2)>();
+
+    assert!(min.distance_to(&Coord2(0.0, 0.0)) < 0.01, "{:?}", min);
+    assert!(max.distance_to(&Coord2(10.0, 6.0)) < 0.01, "{:?}", max);
+}
+
+#[test]
+fn arc_to_adds_line_to_start_of_arc() {
+    let path = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0))
+        .arc_to(Coord2(5.0, 5.0), 1.0, 0.0, f64::consts::PI)
+        .build();
+
+    let curves = path.to_curves::<bezier::Curve<_>>();
+
+    // One line and two curves for the 180 degree arc
+    assert!(curves.len() == 3);
+    assert!(curves[0].end_point().distance_to(&Coord2(6.0, 5.0)) < 0.001);
+    assert!(curves[2].end_point().distance_to(&Coord2(4.0, 5.0)) < 0.001);
+
+    for curve in curves.iter().skip(1) {
+        for t in 0..=10 {
+            let pos = curve.point_at_pos((t as f64)/10.0);
+            assert!((pos.distance_to(&Coord2(5.0, 5.0)) - 1.0).abs() < 0.01);
+        }
+    }
+
+    // Arc should go anticlockwise, over the top of the circle
+    assert!(curves[1].end_point().distance_to(&Coord2(5.0, 6.0)) < 0.001);
+}
+
+#[test]
+fn clockwise_arc() {
+    let path = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(6.0, 5.0))
+        .arc_to(Coord2(5.0, 5.0), 1.0, 0.0, -f64::consts::PI/2.0)
+        .build();
+
+    let curves = path.to_curves::<bezier::Curve<_>>();
+
+    assert!(curves.len() == 1);
+    assert!(curves[0].end_point().distance_to(&Coord2(5.0, 4.0)) < 0.001);
+    assert!(curves[0].point_at_pos(0.5).distance_to(&Coord2(5.0 + 0.5f64.sqrt(), 5.0 - 0.5f64.sqrt())) < 0.01);
+}
+
+#[test]
+fn elliptical_arc() {
+    let path = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(13.0, 10.0))
+        .elliptical_arc_to(Coord2(10.0, 10.0), (3.0, 1.0), 0.0, 0.0, 2.0*f64::consts::PI)
+        .build();
+
+    let curves = path.to_curves::<bezier::Curve<_>>();
+    assert!(curves.len() == 4);
+
+    for curve in curves.iter() {
+        for t in 0..=10 {
+            // Should be on the ellipse x^2/9 + y^2 = 1
+            let pos = curve.point_at_pos((t as f64)/10.0) - Coord2(10.0, 10.0);
+            let val = (pos.x()*pos.x())/9.0 + pos.y()*pos.y();
+
+            assert!((val - 1.0).abs() < 0.01, "{:?} {:?}", pos, val);
+        }
+    }
+}
+
+#[test]
+fn rotated_elliptical_arc() {
+    let path = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 3.0))
+        .elliptical_arc_to(Coord2(0.0, 0.0), (3.0, 1.0), f64::consts::PI/2.0, 0.0, f64::consts::PI/2.0)
+        .build();
+
+    let curves = path.to_curves::<bezier::Curve<_>>();
+    assert!(curves.len() == 1);
+
+    // Rotating by 90 degrees moves the major axis onto the y axis
+    assert!(curves[0].end_point().distance_to(&Coord2(-1.0, 0.0)) < 0.001, "{:?}", curves[0].end_point());
+}
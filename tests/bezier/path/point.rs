@@ -121,3 +121,125 @@ fn crossing_first_point_leaves_us_outside_circle() {
     // This line crosses the first point of the circle (which can appear as a crossing at both the start and end of the path, which might fool the algorithm into thinking the point is inside)
     assert!(!path_contains_point(&path, &Coord2(1.5, 1.5)));
 }
+
+#[test]
+fn winding_number_of_doubled_circle() {
+    // A circle that goes around twice
+    let circle: SimpleBezierPath    = Circle::new(Coord2(5.0, 5.0), 4.0).to_path();
+    let doubled                     = (circle.0, circle.1.iter().cloned().chain(circle.1.iter().cloned()).collect::<Vec<_>>());
+
+    assert!(path_winding_number(&circle, &Coord2(5.0, 5.0)).abs() == 1);
+    assert!(path_winding_number(&doubled, &Coord2(5.0, 5.0)).abs() == 2);
+    assert!(path_winding_number(&doubled, &Coord2(20.0, 5.0)) == 0);
+}
+
+#[test]
+fn signed_distance_to_circle() {
+    let circle: SimpleBezierPath = Circle::new(Coord2(5.0, 5.0), 2.0).to_path();
+
+    assert!((path_signed_distance(&[circle.clone()], &Coord2(5.0, 5.0)) - -2.0).abs() < 0.01);
+    assert!((path_signed_distance(&[circle.clone()], &Coord2(6.0, 5.0)) - -1.0).abs() < 0.01);
+    assert!((path_signed_distance(&[circle.clone()], &Coord2(5.0, 8.0)) - 1.0).abs() < 0.01);
+    assert!((path_signed_distance(&[circle.clone()], &Coord2(10.0, 10.0)) - (50.0f64.sqrt() - 2.0)).abs() < 0.01);
+}
+
+#[test]
+fn signed_distance_to_square_with_hole() {
+    let square = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0))
+        .line_to(Coord2(10.0, 0.0))
+        .line_to(Coord2(10.0, 10.0))
+        .line_to(Coord2(0.0, 10.0))
+        .line_to(Coord2(0.0, 0.0))
+        .build();
+    let hole = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(3.0, 3.0))
+        .line_to(Coord2(3.0, 7.0))
+        .line_to(Coord2(7.0, 7.0))
+        .line_to(Coord2(7.0, 3.0))
+        .line_to(Coord2(3.0, 3.0))
+        .build();
+    let paths = vec![square, hole];
+
+    // Inside the hole is outside the shape
+    assert!((path_signed_distance(&paths, &Coord2(5.0, 5.0)) - 2.0).abs() < 0.01);
+
+    // Between the hole and the outer edge
+    assert!((path_signed_distance(&paths, &Coord2(1.0, 5.0)) - -1.0).abs() < 0.01);
+    assert!((path_signed_distance(&paths, &Coord2(5.0, 2.5)) - -0.5).abs() < 0.01);
+
+    // Outside the square
+    assert!((path_signed_distance(&paths, &Coord2(13.0, 5.0)) - 3.0).abs() < 0.01);
+}
+
+#[test]
+fn open_path_winding_number_uses_closing_line() {
+    // Triangle with the final edge missing
+    let open    = polygon_to_path::<SimpleBezierPath>(&[Coord2(1.0, 1.0), Coord2(9.0, 1.0), Coord2(5.0, 9.0)], false);
+    let closed  = polygon_to_path::<SimpleBezierPath>(&[Coord2(1.0, 1.0), Coord2(9.0, 1.0), Coord2(5.0, 9.0)], true);
+
+    assert!(path_winding_number(&open, &Coord2(5.0, 3.0)) == path_winding_number(&closed, &Coord2(5.0, 3.0)));
+    assert!(path_winding_number(&open, &Coord2(5.0, 3.0)) != 0);
+    assert!(path_winding_number(&open, &Coord2(2.0, 7.0)) == 0);
+}
+
+#[test]
+fn ray_from_inside_circle_crosses_once() {
+    let circle = Circle::new(Coord2(5.0, 5.0), 4.0).to_path::<SimpleBezierPath>();
+
+    assert!(ray_crossings(&[circle.clone()], &Coord2(5.0, 5.0), &Coord2(1.0, 0.0)) == 1);
+    assert!(ray_crossings(&[circle.clone()], &Coord2(5.0, 5.0), &Coord2(-1.0, 1.0)) == 1);
+    assert!(ray_crossings(&[circle], &Coord2(6.0, 4.0), &Coord2(0.3, 0.7)) == 1);
+}
+
+#[test]
+fn ray_from_outside_circle_crosses_twice() {
+    let circle = Circle::new(Coord2(5.0, 5.0), 4.0).to_path::<SimpleBezierPath>();
+
+    assert!(ray_crossings(&[circle.clone()], &Coord2(-5.0, 5.0), &Coord2(1.0, 0.0)) == 2);
+    assert!(ray_crossings(&[circle], &Coord2(-5.0, 5.0), &Coord2(-1.0, 0.0)) == 0);
+}
+
+#[test]
+fn ray_crosses_several_paths() {
+    let outer = Circle::new(Coord2(5.0, 5.0), 4.0).to_path::<SimpleBezierPath>();
+    let inner = Circle::new(Coord2(5.0, 5.0), 2.0).to_path::<SimpleBezierPath>();
+
+    assert!(ray_crossings(&[outer.clone(), inner.clone()], &Coord2(5.0, 5.0), &Coord2(0.0, 1.0)) == 2);
+    assert!(ray_crossings(&[outer, inner], &Coord2(5.0, 7.5), &Coord2(0.0, 1.0)) == 1);
+}
+
+#[test]
+fn donut_contains_point_non_zero() {
+    // Inner circle goes in the opposite direction to the outer circle, so it's a hole for both fill rules
+    let outer = Circle::new(Coord2(5.0, 5.0), 4.0).to_path::<SimpleBezierPath>();
+    let inner = Circle::new(Coord2(5.0, 5.0), 2.0).to_path::<SimpleBezierPath>().reversed::<SimpleBezierPath>();
+    let donut = vec![outer, inner];
+
+    assert!(!paths_contain_point(&donut, &Coord2(5.0, 5.0), FillRule::NonZero));
+    assert!(paths_contain_point(&donut, &Coord2(5.0, 8.0), FillRule::NonZero));
+    assert!(paths_contain_point(&donut, &Coord2(2.0, 5.0), FillRule::NonZero));
+    assert!(!paths_contain_point(&donut, &Coord2(10.0, 5.0), FillRule::NonZero));
+}
+
+#[test]
+fn donut_contains_point_even_odd() {
+    // Both circles go in the same direction, so the inner circle is only a hole with the even-odd rule
+    let outer = Circle::new(Coord2(5.0, 5.0), 4.0).to_path::<SimpleBezierPath>();
+    let inner = Circle::new(Coord2(5.0, 5.0), 2.0).to_path::<SimpleBezierPath>();
+    let donut = vec![outer, inner];
+
+    assert!(!paths_contain_point(&donut, &Coord2(5.0, 5.0), FillRule::EvenOdd));
+    assert!(paths_contain_point(&donut, &Coord2(5.0, 5.0), FillRule::NonZero));
+    assert!(paths_contain_point(&donut, &Coord2(5.0, 8.0), FillRule::EvenOdd));
+    assert!(!paths_contain_point(&donut, &Coord2(10.0, 5.0), FillRule::EvenOdd));
+}
+
+#[test]
+fn ray_grazing_corner_is_consistent() {
+    // The point is level with the corners of the diamond, so rays from it can touch them without crossing the edge
+    let diamond = polygon_to_path::<SimpleBezierPath>(&[Coord2(5.0, 1.0), Coord2(9.0, 5.0), Coord2(5.0, 9.0), Coord2(1.0, 5.0)], true);
+
+    assert!(paths_contain_point(&[diamond.clone()], &Coord2(5.0, 5.0), FillRule::NonZero));
+    assert!(paths_contain_point(&[diamond.clone()], &Coord2(5.0, 5.0), FillRule::EvenOdd));
+    assert!(!paths_contain_point(&[diamond.clone()], &Coord2(9.5, 9.5), FillRule::EvenOdd));
+    assert!(!paths_contain_point(&[diamond], &Coord2(0.5, 5.0), FillRule::EvenOdd));
+}
@@ -0,0 +1,176 @@
+use flo_curves::*;
+use flo_curves::line::*;
+use flo_curves::arc::*;
+use flo_curves::bezier;
+use flo_curves::bezier::BezierCurve2D;
+use flo_curves::bezier::NormalCurve;
+use flo_curves::bezier::path::*;
+
+#[test]
+fn closed_triangle() {
+    let triangle = polygon_to_path::<SimpleBezierPath>(&[Coord2(1.0, 1.0), Coord2(5.0, 1.0), Coord2(3.0, 4.0)], true);
+
+    assert!(triangle.num_segments() == 3);
+    assert!(triangle.start_point() == Coord2(1.0, 1.0));
+    assert!(triangle.points().last().unwrap().2 == Coord2(1.0, 1.0));
+
+    // Every curve should be a straight line between the points
+    let corners = [Coord2(1.0, 1.0), Coord2(5.0, 1.0), Coord2(3.0, 4.0), Coord2(1.0, 1.0)];
+    for (idx, curve) in triangle.to_curves::<bezier::Curve<_>>().into_iter().enumerate() {
+        let edge = (corners[idx], corners[idx+1]);
+
+        assert!(curve.start_point() == corners[idx]);
+        assert!(curve.end_point() == corners[idx+1]);
+
+        for t in 0..=10 {
+            let pos = curve.point_at_pos((t as f64)/10.0);
+            assert!(edge.distance_to(&pos).abs() < 0.0001);
+        }
+    }
+}
+
+#[test]
+fn open_polyline() {
+    let polyline = polygon_to_path::<SimpleBezierPath>(&[Coord2(1.0, 1.0), Coord2(5.0, 1.0), Coord2(3.0, 4.0)], false);
+
+    assert!(polyline.num_segments() == 2);
+    assert!(polyline.points().last().unwrap().2 == Coord2(3.0, 4.0));
+}
+
+#[test]
+fn already_closed_polygon_does_not_get_extra_line() {
+    let square = polygon_to_path::<SimpleBezierPath>(&[Coord2(0.0, 0.0), Coord2(1.0, 0.0), Coord2(1.0, 1.0), Coord2(0.0, 1.0), Coord2(0.0, 0.0)], true);
+
+    assert!(square.num_segments() == 4);
+}
+
+#[test]
+fn empty_polygon() {
+    let empty = polygon_to_path::<SimpleBezierPath>(&[], true);
+
+    assert!(empty.num_segments() == 0);
+}
+
+fn distance_to_polygon(polygon: &[Coord2], point: &Coord2) -> f64 {
+    polygon.iter().zip(polygon.iter().skip(1))
+        .map(|(start, end)| {
+            let segment = *end - *start;
+            let t       = ((*point - *start).dot(&segment) / segment.dot(&segment)).max(0.0).min(1.0);
+
+            point.distance_to(&(*start + segment * t))
+        })
+        .fold(f64::MAX, f64::min)
+}
+
+#[test]
+fn flatten_circle_with_chord_error() {
+    let circle = Circle::new(Coord2(5.0, 5.0), 4.0).to_path::<SimpleBezierPath>();
+
+    for max_error in [1.0, 0.1, 0.01].iter() {
+        let polygon = to_polygon_with_chord_error(&circle, *max_error);
+
+        assert!(polygon[0] == circle.start_point());
+        assert!(polygon[polygon.len()-1].distance_to(&circle.start_point()) < 0.0001);
+
+        // Every point on the curve should be close to the polygon
+        let curves = circle.to_curves::<bezier::Curve<_>>();
+        for curve in curves.iter() {
+            for t in 0..=100 {
+                let point = curve.point_at_pos((t as f64)/100.0);
+                assert!(distance_to_polygon(&polygon, &point) <= *max_error);
+            }
+        }
+
+        // Every point on the polygon should be close to the curve
+        for (start, end) in polygon.iter().zip(polygon.iter().skip(1)) {
+            for t in 0..=10 {
+                let point       = *start + (*end - *start) * ((t as f64)/10.0);
+                let distance    = curves.iter().map(|curve| curve.distance_to(&point)).fold(f64::MAX, f64::min);
+
+                assert!(distance <= *max_error + 0.0001, "{} {}", distance, max_error);
+            }
+        }
+    }
+}
+
+#[test]
+fn smaller_chord_error_uses_more_points() {
+    let circle  = Circle::new(Coord2(5.0, 5.0), 4.0).to_path::<SimpleBezierPath>();
+    let coarse  = to_polygon_with_chord_error(&circle, 0.1);
+    let fine    = to_polygon_with_chord_error(&circle, 0.001);
+
+    assert!(fine.len() > coarse.len());
+}
+
+#[test]
+fn chord_error_holds_for_large_curves() {
+    // This curve needs more than 16 levels of subdivision to get within the error
+    let arch    = (Coord2(0.0, 0.0), vec![(Coord2(0.0, 1e8), Coord2(1e8, 1e8), Coord2(1e8, 0.0))]);
+    let curve   = bezier::Curve::from_points(Coord2(0.0, 0.0), (Coord2(0.0, 1e8), Coord2(1e8, 1e8)), Coord2(1e8, 0.0));
+    let polygon = to_polygon_with_chord_error(&arch, 0.01);
+
+    assert!(polygon.len() > (1<<16) + 1, "{}", polygon.len());
+
+    for (start, end) in polygon.iter().zip(polygon.iter().skip(1)).step_by(997) {
+        let midpoint = (*start + *end) * 0.5;
+        assert!(curve.distance_to(&midpoint) <= 0.01, "{}", curve.distance_to(&midpoint));
+    }
+}
+
+#[test]
+fn lines_are_not_subdivided() {
+    let triangle = polygon_to_path::<SimpleBezierPath>(&[Coord2(1.0, 1.0), Coord2(5.0, 1.0), Coord2(3.0, 4.0)], true);
+    let polygon  = to_polygon_with_chord_error(&triangle, 0.01);
+
+    assert!(polygon == vec![Coord2(1.0, 1.0), Coord2(5.0, 1.0), Coord2(3.0, 4.0), Coord2(1.0, 1.0)]);
+}
+
+#[test]
+fn smooth_closed_path_passes_through_points() {
+    let points  = vec![Coord2(1.0, 1.0), Coord2(5.0, 0.0), Coord2(9.0, 3.0), Coord2(6.0, 8.0), Coord2(2.0, 6.0)];
+    let path    = smooth_closed_path_through::<SimpleBezierPath>(&points, 1.0);
+
+    assert!(path.start_point() == points[0]);
+    assert!(path.num_segments() == 5);
+    assert!(path.is_closed(0.0));
+
+    // Each curve ends at the next point
+    let end_points = path.points().map(|(_, _, end_point)| end_point).collect::<Vec<_>>();
+    for idx in 0..5 {
+        assert!(end_points[idx] == points[(idx+1)%5]);
+    }
+
+    // Smooth at every point, including the start
+    let curves = path.to_curves::<bezier::Curve<_>>();
+    for idx in 0..5 {
+        let incoming = curves[idx].tangent_at_pos(1.0).to_unit_vector();
+        let outgoing = curves[(idx+1)%5].tangent_at_pos(0.0).to_unit_vector();
+
+        assert!(incoming.distance_to(&outgoing) < 1e-6, "{:?} {:?}", incoming, outgoing);
+    }
+}
+
+#[test]
+fn smooth_closed_path_ignores_repeated_start_point() {
+    let points  = vec![Coord2(1.0, 1.0), Coord2(5.0, 1.0), Coord2(5.0, 5.0), Coord2(1.0, 1.0)];
+    let path    = smooth_closed_path_through::<SimpleBezierPath>(&points, 0.5);
+
+    assert!(path.num_segments() == 3);
+    assert!(path.is_closed(0.0));
+}
+
+#[test]
+fn unsmoothed_closed_path_is_polygon() {
+    let points  = vec![Coord2(1.0, 1.0), Coord2(5.0, 1.0), Coord2(5.0, 5.0)];
+    let path    = smooth_closed_path_through::<SimpleBezierPath>(&points, 0.0);
+
+    for curve in path.to_curves::<bezier::Curve<_>>() {
+        assert!(curve.point_at_pos(0.5).distance_to(&((curve.start_point() + curve.end_point()) * 0.5)) < 1e-6);
+    }
+}
+
+#[test]
+fn smooth_closed_path_with_too_few_points() {
+    assert!(smooth_closed_path_through::<SimpleBezierPath>(&[], 1.0).num_segments() == 0);
+    assert!(smooth_closed_path_through::<SimpleBezierPath>(&[Coord2(1.0, 2.0)], 1.0) == (Coord2(1.0, 2.0), vec![]));
+}
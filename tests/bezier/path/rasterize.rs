@@ -0,0 +1,91 @@
+use flo_curves::*;
+use flo_curves::arc::*;
+use flo_curves::bezier::path::*;
+
+fn rasterize_to_buffer(paths: &[SimpleBezierPath], size: (usize, usize), rule: FillRule) -> Vec<Vec<bool>> {
+    let mut buffer      = vec![vec![false; size.0]; size.1];
+    let mut next_row    = 0;
+
+    rasterize_scanlines(paths, size, rule, |row, ranges| {
+        // Rows are generated in order
+        assert!(row == next_row);
+        next_row += 1;
+
+        // Fill in the pixels whose centers are in the ranges
+        for range in ranges.iter() {
+            for x in 0..size.0 {
+                let center = (x as f64) + 0.5;
+                if range.start <= center && center < range.end {
+                    buffer[row][x] = true;
+                }
+            }
+        }
+    });
+
+    assert!(next_row == size.1);
+
+    buffer
+}
+
+fn count_mismatches(buffer: &[Vec<bool>], expected: impl Fn(Coord2) -> bool) -> usize {
+    let mut mismatches = 0;
+
+    for (y, row) in buffer.iter().enumerate() {
+        for (x, filled) in row.iter().enumerate() {
+            let center = Coord2((x as f64) + 0.5, (y as f64) + 0.5);
+
+            if *filled != expected(center) {
+                mismatches += 1;
+            }
+        }
+    }
+
+    mismatches
+}
+
+#[test]
+fn rasterize_circle() {
+    let circle = Circle::new(Coord2(16.0, 16.0), 10.0).to_path::<SimpleBezierPath>();
+    let buffer = rasterize_to_buffer(&[circle], (32, 32), FillRule::NonZero);
+
+    // Only pixels that are very close to the edge of the circle might be different
+    let mismatches = count_mismatches(&buffer, |point| {
+        let distance = point.distance_to(&Coord2(16.0, 16.0));
+        if (distance - 10.0).abs() < 0.1 { buffer[point.y() as usize][point.x() as usize] } else { distance < 10.0 }
+    });
+
+    assert!(mismatches == 0, "{}", mismatches);
+}
+
+#[test]
+fn rasterize_matches_winding_number() {
+    let circle  = Circle::new(Coord2(16.0, 16.0), 10.0).to_path::<SimpleBezierPath>();
+    let square  = polygon_to_path::<SimpleBezierPath>(&[Coord2(4.2, 4.2), Coord2(4.2, 20.2), Coord2(20.2, 20.2), Coord2(20.2, 4.2)], true);
+    let paths   = vec![circle, square];
+
+    let non_zero = rasterize_to_buffer(&paths, (32, 32), FillRule::NonZero);
+    let even_odd = rasterize_to_buffer(&paths, (32, 32), FillRule::EvenOdd);
+
+    let winding  = |point: Coord2| paths.iter().map(|path| path_winding_number(path, &point)).sum::<i32>();
+
+    assert!(count_mismatches(&non_zero, |point| winding(point) != 0) <= 2);
+    assert!(count_mismatches(&even_odd, |point| winding(point) % 2 != 0) <= 2);
+
+    // The overlapping region is filled with the non-zero rule, and empty with the even-odd rule
+    assert!(non_zero[12][12]);
+    assert!(!even_odd[12][12]);
+}
+
+#[test]
+fn rasterize_clips_to_width() {
+    let square = polygon_to_path::<SimpleBezierPath>(&[Coord2(-10.0, 2.0), Coord2(-10.0, 6.0), Coord2(50.0, 6.0), Coord2(50.0, 2.0)], true);
+
+    rasterize_scanlines(&[square], (16, 8), FillRule::NonZero, |row, ranges| {
+        if row >= 2 && row < 6 {
+            assert!(ranges.len() == 1);
+            assert!(ranges[0] == (0.0..16.0));
+        } else {
+            assert!(ranges.is_empty());
+        }
+    });
+}
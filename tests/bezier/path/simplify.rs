@@ -0,0 +1,75 @@
+use flo_curves::*;
+use flo_curves::arc::*;
+use flo_curves::bezier::*;
+use flo_curves::bezier::path::*;
+
+///
+/// Divides every curve in a path into a number of equal sections
+///
+fn subdivide_path(path: &SimpleBezierPath, sections: usize) -> SimpleBezierPath {
+    let points = path_to_curves::<_, Curve<_>>(path)
+        .flat_map(|curve| (0..sections).map(move |idx| {
+            let section     = curve.section((idx as f64) / (sections as f64), ((idx+1) as f64) / (sections as f64));
+            let (cp1, cp2)  = section.control_points();
+
+            (cp1, cp2, section.end_point())
+        }))
+        .collect::<Vec<_>>();
+
+    (path.start_point(), points)
+}
+
+///
+/// Finds the largest distance from a point on one path to the nearest point on another
+///
+fn max_deviation(from: &SimpleBezierPath, to: &SimpleBezierPath) -> f64 {
+    let to_curves = path_to_curves::<_, Curve<_>>(to).collect::<Vec<_>>();
+
+    path_to_curves::<_, Curve<_>>(from)
+        .flat_map(|curve| (0..=20).map(move |idx| curve.point_at_pos((idx as f64) / 20.0)))
+        .map(|point| to_curves.iter()
+            .map(|curve| curve.point_at_pos(curve.nearest_t(&point)).distance_to(&point))
+            .fold(f64::MAX, f64::min))
+        .fold(0.0, f64::max)
+}
+
+#[test]
+fn simplify_subdivided_circle() {
+    let circle      = Circle::new(Coord2(10.0, 10.0), 8.0).to_path::<SimpleBezierPath>();
+    let subdivided  = subdivide_path(&circle, 16);
+    let simplified  = simplify_path(&subdivided, 0.01);
+
+    assert!(subdivided.points().count() == 64);
+    assert!(simplified.points().count() <= 8);
+    assert!(simplified.is_closed(0.001));
+
+    assert!(max_deviation(&simplified, &subdivided) < 0.01);
+    assert!(max_deviation(&subdivided, &simplified) < 0.01);
+}
+
+#[test]
+fn simplify_collinear_lines() {
+    let path        = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0))
+        .line_to(Coord2(2.0, 0.0))
+        .line_to(Coord2(5.0, 0.0))
+        .line_to(Coord2(10.0, 0.0))
+        .line_to(Coord2(10.0, 4.0))
+        .line_to(Coord2(10.0, 10.0))
+        .build();
+    let simplified  = simplify_path(&path, 0.01);
+
+    // The corner at (10, 0) is kept
+    assert!(simplified.points().count() == 2);
+    assert!(simplified.points().next().unwrap().2.is_near_to(&Coord2(10.0, 0.0), 0.001));
+    assert!(simplified.points().last().unwrap().2.is_near_to(&Coord2(10.0, 10.0), 0.001));
+    assert!(max_deviation(&simplified, &path) < 0.01);
+}
+
+#[test]
+fn simplify_keeps_simple_path() {
+    let circle      = Circle::new(Coord2(10.0, 10.0), 8.0).to_path::<SimpleBezierPath>();
+    let simplified  = simplify_path(&circle, 0.01);
+
+    assert!(simplified.points().count() <= circle.points().count());
+    assert!(max_deviation(&simplified, &circle) < 0.01);
+}
@@ -0,0 +1,105 @@
+use flo_curves::*;
+use flo_curves::bezier::path::*;
+
+use std::f64;
+
+///
+/// Measures the area covered by a set of paths using the non-zero winding rule
+///
+fn filled_area(paths: &[SimpleBezierPath]) -> f64 {
+    let mut area = 0.0;
+
+    rasterize_scanlines(paths, (200, 200), FillRule::NonZero, |_y, ranges| {
+        area += ranges.iter().map(|range| range.end - range.start).sum::<f64>();
+    });
+
+    area
+}
+
+#[test]
+fn stroke_line_with_butt_caps() {
+    let line    = polygon_to_path::<SimpleBezierPath>(&[Coord2(20.0, 50.0), Coord2(120.0, 50.0)], false);
+    let stroke  = stroke_path::<_, SimpleBezierPath>(&line, 10.0, &StrokeOptions::default());
+
+    assert!(stroke.len() == 1);
+    assert!(stroke[0].is_closed(0.0001));
+    assert!((filled_area(&stroke) - 1000.0).abs() < 1.0, "{}", filled_area(&stroke));
+    assert!((path_area(&stroke[0]).abs() - 1000.0).abs() < 0.01, "{}", path_area(&stroke[0]));
+}
+
+#[test]
+fn stroke_line_with_square_caps() {
+    let line    = polygon_to_path::<SimpleBezierPath>(&[Coord2(20.0, 50.0), Coord2(120.0, 50.0)], false);
+    let stroke  = stroke_path::<_, SimpleBezierPath>(&line, 10.0, &StrokeOptions::default().with_cap(LineCap::Square));
+
+    // Each cap adds half the width to the length of the stroke
+    assert!((path_area(&stroke[0]).abs() - 1100.0).abs() < 0.01, "{}", path_area(&stroke[0]));
+}
+
+#[test]
+fn stroke_line_with_round_caps() {
+    let line    = polygon_to_path::<SimpleBezierPath>(&[Coord2(20.0, 50.0), Coord2(120.0, 50.0)], false);
+    let stroke  = stroke_path::<_, SimpleBezierPath>(&line, 10.0, &StrokeOptions::default().with_cap(LineCap::Round));
+
+    // The two caps make a circle with the same diameter as the width of the stroke
+    let expected = 1000.0 + f64::consts::PI*25.0;
+    assert!((path_area(&stroke[0]).abs() - expected).abs() < 0.1, "{} {}", path_area(&stroke[0]), expected);
+}
+
+#[test]
+fn stroke_corner_with_miter_join() {
+    let corner  = polygon_to_path::<SimpleBezierPath>(&[Coord2(20.0, 20.0), Coord2(120.0, 20.0), Coord2(120.0, 120.0)], false);
+    let stroke  = stroke_path::<_, SimpleBezierPath>(&corner, 10.0, &StrokeOptions::default().with_join(LineJoin::Miter));
+
+    // Two 100x10 rectangles that overlap by a 5x5 square, and a 5x5 square for the miter
+    assert!((filled_area(&stroke) - 2000.0).abs() < 1.0, "{}", filled_area(&stroke));
+}
+
+#[test]
+fn stroke_corner_with_bevel_join() {
+    let corner  = polygon_to_path::<SimpleBezierPath>(&[Coord2(20.0, 20.0), Coord2(120.0, 20.0), Coord2(120.0, 120.0)], false);
+    let stroke  = stroke_path::<_, SimpleBezierPath>(&corner, 10.0, &StrokeOptions::default().with_join(LineJoin::Bevel));
+
+    // The bevel cuts off half of the miter
+    assert!((filled_area(&stroke) - 1987.5).abs() < 1.0, "{}", filled_area(&stroke));
+}
+
+#[test]
+fn stroke_corner_with_round_join() {
+    let corner  = polygon_to_path::<SimpleBezierPath>(&[Coord2(20.0, 20.0), Coord2(120.0, 20.0), Coord2(120.0, 120.0)], false);
+    let stroke  = stroke_path::<_, SimpleBezierPath>(&corner, 10.0, &StrokeOptions::default().with_join(LineJoin::Round));
+
+    // The join is a quarter circle
+    let expected = 1975.0 + f64::consts::PI*25.0/4.0;
+    assert!((filled_area(&stroke) - expected).abs() < 1.0, "{} {}", filled_area(&stroke), expected);
+}
+
+#[test]
+fn sharp_corner_uses_bevel_past_miter_limit() {
+    // The corner turns back on itself at a narrow angle, which would make a very long miter
+    let corner  = polygon_to_path::<SimpleBezierPath>(&[Coord2(20.0, 20.0), Coord2(120.0, 20.0), Coord2(20.0, 30.0)], false);
+    let mitered = stroke_path::<_, SimpleBezierPath>(&corner, 10.0, &StrokeOptions::default().with_join(LineJoin::Miter));
+    let beveled = stroke_path::<_, SimpleBezierPath>(&corner, 10.0, &StrokeOptions::default().with_join(LineJoin::Bevel));
+
+    assert!((filled_area(&mitered) - filled_area(&beveled)).abs() < 0.01, "{} {}", filled_area(&mitered), filled_area(&beveled));
+}
+
+#[test]
+fn stroke_closed_square() {
+    let square  = polygon_to_path::<SimpleBezierPath>(&[Coord2(20.0, 20.0), Coord2(120.0, 20.0), Coord2(120.0, 120.0), Coord2(20.0, 120.0)], true);
+    let stroke  = stroke_path::<_, SimpleBezierPath>(&square, 10.0, &StrokeOptions::default());
+
+    // The inside of the square is a hole
+    assert!(stroke.len() == 2);
+    assert!((filled_area(&stroke) - (110.0*110.0 - 90.0*90.0)).abs() < 1.0, "{}", filled_area(&stroke));
+    assert!(!paths_contain_point(&stroke, &Coord2(70.0, 70.0), FillRule::NonZero));
+    assert!(paths_contain_point(&stroke, &Coord2(70.0, 22.0), FillRule::NonZero));
+}
+
+#[test]
+fn stroke_empty_path() {
+    let path    = SimpleBezierPath::from_points(Coord2(20.0, 20.0), vec![]);
+    let stroke  = stroke_path::<_, SimpleBezierPath>(&path, 10.0, &StrokeOptions::default());
+
+    assert!(stroke.is_empty());
+}
@@ -1,4 +1,6 @@
 use flo_curves::geo::*;
+use flo_curves::arc::*;
+use flo_curves::bezier::*;
 use flo_curves::bezier::path::*;
 
 use std::fmt::Write;
@@ -14,3 +16,163 @@ where Path::Point: Coordinate2D {
 
     svg
 }
+
+#[test]
+fn svg_for_circle() {
+    let circle  = Circle::new(Coord2(0.0, 0.0), 1.0).to_path::<SimpleBezierPath>();
+    let svg     = to_svg_path_string(&circle, 2);
+
+    // The circle starts at 45 degrees and is made up of 4 arcs, with the control points at (1.10, 0.32) being the rotated versions of the usual (1, 0.55)
+    assert!(svg == "M 0.71 0.71 C 1.10 0.32 1.10 -0.32 0.71 -0.71 C 0.32 -1.10 -0.32 -1.10 -0.71 -0.71 C -1.10 -0.32 -1.10 0.32 -0.71 0.71 C -0.32 1.10 0.32 1.10 0.71 0.71 Z", "{}", svg);
+}
+
+#[test]
+fn svg_for_open_path() {
+    let path    = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(1.0, 2.0))
+        .curve_to((Coord2(3.0, 4.0), Coord2(5.0, 6.0)), Coord2(7.0, 8.0))
+        .build();
+    let svg     = to_svg_path_string(&path, 1);
+
+    assert!(svg == "M 1.0 2.0 C 3.0 4.0 5.0 6.0 7.0 8.0", "{}", svg);
+}
+
+#[test]
+fn svg_precision() {
+    let path    = polygon_to_path::<SimpleBezierPath>(&[Coord2(0.125, 0.0), Coord2(1.0, 0.0), Coord2(1.0, 1.0)], true);
+
+    assert!(to_svg_path_string(&path, 0).starts_with("M 0 0 C"), "{}", to_svg_path_string(&path, 0));
+    assert!(to_svg_path_string(&path, 3).starts_with("M 0.125 0.000 C"), "{}", to_svg_path_string(&path, 3));
+    assert!(to_svg_path_string(&path, 3).ends_with(" Z"), "{}", to_svg_path_string(&path, 3));
+}
+
+#[test]
+fn svg_for_empty_path() {
+    let path = SimpleBezierPath::from_points(Coord2(1.0, 2.0), vec![]);
+
+    assert!(to_svg_path_string(&path, 1) == "M 1.0 2.0");
+}
+
+fn assert_paths_close(path1: &SimpleBezierPath, path2: &SimpleBezierPath, tolerance: f64) {
+    assert!(path1.start_point().distance_to(&path2.start_point()) < tolerance, "{:?} {:?}", path1, path2);
+    assert!(path1.1.len() == path2.1.len(), "{:?} {:?}", path1, path2);
+
+    for ((cp1a, cp2a, enda), (cp1b, cp2b, endb)) in path1.1.iter().zip(path2.1.iter()) {
+        assert!(cp1a.distance_to(cp1b) < tolerance, "{:?} {:?}", path1, path2);
+        assert!(cp2a.distance_to(cp2b) < tolerance, "{:?} {:?}", path1, path2);
+        assert!(enda.distance_to(endb) < tolerance, "{:?} {:?}", path1, path2);
+    }
+}
+
+#[test]
+fn parse_absolute_curves() {
+    let paths = parse_svg_path::<SimpleBezierPath>("M 1 2 C 3 4, 5 6, 7 8").unwrap();
+
+    assert!(paths == vec![(Coord2(1.0, 2.0), vec![(Coord2(3.0, 4.0), Coord2(5.0, 6.0), Coord2(7.0, 8.0))])], "{:?}", paths);
+}
+
+#[test]
+fn parse_relative_curves() {
+    let paths = parse_svg_path::<SimpleBezierPath>("m1,2c2,2 4,4 6,6c1,0 2,0 3,0").unwrap();
+
+    assert!(paths == vec![(Coord2(1.0, 2.0), vec![
+        (Coord2(3.0, 4.0), Coord2(5.0, 6.0), Coord2(7.0, 8.0)),
+        (Coord2(8.0, 8.0), Coord2(9.0, 8.0), Coord2(10.0, 8.0)),
+    ])], "{:?}", paths);
+}
+
+#[test]
+fn parse_lines_and_close() {
+    // Absolute and relative versions of the same square
+    let absolute = parse_svg_path::<SimpleBezierPath>("M1 1 L5 1 H5 V5 L1 5 Z").unwrap();
+    let relative = parse_svg_path::<SimpleBezierPath>("m1 1 l4 0 h0 v4 l-4 0 z").unwrap();
+    let implicit = parse_svg_path::<SimpleBezierPath>("M1 1 5 1 5 1 5 5 1 5z").unwrap();
+
+    assert!(absolute.len() == 1);
+    assert!(absolute[0].1.len() == 5, "{:?}", absolute);
+    assert!(absolute[0].is_closed(0.0));
+    assert!((path_area(&absolute[0]).abs() - 16.0).abs() < 1e-6);
+
+    assert_paths_close(&absolute[0], &relative[0], 1e-6);
+    assert_paths_close(&absolute[0], &implicit[0], 1e-6);
+}
+
+#[test]
+fn parse_smooth_curves() {
+    let smooth      = parse_svg_path::<SimpleBezierPath>("M0 0 C 1 2 3 2 4 0 S 7 -2 8 0").unwrap();
+    let explicit    = parse_svg_path::<SimpleBezierPath>("M0 0 C 1 2 3 2 4 0 C 5 -2 7 -2 8 0").unwrap();
+
+    assert_paths_close(&smooth[0], &explicit[0], 1e-6);
+}
+
+#[test]
+fn parse_quadratic_curves() {
+    let quadratic   = parse_svg_path::<SimpleBezierPath>("M0 0 Q 3 3 6 0 T 12 0").unwrap();
+    let cubic       = parse_svg_path::<SimpleBezierPath>("M0 0 C 2 2 4 2 6 0 C 8 -2 10 -2 12 0").unwrap();
+
+    assert_paths_close(&quadratic[0], &cubic[0], 1e-6);
+}
+
+#[test]
+fn parse_multiple_subpaths() {
+    let paths = parse_svg_path::<SimpleBezierPath>("M0 0 L1 0 L1 1 Z M 5 5 l 1 0 l 0 1 z").unwrap();
+
+    assert!(paths.len() == 2, "{:?}", paths);
+    assert!(paths[1].start_point() == Coord2(5.0, 5.0));
+    assert!(paths[1].is_closed(1e-6));
+}
+
+#[test]
+fn parse_numbers_without_separators() {
+    let paths = parse_svg_path::<SimpleBezierPath>("M.5.5L-1.5-2e1").unwrap();
+
+    assert!(paths[0].start_point() == Coord2(0.5, 0.5));
+    assert!(paths[0].1[0].2 == Coord2(-1.5, -20.0));
+}
+
+#[test]
+fn parse_errors() {
+    assert!(parse_svg_path::<SimpleBezierPath>("M 1 2 X 3 4") == Err(SvgParseError::UnexpectedCharacter(6)));
+    assert!(parse_svg_path::<SimpleBezierPath>("1 2 L 3 4") == Err(SvgParseError::MissingCommand(0)));
+    assert!(parse_svg_path::<SimpleBezierPath>("L 3 4") == Err(SvgParseError::MissingMoveTo(0)));
+    assert!(parse_svg_path::<SimpleBezierPath>("M 1 2 C 3 4 5 6") == Err(SvgParseError::MissingCoordinates(6)));
+}
+
+#[test]
+fn svg_round_trip() {
+    let circle  = Circle::new(Coord2(5.0, 5.0), 4.0).to_path::<SimpleBezierPath>();
+    let svg     = to_svg_path_string(&circle, 6);
+    let parsed  = parse_svg_path::<SimpleBezierPath>(&svg).unwrap();
+
+    assert!(parsed.len() == 1);
+    assert_paths_close(&circle, &parsed[0], 1e-5);
+
+    // Writing the parsed path should produce the same string
+    assert!(to_svg_path_string(&parsed[0], 6) == svg);
+}
+
+#[test]
+fn parse_arcs() {
+    // Semicircles with a radius of 2 around (3, 1), drawn with absolute and relative coordinates
+    let absolute = parse_svg_path::<SimpleBezierPath>("M 1 1 A 2 2 0 0 1 5 1").unwrap();
+    let relative = parse_svg_path::<SimpleBezierPath>("M 1 1 a 2 2 0 0 1 4 0").unwrap();
+
+    assert!(absolute[0].1.len() == 2, "{:?}", absolute);
+    assert!(absolute[0].1[1].2 == Coord2(5.0, 1.0));
+    assert_paths_close(&absolute[0], &relative[0], 1e-9);
+
+    for curve in absolute[0].to_curves::<Curve<_>>() {
+        assert!((curve.point_at_pos(0.5).distance_to(&Coord2(3.0, 1.0)) - 2.0).abs() < 0.01);
+    }
+}
+
+#[test]
+fn parse_arc_flags_without_separators() {
+    let separated   = parse_svg_path::<SimpleBezierPath>("M 1 1 a1 1 0 0 1 1 0 a1 1 0 0 1 10 0").unwrap();
+    let minified    = parse_svg_path::<SimpleBezierPath>("M1 1a1 1 0 0 1 1 0a1 1 0 0110 0").unwrap();
+
+    assert!(minified.len() == 1);
+    assert_paths_close(&separated[0], &minified[0], 1e-9);
+
+    // Flags can only be 0 or 1
+    assert!(parse_svg_path::<SimpleBezierPath>("M 1 1 a 1 1 0 2 1 1 0") == Err(SvgParseError::UnexpectedCharacter(14)));
+}
@@ -0,0 +1,61 @@
+use flo_curves::*;
+use flo_curves::bezier::path::*;
+
+///
+/// Adds up the area of the triangles in a mesh
+///
+fn mesh_area(vertices: &[Coord2], triangles: &[[u32; 3]]) -> f64 {
+    triangles.iter()
+        .map(|[a, b, c]| {
+            let (a, b, c) = (vertices[*a as usize], vertices[*b as usize], vertices[*c as usize]);
+            ((b.x()-a.x())*(c.y()-a.y()) - (c.x()-a.x())*(b.y()-a.y())).abs() / 2.0
+        })
+        .sum()
+}
+
+#[test]
+fn sweep_constant_width_along_straight_line() {
+    let line                    = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0)).line_to(Coord2(100.0, 0.0)).build();
+    let (vertices, triangles)   = sweep_profile_along_path(&line, &[10.0], 11);
+
+    assert!(vertices.len() == 22);
+    assert!(triangles.len() == 20);
+    assert!(triangles.iter().all(|triangle| triangle.iter().all(|idx| (*idx as usize) < vertices.len())));
+
+    // The mesh should be a 100x10 rectangle
+    assert!(vertices.iter().all(|vertex| (vertex.y().abs() - 5.0).abs() < 1e-6), "{:?}", vertices);
+    assert!(vertices.iter().all(|vertex| vertex.x() > -1e-6 && vertex.x() < 100.0+1e-6), "{:?}", vertices);
+    assert!(vertices[0].x().abs() < 1e-6 && (vertices[21].x() - 100.0).abs() < 1e-6);
+    assert!((mesh_area(&vertices, &triangles) - 1000.0).abs() < 1e-6, "{}", mesh_area(&vertices, &triangles));
+}
+
+#[test]
+fn sweep_is_evenly_spaced() {
+    // Two curves of different lengths
+    let path                    = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0)).line_to(Coord2(30.0, 0.0)).line_to(Coord2(100.0, 0.0)).build();
+    let (vertices, _)           = sweep_profile_along_path(&path, &[10.0], 11);
+
+    for idx in 0..11 {
+        assert!((vertices[idx*2].x() - 10.0*(idx as f64)).abs() < 1e-6, "{:?}", vertices);
+    }
+}
+
+#[test]
+fn sweep_tapering_width() {
+    let line                    = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(0.0, 0.0)).line_to(Coord2(100.0, 0.0)).build();
+    let (vertices, triangles)   = sweep_profile_along_path(&line, &[0.0, 10.0], 21);
+
+    // Triangle with a base of 10 and a length of 100
+    assert!((vertices[0].distance_to(&vertices[1])) < 1e-6);
+    assert!((vertices[40].distance_to(&vertices[41]) - 10.0).abs() < 1e-6);
+    assert!((mesh_area(&vertices, &triangles) - 500.0).abs() < 1e-6, "{}", mesh_area(&vertices, &triangles));
+}
+
+#[test]
+fn sweep_empty_path() {
+    let path                    = SimpleBezierPath::from_points(Coord2(0.0, 0.0), vec![]);
+    let (vertices, triangles)   = sweep_profile_along_path(&path, &[10.0], 11);
+
+    assert!(vertices.is_empty());
+    assert!(triangles.is_empty());
+}
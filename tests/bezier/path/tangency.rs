@@ -0,0 +1,67 @@
+use flo_curves::*;
+use flo_curves::arc::*;
+use flo_curves::bezier;
+use flo_curves::bezier::path::*;
+
+#[test]
+fn circles_touching_on_outside() {
+    let circle1     = Circle::new(Coord2(0.0, 0.0), 2.0).to_path::<SimpleBezierPath>();
+    let circle2     = Circle::new(Coord2(4.0, 0.0), 2.0).to_path::<SimpleBezierPath>();
+
+    let tangencies  = path_tangencies(&circle1, &circle2, 0.01);
+
+    assert!(tangencies.len() == 1, "{:?}", tangencies);
+
+    let ((section1, t1), (section2, t2)) = tangencies[0];
+    let pos1 = circle1.to_curves::<bezier::Curve<_>>()[section1].point_at_pos(t1);
+    let pos2 = circle2.to_curves::<bezier::Curve<_>>()[section2].point_at_pos(t2);
+
+    assert!(pos1.distance_to(&Coord2(2.0, 0.0)) < 0.01, "{:?}", pos1);
+    assert!(pos2.distance_to(&Coord2(2.0, 0.0)) < 0.01, "{:?}", pos2);
+}
+
+#[test]
+fn circles_touching_on_inside() {
+    let circle1     = Circle::new(Coord2(0.0, 0.0), 2.0).to_path::<SimpleBezierPath>();
+    let circle2     = Circle::new(Coord2(1.0, 0.0), 1.0).to_path::<SimpleBezierPath>();
+
+    let tangencies  = path_tangencies(&circle1, &circle2, 0.01);
+
+    assert!(tangencies.len() == 1, "{:?}", tangencies);
+
+    let ((section1, t1), _) = tangencies[0];
+    let pos1 = circle1.to_curves::<bezier::Curve<_>>()[section1].point_at_pos(t1);
+
+    assert!(pos1.distance_to(&Coord2(2.0, 0.0)) < 0.01, "{:?}", pos1);
+}
+
+#[test]
+fn crossing_circles_are_not_tangent() {
+    let circle1     = Circle::new(Coord2(0.0, 0.0), 2.0).to_path::<SimpleBezierPath>();
+    let circle2     = Circle::new(Coord2(3.0, 0.0), 2.0).to_path::<SimpleBezierPath>();
+
+    assert!(path_tangencies(&circle1, &circle2, 0.01).is_empty());
+}
+
+#[test]
+fn separate_circles_are_not_tangent() {
+    let circle1     = Circle::new(Coord2(0.0, 0.0), 2.0).to_path::<SimpleBezierPath>();
+    let circle2     = Circle::new(Coord2(5.0, 0.0), 2.0).to_path::<SimpleBezierPath>();
+
+    assert!(path_tangencies(&circle1, &circle2, 0.01).is_empty());
+}
+
+#[test]
+fn line_touching_circle() {
+    let circle  = Circle::new(Coord2(0.0, 0.0), 2.0).to_path::<SimpleBezierPath>();
+    let line    = polygon_to_path::<SimpleBezierPath>(&[Coord2(-3.0, 2.0), Coord2(3.0, 2.0)], false);
+
+    let tangencies = path_tangencies(&line, &circle, 0.01);
+
+    assert!(tangencies.len() == 1, "{:?}", tangencies);
+
+    let ((section1, t1), _) = tangencies[0];
+    let pos1 = line.to_curves::<bezier::Curve<_>>()[section1].point_at_pos(t1);
+
+    assert!(pos1.distance_to(&Coord2(0.0, 2.0)) < 0.01, "{:?}", pos1);
+}
@@ -29,3 +29,40 @@ pub fn no_points_means_no_curve() {
 
     assert!(curve.len() == 0);
 }
+
+#[test]
+fn segment_windows_on_closed_path_wraps_around() {
+    let triangle = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(1.0, 1.0))
+        .line_to(Coord2(5.0, 1.0))
+        .line_to(Coord2(3.0, 5.0))
+        .line_to(Coord2(1.0, 1.0))
+        .build();
+
+    let windows = segment_windows::<_, Curve<_>>(&triangle).collect::<Vec<_>>();
+
+    assert!(windows.len() == 3);
+
+    // Each window should be two curves that join together
+    for (first, second) in windows.iter() {
+        assert!(first.end_point() == second.start_point());
+    }
+
+    // Final window is the join at the start of the path
+    assert!(windows[2].0.end_point() == Coord2(1.0, 1.0));
+    assert!(windows[2].1.start_point() == Coord2(1.0, 1.0));
+    assert!(windows[2].1.end_point() == Coord2(5.0, 1.0));
+}
+
+#[test]
+fn segment_windows_on_open_path() {
+    let open_path = BezierPathBuilder::<SimpleBezierPath>::start(Coord2(1.0, 1.0))
+        .line_to(Coord2(5.0, 1.0))
+        .line_to(Coord2(3.0, 5.0))
+        .build();
+
+    let windows = segment_windows::<_, Curve<_>>(&open_path).collect::<Vec<_>>();
+
+    assert!(windows.len() == 1);
+    assert!(windows[0].0.end_point() == Coord2(5.0, 1.0));
+    assert!(windows[0].1.start_point() == Coord2(5.0, 1.0));
+}
@@ -0,0 +1,43 @@
+use flo_curves::*;
+use flo_curves::bezier::*;
+
+#[test]
+fn read_quadratic_curve_points() {
+    let curve = QuadCurve::from_points(Coord2(1.0, 1.0), Coord2(3.0, 5.0), Coord2(6.0, 2.0));
+
+    assert!(curve.start_point() == Coord2(1.0, 1.0));
+    assert!(curve.control_point() == Coord2(3.0, 5.0));
+    assert!(curve.end_point() == Coord2(6.0, 2.0));
+}
+
+#[test]
+fn quadratic_point_at_pos_matches_de_casteljau() {
+    let (w1, w2, w3)    = (Coord2(1.0, 1.0), Coord2(3.0, 5.0), Coord2(6.0, 2.0));
+    let curve           = QuadCurve::from_points(w1, w2, w3);
+
+    for x in 0..=100 {
+        let t           = (x as f64)/100.0;
+
+        // Interpolate between the points by hand
+        let p1          = w1*(1.0-t) + w2*t;
+        let p2          = w2*(1.0-t) + w3*t;
+        let expected    = p1*(1.0-t) + p2*t;
+
+        assert!(curve.point_at_pos(t).distance_to(&expected) < 1e-9);
+    }
+}
+
+#[test]
+fn quadratic_to_cubic() {
+    let curve           = QuadCurve::from_points(Coord2(1.0, 1.0), Coord2(3.0, 5.0), Coord2(6.0, 2.0));
+    let cubic: Curve<_> = curve.to_cubic();
+
+    assert!(cubic.start_point() == curve.start_point());
+    assert!(cubic.end_point() == curve.end_point());
+
+    for x in 0..=100 {
+        let t = (x as f64)/100.0;
+
+        assert!(curve.point_at_pos(t).distance_to(&cubic.point_at_pos(t)) < 1e-9);
+    }
+}
@@ -0,0 +1,43 @@
+use flo_curves::*;
+use flo_curves::bezier::*;
+use flo_curves::bezier::path::*;
+
+#[test]
+fn reflect_curve_across_x_axis() {
+    let curve       = Curve::from_points(Coord2(1.0, 2.0), (Coord2(3.0, 5.0), Coord2(6.0, 4.0)), Coord2(8.0, -1.0));
+    let reflected   = reflect_curve(&curve, &(Coord2(0.0, 0.0), Coord2(1.0, 0.0)));
+
+    let original    = curve.control_point_array();
+    let mirrored    = reflected.control_point_array();
+
+    for (original, mirrored) in original.iter().zip(mirrored.iter()) {
+        assert!((original.x() - mirrored.x()).abs() < 0.0001);
+        assert!((original.y() + mirrored.y()).abs() < 0.0001);
+    }
+}
+
+#[test]
+fn reflect_curve_across_diagonal() {
+    // Reflecting across the line x=y swaps the coordinates
+    let curve       = Curve::from_points(Coord2(1.0, 2.0), (Coord2(3.0, 5.0), Coord2(6.0, 4.0)), Coord2(8.0, -1.0));
+    let reflected   = reflect_curve(&curve, &(Coord2(2.0, 2.0), Coord2(4.0, 4.0)));
+
+    for t in 0..=10 {
+        let t           = (t as f64)/10.0;
+        let original    = curve.point_at_pos(t);
+        let mirrored    = reflected.point_at_pos(t);
+
+        assert!(mirrored.distance_to(&Coord2(original.y(), original.x())) < 0.0001);
+    }
+}
+
+#[test]
+fn reflect_path_reverses_direction() {
+    let square      = polygon_to_path::<SimpleBezierPath>(&[Coord2(1.0, 1.0), Coord2(1.0, 5.0), Coord2(5.0, 5.0), Coord2(5.0, 1.0)], true);
+    let reflected   = reflect_path(&square, &(Coord2(0.0, 0.0), Coord2(0.0, 1.0)));
+
+    assert!(square.is_clockwise());
+    assert!(!reflected.is_clockwise());
+    assert!(reflected.start_point() == Coord2(-1.0, 1.0));
+    assert!(reflected.points().map(|(_, _, end_point)| end_point).collect::<Vec<_>>() == vec![Coord2(-1.0, 5.0), Coord2(-5.0, 5.0), Coord2(-5.0, 1.0), Coord2(-1.0, 1.0)]);
+}
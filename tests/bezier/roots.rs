@@ -0,0 +1,77 @@
+use flo_curves::bezier::*;
+
+///
+/// The quadratic (x-0.25)(x-0.75) as a bezier curve, which falls through 0.25 and rises through 0.75
+///
+fn quadratic_with_two_roots() -> [Coord2; 3] {
+    [Coord2(0.0, 0.1875), Coord2(0.5, -0.3125), Coord2(1.0, 0.1875)]
+}
+
+#[test]
+fn find_roots_with_tight_tolerance() {
+    let mut roots = find_bezier_roots_with_tolerance(quadratic_with_two_roots(), 0.0001);
+    roots.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+    assert!(roots.len() == 2, "{:?}", roots);
+    assert!((roots[0]-0.25).abs() < 1e-6, "{:?}", roots);
+    assert!((roots[1]-0.75).abs() < 1e-6, "{:?}", roots);
+}
+
+#[test]
+fn find_roots_with_loose_tolerance() {
+    let mut roots = find_bezier_roots_with_tolerance(quadratic_with_two_roots(), 0.5);
+    roots.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+    assert!(roots.iter().all(|root| (root-0.25).abs() < 0.01 || (root-0.75).abs() < 0.01), "{:?}", roots);
+}
+
+#[test]
+fn default_tolerance_finds_same_roots() {
+    let roots = find_bezier_roots(quadratic_with_two_roots());
+
+    assert!(roots.len() == 2, "{:?}", roots);
+    assert!(roots.iter().all(|root| (root-0.25).abs() < 0.001 || (root-0.75).abs() < 0.001), "{:?}", roots);
+}
+
+#[test]
+fn find_roots_poly_quadratic() {
+    // (x-0.25)(x-0.75) == 0.1875 - x + x^2
+    let mut roots = find_roots_poly(&[0.1875, -1.0, 1.0]);
+    roots.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+    assert!(roots.len() == 2, "{:?}", roots);
+    assert!((roots[0]-0.25).abs() < 0.001, "{:?}", roots);
+    assert!((roots[1]-0.75).abs() < 0.001, "{:?}", roots);
+}
+
+#[test]
+fn find_roots_poly_high_degree_is_empty() {
+    // Degree 9 is higher than the root finder supports
+    let mut coefficients = vec![0.0; 10];
+    coefficients[0] = -0.5;
+    coefficients[9] = 1.0;
+
+    assert!(find_roots_poly(&coefficients).is_empty());
+}
+
+#[test]
+fn find_roots_poly_high_degree_with_zero_coefficients() {
+    // Zero coefficients above degree 8 don't count towards the degree
+    let roots = find_roots_poly(&[-1.0, 2.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0]);
+
+    assert!(roots.len() == 1, "{:?}", roots);
+    assert!((roots[0]-0.5).abs() < 0.001, "{:?}", roots);
+}
+
+#[test]
+fn find_roots_with_crossing_direction() {
+    let mut roots = find_bezier_roots_with_sign(quadratic_with_two_roots(), 0.0001);
+    roots.sort_by(|(t1, _), (t2, _)| t1.partial_cmp(t2).unwrap());
+
+    // The quadratic is positive at both ends, so it falls through the first root and rises through the second
+    assert!(roots.len() == 2, "{:?}", roots);
+    assert!((roots[0].0-0.25).abs() < 1e-6, "{:?}", roots);
+    assert!((roots[1].0-0.75).abs() < 1e-6, "{:?}", roots);
+    assert!(roots[0].1 == CrossingDirection::PositiveToNegative, "{:?}", roots);
+    assert!(roots[1].1 == CrossingDirection::NegativeToPositive, "{:?}", roots);
+}
@@ -39,3 +39,102 @@ fn subdivide_2() {
         assert!(approx_equal(original, subdivision));
     }
 }
+
+#[test]
+fn split_at_with_ranges_in_half() {
+    let curve   = bezier::Curve::from_points(Coord2(1.0, 2.0), (Coord2(2.0, 0.0), Coord2(3.0, 5.0)), Coord2(4.0, 2.0));
+    let split   = curve.split_at_with_ranges::<bezier::Curve<_>>(&[0.5]);
+
+    assert!(split.len() == 2);
+    assert!(split[0].1 == (0.0..0.5));
+    assert!(split[1].1 == (0.5..1.0));
+
+    let (first, second) = curve.subdivide::<bezier::Curve<_>>(0.5);
+    assert!(split[0].0.start_point().distance_to(&first.start_point()) < 1e-9);
+    assert!(split[0].0.end_point().distance_to(&first.end_point()) < 1e-9);
+    assert!(split[1].0.end_point().distance_to(&second.end_point()) < 1e-9);
+}
+
+#[test]
+fn split_at_with_ranges_maps_to_original_curve() {
+    let curve   = bezier::Curve::from_points(Coord2(1.0, 2.0), (Coord2(2.0, 0.0), Coord2(3.0, 5.0)), Coord2(4.0, 2.0));
+    let split   = curve.split_at_with_ranges::<bezier::Curve<_>>(&[0.7, 0.2, 1.5, 0.2, 0.0, 0.45]);
+
+    assert!(split.iter().map(|(_, range)| range.clone()).collect::<Vec<_>>() == vec![0.0..0.2, 0.2..0.45, 0.45..0.7, 0.7..1.0]);
+
+    for (section, range) in split.iter() {
+        for x in 0..=10 {
+            let t           = (x as f64)/10.0;
+            let original_t  = range.start + (range.end - range.start) * t;
+
+            assert!(section.point_at_pos(t).distance_to(&curve.point_at_pos(original_t)) < 1e-9);
+        }
+    }
+}
+
+#[test]
+fn split_at_with_no_ranges() {
+    let curve   = bezier::Curve::from_points(Coord2(1.0, 2.0), (Coord2(2.0, 0.0), Coord2(3.0, 5.0)), Coord2(4.0, 2.0));
+    let split   = curve.split_at_with_ranges::<bezier::Curve<_>>(&[]);
+
+    assert!(split.len() == 1);
+    assert!(split[0].0 == curve);
+    assert!(split[0].1 == (0.0..1.0));
+}
+
+#[test]
+fn subdivide_many_reassembles_curve() {
+    let curve   = bezier::Curve::from_points(Coord2(1.0, 2.0), (Coord2(2.0, 0.0), Coord2(3.0, 5.0)), Coord2(4.0, 2.0));
+    let pieces  = curve.subdivide_many::<bezier::Curve<_>>(&[0.25, 0.5, 0.75]);
+
+    assert!(pieces.len() == 4);
+
+    // Each piece covers a quarter of the original curve
+    for (idx, piece) in pieces.iter().enumerate() {
+        for x in 0..=20 {
+            let t           = (x as f64)/20.0;
+            let original_t  = ((idx as f64) + t) / 4.0;
+
+            assert!(piece.point_at_pos(t).distance_to(&curve.point_at_pos(original_t)) < 1e-9);
+        }
+    }
+
+    // The pieces join up
+    for (first, second) in pieces.iter().zip(pieces.iter().skip(1)) {
+        assert!(first.end_point().distance_to(&second.start_point()) < 1e-9);
+    }
+}
+
+#[test]
+fn subdivide_many_ignores_bad_t_values() {
+    let curve   = bezier::Curve::from_points(Coord2(1.0, 2.0), (Coord2(2.0, 0.0), Coord2(3.0, 5.0)), Coord2(4.0, 2.0));
+    let pieces  = curve.subdivide_many::<bezier::Curve<_>>(&[-0.5, 0.0, 0.5, 0.5, 1.0, 2.0]);
+
+    assert!(pieces.len() == 2);
+    assert!(curve.subdivide_many::<bezier::Curve<_>>(&[]) == vec![curve]);
+}
+
+#[test]
+fn subdivide_at_half_distance() {
+    // Curve with uneven speed, so half the length is not at t=0.5
+    let curve               = bezier::Curve::from_points(Coord2(0.0, 0.0), (Coord2(1.0, 0.0), Coord2(2.0, 0.0)), Coord2(30.0, 20.0));
+    let length              = bezier::curve_length(&curve, 1e-8);
+    let (first, second)     = curve.subdivide_at_distance::<bezier::Curve<_>>(length / 2.0);
+
+    let first_length        = bezier::curve_length(&first, 1e-8);
+    let second_length       = bezier::curve_length(&second, 1e-8);
+
+    assert!((first_length - second_length).abs() < 1e-4, "{} {}", first_length, second_length);
+    assert!(first.end_point() == second.start_point());
+}
+
+#[test]
+fn subdivide_at_distance_out_of_range() {
+    let curve           = bezier::Curve::from_points(Coord2(1.0, 2.0), (Coord2(2.0, 0.0), Coord2(3.0, 5.0)), Coord2(4.0, 2.0));
+
+    let (first, _)      = curve.subdivide_at_distance::<bezier::Curve<_>>(-1.0);
+    assert!(first.end_point() == curve.start_point());
+
+    let (_, second)     = curve.subdivide_at_distance::<bezier::Curve<_>>(1000.0);
+    assert!(second.start_point() == curve.end_point());
+}
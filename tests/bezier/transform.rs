@@ -0,0 +1,74 @@
+use flo_curves::*;
+use flo_curves::bezier::*;
+use flo_curves::bezier::path::*;
+
+use std::f64;
+
+fn assert_transforms_close(a: &Transform2D, b: &Transform2D) {
+    for row in 0..2 {
+        for col in 0..3 {
+            assert!((a.0[row][col] - b.0[row][col]).abs() < 1e-9, "{:?} {:?}", a, b);
+        }
+    }
+}
+
+#[test]
+fn rotate_curve_90_degrees() {
+    let curve       = Curve::from_points(Coord2(1.0, 0.0), (Coord2(2.0, 1.0), Coord2(3.0, -1.0)), Coord2(4.0, 0.0));
+    let rotated     = Transform2D::rotate(f64::consts::PI/2.0).transform_curve(&curve);
+
+    // Anticlockwise rotation takes the x axis to the y axis
+    assert!(rotated.start_point().distance_to(&Coord2(0.0, 1.0)) < 1e-9);
+    assert!(rotated.end_point().distance_to(&Coord2(0.0, 4.0)) < 1e-9);
+    assert!(rotated.control_points().0.distance_to(&Coord2(-1.0, 2.0)) < 1e-9);
+    assert!(rotated.control_points().1.distance_to(&Coord2(1.0, 3.0)) < 1e-9);
+
+    // Transforming the control points transforms every point on the curve
+    for t in 0..=10 {
+        let t = (t as f64) / 10.0;
+        let Coord2(x, y) = curve.point_at_pos(t);
+
+        assert!(rotated.point_at_pos(t).distance_to(&Coord2(-y, x)) < 1e-9);
+    }
+}
+
+#[test]
+fn translate_and_scale_points() {
+    assert!(Transform2D::translate(2.0, 3.0).transform_point(&Coord2(1.0, 1.0)) == Coord2(3.0, 4.0));
+    assert!(Transform2D::scale(2.0, -1.0).transform_point(&Coord2(1.5, 1.0)) == Coord2(3.0, -1.0));
+    assert!(Transform2D::identity().transform_point(&Coord2(1.5, 1.0)) == Coord2(1.5, 1.0));
+}
+
+#[test]
+fn multiply_transforms() {
+    let scale       = Transform2D::scale(2.0, 3.0);
+    let translate   = Transform2D::translate(1.0, -1.0);
+    let rotate      = Transform2D::rotate(0.3);
+
+    // Multiplying applies the right-hand transform first
+    let combined    = translate.multiply(&scale);
+    assert!(combined == Transform2D([[2.0, 0.0, 1.0], [0.0, 3.0, -1.0]]));
+
+    let point       = Coord2(0.7, -1.3);
+    let combined    = rotate.multiply(&translate).multiply(&scale);
+    let expected    = rotate.transform_point(&translate.transform_point(&scale.transform_point(&point)));
+    assert!(combined.transform_point(&point).distance_to(&expected) < 1e-9);
+
+    // Multiplication is associative and the identity does nothing
+    assert_transforms_close(&rotate.multiply(&translate).multiply(&scale), &rotate.multiply(&translate.multiply(&scale)));
+    assert_transforms_close(&rotate.multiply(&Transform2D::identity()), &rotate);
+    assert_transforms_close(&Transform2D::rotate(0.2).multiply(&Transform2D::rotate(0.5)), &Transform2D::rotate(0.7));
+}
+
+#[test]
+fn transform_path() {
+    let square      = polygon_to_path::<SimpleBezierPath>(&[Coord2(0.0, 0.0), Coord2(1.0, 0.0), Coord2(1.0, 1.0), Coord2(0.0, 1.0)], true);
+    let transformed = Transform2D::translate(5.0, 5.0).multiply(&Transform2D::scale(2.0, 2.0)).transform_path(&square);
+
+    assert!(transformed.start_point() == Coord2(5.0, 5.0));
+    assert!(transformed.num_segments() == 4);
+    assert!((path_area(&transformed) - path_area(&square) * 4.0).abs() < 1e-9);
+
+    let end_points = transformed.points().map(|(_, _, end_point)| end_point).collect::<Vec<_>>();
+    assert!(end_points == vec![Coord2(7.0, 5.0), Coord2(7.0, 7.0), Coord2(5.0, 7.0), Coord2(5.0, 5.0)]);
+}
@@ -53,3 +53,37 @@ fn unit_vector_0_degrees() {
 fn unit_vector_90_degrees() {
     assert!(Coord2::unit_vector_at_angle(f64::consts::PI / 2.0).distance_to(&Coord2(0.0, 1.0)) < 0.001);
 }
+
+#[test]
+fn coord2_constants() {
+    assert!(Coord2::ORIGIN == Coord2(0.0, 0.0));
+    assert!(Coord2::unit_x() == Coord2(1.0, 0.0));
+    assert!(Coord2::unit_y() == Coord2(0.0, 1.0));
+}
+
+#[test]
+fn coord2_from_angle() {
+    assert!(Coord2::from_angle(0.0, 1.0).distance_to(&Coord2::unit_x()) < 1e-10);
+    assert!(Coord2::from_angle(f64::consts::PI/2.0, 1.0).distance_to(&Coord2::unit_y()) < 1e-10);
+    assert!(Coord2::from_angle(f64::consts::PI, 2.0).distance_to(&Coord2(-2.0, 0.0)) < 1e-10);
+    assert!((Coord2::from_angle(1.234, 3.0).magnitude() - 3.0).abs() < 1e-10);
+}
+
+#[test]
+fn coord2_f64_array_round_trip() {
+    let point = Coord2(1.0/3.0, -12345.678);
+    let array = point.to_f64_array();
+
+    assert!(array.len() == 2);
+    assert!(array[0] == point.x() && array[1] == point.y());
+    assert!(Coord2::from_f64_array(&array) == point);
+}
+
+#[test]
+fn f64_array_round_trip() {
+    let value = 0.1f64;
+    let array = value.to_f64_array();
+
+    assert!(array.len() == 1);
+    assert!(f64::from_f64_array(&array) == value);
+}
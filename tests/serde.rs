@@ -0,0 +1,55 @@
+#![cfg(feature = "serde")]
+
+extern crate flo_curves;
+
+use flo_curves::*;
+use flo_curves::arc::*;
+use flo_curves::bezier::*;
+use flo_curves::bezier::path::*;
+
+#[test]
+fn coord2_round_trip() {
+    let coord           = Coord2(1.5, -2.25);
+    let json            = serde_json::to_string(&coord).unwrap();
+    let deserialized    = serde_json::from_str::<Coord2>(&json).unwrap();
+
+    assert!(deserialized == coord);
+}
+
+#[test]
+fn curve_round_trip() {
+    let curve           = Curve::from_points(Coord2(10.0, 100.0), (Coord2(90.0, 30.0), Coord2(40.0, 140.0)), Coord2(220.0, 220.0));
+    let json            = serde_json::to_string(&curve).unwrap();
+    let deserialized    = serde_json::from_str::<Curve<Coord2>>(&json).unwrap();
+
+    for t in 0..=10 {
+        let t = (t as f64) / 10.0;
+        assert!(deserialized.point_at_pos(t) == curve.point_at_pos(t));
+    }
+}
+
+#[test]
+fn curve_field_order() {
+    let curve   = Curve::from_points(Coord2(1.0, 2.0), (Coord2(3.0, 4.0), Coord2(5.0, 6.0)), Coord2(7.0, 8.0));
+    let json    = serde_json::to_string(&curve).unwrap();
+
+    assert!(json == r#"{"start_point":[1.0,2.0],"end_point":[7.0,8.0],"control_points":[[3.0,4.0],[5.0,6.0]]}"#, "{}", json);
+}
+
+#[test]
+fn path_round_trip() {
+    let path            = Circle::new(Coord2(5.0, 5.0), 4.0).to_path::<SimpleBezierPath>();
+    let json            = serde_json::to_string(&path).unwrap();
+    let deserialized    = serde_json::from_str::<SimpleBezierPath>(&json).unwrap();
+
+    let curves          = path.to_curves::<Curve<_>>();
+    let new_curves      = deserialized.to_curves::<Curve<_>>();
+
+    assert!(curves.len() == new_curves.len());
+    for (curve, new_curve) in curves.iter().zip(new_curves.iter()) {
+        for t in 0..=10 {
+            let t = (t as f64) / 10.0;
+            assert!(new_curve.point_at_pos(t) == curve.point_at_pos(t));
+        }
+    }
+}